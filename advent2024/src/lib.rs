@@ -0,0 +1,5 @@
+use aoc_utils::Registry;
+
+pub const YEAR: u16 = 2024;
+
+pub fn register(_registry: &mut Registry) {}
@@ -0,0 +1,75 @@
+//! Values read from an input are validated when they are parsed, so that solvers can use
+//! plain arithmetic on them. Results that grow with the size of the input, like sums and
+//! products, use the checked operations below and report an overflow as an error.
+
+pub trait CheckedNumber: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_number {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedNumber for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_number!(u32, u64, usize, i64, i128);
+
+pub trait CheckedIterator: Iterator + Sized {
+    fn checked_sum(mut self) -> Option<Self::Item>
+    where
+        Self::Item: CheckedNumber,
+    {
+        self.try_fold(Self::Item::ZERO, CheckedNumber::checked_add)
+    }
+
+    fn checked_product(mut self) -> Option<Self::Item>
+    where
+        Self::Item: CheckedNumber,
+    {
+        self.try_fold(Self::Item::ONE, CheckedNumber::checked_mul)
+    }
+}
+
+impl<I: Iterator> CheckedIterator for I {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checked_sum_should_return_the_sum() {
+        assert_eq!([1u32, 2, 3].into_iter().checked_sum(), Some(6));
+    }
+
+    #[test]
+    fn checked_sum_should_return_none_on_overflow() {
+        assert_eq!([u32::MAX, 1].into_iter().checked_sum(), None);
+    }
+
+    #[test]
+    fn checked_product_should_return_the_product() {
+        assert_eq!([2u64, 3, 4].into_iter().checked_product(), Some(24));
+    }
+
+    #[test]
+    fn checked_product_should_return_none_on_overflow() {
+        assert_eq!([u64::MAX, 2].into_iter().checked_product(), None);
+    }
+}
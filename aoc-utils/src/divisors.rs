@@ -0,0 +1,68 @@
+//! Greatest common divisors and least common multiples, for the puzzles whose cycles have to line up.
+
+use std::ops::{Div, Rem};
+
+use crate::checked::CheckedNumber;
+
+pub trait Integer: CheckedNumber + Eq + Div<Output = Self> + Rem<Output = Self> {
+    /// The absolute value, so that divisors are positive whatever the signs.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*; $($signed:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+        $(
+            impl Integer for $signed {
+                fn abs(self) -> Self {
+                    <$signed>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u32, u64, usize; i64, i128);
+
+/// The greatest common divisor of the numbers, which is only 0 if both are.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple of the numbers, 0 if either is, or `None` if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b).map(Integer::abs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gcd_should_be_positive() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(-12i128, 18), 6);
+        assert_eq!(gcd(0i64, -5), 5);
+        assert_eq!(gcd(0u32, 0), 0);
+    }
+
+    #[test]
+    fn lcm_should_return_none_on_overflow() {
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(0usize, 6), Some(0));
+        assert_eq!(lcm(usize::MAX / 2, usize::MAX / 2 - 1), None);
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if width.checked_mul(height) != Some(cells.len()) {
+            return Err(format!(
+                "{} cells do not fill a {width}x{height} grid",
+                cells.len()
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).map(|index| &mut self.cells[index])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_should_reject_cells_that_do_not_fill_the_grid() {
+        assert_eq!(
+            Grid::new(2, 2, vec![1, 2, 3]),
+            Err("3 cells do not fill a 2x2 grid".to_string()),
+        );
+    }
+
+    #[test]
+    fn get_should_return_none_outside_of_the_grid() {
+        let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn char_grid_should_display_one_row_per_line() {
+        let mut grid = Grid::filled(3, 2, '.');
+        *grid.get_mut(1, 1).unwrap() = '#';
+
+        assert_eq!(grid.to_string(), "...\n.#.\n");
+    }
+}
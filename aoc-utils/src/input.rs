@@ -0,0 +1,196 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, T::Err> {
+    non_empty_lines(input).map(str::parse).collect()
+}
+
+/// The bytes of the non-empty lines, which must all have the same length. Errors give the line number.
+pub fn read_grid(input: &str) -> Result<Grid<u8>, String> {
+    read_cells(input, |_, byte| Ok(byte))
+}
+
+/// The digits of the non-empty lines as numbers from 0 to 9, the lines having the same length. Errors give the line
+/// number.
+pub fn read_digit_grid(input: &str) -> Result<Grid<u8>, String> {
+    read_cells(input, |column, byte| {
+        if byte.is_ascii_digit() {
+            Ok(byte - b'0')
+        } else {
+            Err(format!(
+                "Invalid digit '{}' at column {column}",
+                char::from(byte)
+            ))
+        }
+    })
+}
+
+fn read_cells<F>(input: &str, cell: F) -> Result<Grid<u8>, String>
+where
+    F: Fn(usize, u8) -> Result<u8, String>,
+{
+    let mut width = None;
+    let mut height = 0;
+    let mut cells = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        let number = index + 1;
+        match width {
+            None => width = Some(line.len()),
+            Some(width) if width != line.len() => {
+                return Err(format!(
+                    "Line {number}: {} cells instead of {width}",
+                    line.len()
+                ));
+            }
+            Some(_) => {}
+        }
+        for (column, byte) in line.bytes().enumerate() {
+            cells.push(cell(column + 1, byte).map_err(|error| format!("Line {number}: {error}"))?);
+        }
+        height += 1;
+    }
+    Grid::new(width.unwrap_or(0), height, cells)
+}
+
+/// Descriptive statistics of a raw input, to compare the structure of an example with the one of a real input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputStats {
+    pub lines: usize,
+    pub blank_lines: usize,
+    /// The shortest, median and longest lengths of the lines, in characters.
+    pub line_lengths: Option<(usize, usize, usize)>,
+    /// The characters of the input, except whitespace.
+    pub alphabet: BTreeSet<char>,
+    pub numbers: usize,
+    /// The smallest and largest numbers, among those that fit in a `u64`.
+    pub number_range: Option<(u64, u64)>,
+}
+
+pub fn input_stats(input: &str) -> InputStats {
+    let mut lengths = input
+        .lines()
+        .map(|line| line.chars().count())
+        .collect::<Vec<_>>();
+    lengths.sort_unstable();
+    let numbers = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+    let values = numbers.iter().filter_map(|token| token.parse::<u64>().ok());
+    InputStats {
+        lines: lengths.len(),
+        blank_lines: input.lines().filter(|line| line.trim().is_empty()).count(),
+        line_lengths: lengths
+            .first()
+            .zip(lengths.last())
+            .map(|(min, max)| (*min, lengths[lengths.len() / 2], *max)),
+        alphabet: input.chars().filter(|c| !c.is_whitespace()).collect(),
+        numbers: numbers.len(),
+        number_range: values.clone().min().zip(values.max()),
+    }
+}
+
+impl Display for InputStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Lines: {} ({} blank)", self.lines, self.blank_lines)?;
+        if let Some((min, median, max)) = self.line_lengths {
+            writeln!(f, "Line length: min {min}, median {median}, max {max}")?;
+        }
+        writeln!(f, "Alphabet: {}", self.alphabet.iter().collect::<String>())?;
+        match self.number_range {
+            Some((min, max)) => writeln!(f, "Numbers: {}, from {min} to {max}", self.numbers),
+            None => writeln!(f, "Numbers: {}", self.numbers),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn non_empty_lines_should_skip_blank_lines() {
+        assert_eq!(
+            non_empty_lines("\na\r\n  \nb\n").collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn parse_lines_should_report_the_first_error() {
+        assert!(parse_lines::<u32>("1\nx\n3").is_err());
+        assert_eq!(parse_lines::<u32>("1\n\n3"), Ok(vec![1, 3]));
+    }
+
+    #[test]
+    fn input_stats_should_describe_lines_characters_and_numbers() {
+        let stats = input_stats("Time: 7 15\n\nDistance: 9 40 200\n");
+
+        assert_eq!(stats.lines, 3);
+        assert_eq!(stats.blank_lines, 1);
+        assert_eq!(stats.line_lengths, Some((0, 10, 18)));
+        assert_eq!(stats.numbers, 5);
+        assert_eq!(stats.number_range, Some((7, 200)));
+        assert_eq!(
+            stats.to_string(),
+            "Lines: 3 (1 blank)
+Line length: min 0, median 10, max 18
+Alphabet: 0124579:DTaceimnst
+Numbers: 5, from 7 to 200
+",
+        );
+    }
+
+    #[test]
+    fn input_stats_of_empty_input() {
+        assert_eq!(
+            input_stats("").to_string(),
+            "Lines: 0 (0 blank)\nAlphabet: \nNumbers: 0\n"
+        );
+    }
+
+    #[test]
+    fn read_grid_should_keep_the_bytes_of_each_row() {
+        let grid = read_grid("#.\n.#\n\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![b"#.", b".#"]);
+    }
+
+    #[test]
+    fn read_grid_should_report_the_first_line_of_another_length() {
+        assert_eq!(
+            read_grid("...\n\n...\n..\n"),
+            Err("Line 4: 2 cells instead of 3".to_string()),
+        );
+    }
+
+    #[test]
+    fn read_digit_grid_should_convert_the_digits() {
+        let grid = read_digit_grid("123\n405\n").unwrap();
+
+        assert_eq!(grid.get(1, 1), Some(&0));
+        assert_eq!(grid.get(2, 0), Some(&3));
+        assert_eq!(
+            read_digit_grid("123\n4x5\n"),
+            Err("Line 2: Invalid digit 'x' at column 2".to_string()),
+        );
+    }
+
+    #[test]
+    fn read_grid_of_an_empty_input_should_be_empty() {
+        assert_eq!(read_grid("\n").map(|grid| grid.height()), Ok(0));
+    }
+}
@@ -0,0 +1,16 @@
+pub use progress::Progress;
+pub use registry::Registry;
+pub use report::{answer_value, Answer, DayResult};
+pub use solution::{Example, Label, Solution, Variant};
+
+pub mod checked;
+pub mod divisors;
+pub mod grid;
+pub mod input;
+pub mod plugin;
+mod progress;
+pub mod random;
+mod registry;
+pub mod render;
+mod report;
+mod solution;
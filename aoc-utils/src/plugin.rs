@@ -0,0 +1,45 @@
+/// The ABI of the plugins, a hash of the versions of `aoc-utils` and of the compiler. The solutions of a plugin cross
+/// the library boundary with the Rust ABI, which is only stable within the same compiler build, so the loader checks
+/// it before calling `register`.
+pub const ABI: u64 =
+    fnv1a(concat!(env!("CARGO_PKG_VERSION"), " ", env!("AOC_RUSTC_VERSION")).as_bytes());
+
+const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let mut index = 0;
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        index += 1;
+    }
+    hash
+}
+
+/// Exports the entry points of a plugin: `aoc_plugin_abi`, with the C ABI, that the loader checks first, then
+/// `register`, that it calls with the registry.
+#[macro_export]
+macro_rules! export_plugin {
+    ($register:path) => {
+        // Exported under other Rust names, so that the function passed to the macro may be called `register` too.
+        #[export_name = "aoc_plugin_abi"]
+        pub extern "C" fn __aoc_plugin_abi() -> u64 {
+            $crate::plugin::ABI
+        }
+
+        #[export_name = "register"]
+        pub fn __aoc_plugin_register(registry: &mut $crate::Registry) {
+            $register(registry)
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fnv1a_should_hash_the_bytes() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
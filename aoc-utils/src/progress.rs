@@ -0,0 +1,33 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// Where a long part reports how far it is, from any thread. Reports go nowhere unless the runner listens to them.
+#[derive(Clone, Default)]
+pub struct Progress {
+    listener: Option<Arc<dyn Fn(u64, u64) + Send + Sync>>,
+}
+
+impl Progress {
+    /// Calls `listener` with the steps done and the total on every report.
+    pub fn new(listener: impl Fn(u64, u64) + Send + Sync + 'static) -> Self {
+        Self {
+            listener: Some(Arc::new(listener)),
+        }
+    }
+
+    /// Reports that `done` steps are done out of at most `total`. The parts call it every so many steps, not on each
+    /// one, as the listener may be slow.
+    pub fn report(&self, done: u64, total: u64) {
+        if let Some(listener) = &self.listener {
+            listener(done, total);
+        }
+    }
+}
+
+impl Debug for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Progress")
+            .field("listening", &self.listener.is_some())
+            .finish()
+    }
+}
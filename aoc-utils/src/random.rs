@@ -0,0 +1,92 @@
+//! A small seeded generator for the synthetic inputs, so that a seed gives the same input on every platform and with
+//! every version of the dependencies.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64, which is fast and good enough to shuffle puzzle inputs, but not for anything cryptographic.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number of the range, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range: {start}..={end}");
+        match (end - start).checked_add(1) {
+            Some(length) => start + self.next_u64() % length,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index of a slice of the given length, which must not be zero.
+    pub fn index(&mut self, length: usize) -> usize {
+        self.range(0..=length as u64 - 1) as usize
+    }
+
+    /// Whether an event of the given probability, in percent, happens.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(1..=100) <= percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_give_the_reference_splitmix64_sequence() {
+        let mut rng = Rng::new(0);
+
+        assert_eq!(
+            [rng.next_u64(), rng.next_u64(), rng.next_u64()],
+            [
+                0xe220_a839_7b1d_cdaf,
+                0x6e78_9e6a_a1b9_65f4,
+                0x06c4_5d18_8009_454f
+            ],
+        );
+    }
+
+    #[test]
+    fn range_should_stay_within_its_bounds() {
+        let mut rng = Rng::new(42);
+
+        assert!((0..1000).all(|_| (3..=5).contains(&rng.range(3..=5))));
+        assert_eq!(rng.range(7..=7), 7);
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn shuffle_should_keep_the_items() {
+        let mut items = (0..20).collect::<Vec<_>>();
+
+        Rng::new(1).shuffle(&mut items);
+        items.sort();
+
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
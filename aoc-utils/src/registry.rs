@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::Solution;
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Arc<dyn Solution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, year: u16, solution: Box<dyn Solution>) {
+        self.solutions
+            .insert((year, solution.day()), Arc::from(solution));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Solution> {
+        self.solutions.get(&(year, day)).map(Arc::as_ref)
+    }
+
+    /// Same as [`Registry::get`], for a solution that must outlive the registry, such as one run on another thread.
+    pub fn get_shared(&self, year: u16, day: u8) -> Option<Arc<dyn Solution>> {
+        self.solutions.get(&(year, day)).cloned()
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self
+            .solutions
+            .keys()
+            .map(|(year, _)| *year)
+            .collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    pub fn days(&self, year: u16) -> Vec<u8> {
+        self.solutions
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|((_, day), _)| *day)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct FakeSolution(u8);
+
+    impl Solution for FakeSolution {
+        fn day(&self) -> u8 {
+            self.0
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, _input: &str) -> Result<String, String> {
+            Ok(format!("Day {}", self.0))
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            Ok(format!("Day {}", self.0))
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(2023, Box::new(FakeSolution(8)));
+        registry.register(2022, Box::new(FakeSolution(1)));
+        registry.register(2023, Box::new(FakeSolution(1)));
+        registry
+    }
+
+    #[test]
+    fn get_should_find_a_solution_by_year_and_day() {
+        let registry = registry();
+
+        assert_eq!(registry.get(2023, 8).map(Solution::day), Some(8));
+        assert!(registry.get(2022, 8).is_none());
+    }
+
+    #[test]
+    fn years_should_be_sorted_and_unique() {
+        assert_eq!(registry().years(), vec![2022, 2023]);
+    }
+
+    #[test]
+    fn days_should_only_contain_the_days_of_the_year() {
+        assert_eq!(registry().days(2023), vec![1, 8]);
+    }
+}
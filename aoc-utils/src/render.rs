@@ -0,0 +1,49 @@
+//! Terminal animation of grids, for the days that can show their progress step by step.
+
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::Grid;
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+pub fn animate<I>(frames: I, delay: Duration) -> io::Result<()>
+where
+    I: IntoIterator<Item = Grid<char>>,
+{
+    animate_to(&mut io::stdout().lock(), frames, delay)
+}
+
+pub fn animate_to<W, I>(out: &mut W, frames: I, delay: Duration) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Grid<char>>,
+{
+    for (index, frame) in frames.into_iter().enumerate() {
+        if index > 0 {
+            thread::sleep(delay);
+        }
+        write!(out, "{CLEAR_SCREEN}{frame}")?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn animate_should_clear_the_screen_before_each_frame() {
+        let frames = ['.', '#'].map(|cell| Grid::filled(2, 1, cell));
+        let mut out = Vec::new();
+
+        animate_to(&mut out, frames, Duration::ZERO).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[H..\n\x1b[2J\x1b[H##\n",
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<String, String>,
+    pub duration: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.result {
+            Ok(text) => writeln!(f, "{}:{} — {text}", self.day, self.part)?,
+            Err(error) => writeln!(f, "{}:{} — Error: {error}", self.day, self.part)?,
+        }
+        writeln!(f, "Part {} in {}ms", self.part, self.duration.as_millis())
+    }
+}
+
+/// The value of an answer, without the label that the solutions put before it, so that it can be compared whatever the
+/// wording.
+pub fn answer_value(text: &str) -> &str {
+    text.rsplit(": ").next().unwrap_or(text)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayResult {
+    pub day: u8,
+    pub answers: Vec<Answer>,
+    pub duration: Duration,
+}
+
+impl DayResult {
+    pub fn footer(&self) -> String {
+        format!("Done in {}ms\n", self.duration.as_millis())
+    }
+}
+
+impl Display for DayResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for answer in &self.answers {
+            write!(f, "{answer}")?;
+        }
+        write!(f, "{}", self.footer())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::{assert_snapshot, with_settings};
+
+    use super::*;
+    use crate::Solution;
+
+    struct FakeSolution;
+
+    impl Solution for FakeSolution {
+        fn day(&self) -> u8 {
+            42
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, _input: &str) -> Result<String, String> {
+            Ok("Answer to part one: 1".to_string())
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            Err("Sum overflows".to_string())
+        }
+    }
+
+    #[test]
+    fn render_day_result() {
+        let result = DayResult {
+            day: 3,
+            answers: vec![
+                Answer {
+                    day: 3,
+                    part: 1,
+                    result: Ok("Sum of all part numbers: 4361".to_string()),
+                    duration: Duration::from_micros(1500),
+                },
+                Answer {
+                    day: 3,
+                    part: 2,
+                    result: Ok("Sum of all gear ratios: 467835".to_string()),
+                    duration: Duration::from_millis(12),
+                },
+            ],
+            duration: Duration::from_micros(13500),
+        };
+
+        assert_snapshot!(result.to_string(), @r"
+        3:1 — Sum of all part numbers: 4361
+        Part 1 in 1ms
+        3:2 — Sum of all gear ratios: 467835
+        Part 2 in 12ms
+        Done in 13ms
+        ");
+    }
+
+    #[test]
+    fn render_run_of_a_solution() {
+        let result = FakeSolution.run();
+
+        with_settings!({filters => vec![(r"\d+ms", "[duration]")]}, {
+            assert_snapshot!(result.to_string(), @r"
+            42:1 — Answer to part one: 1
+            Part 1 in [duration]
+            42:2 — Error: Sum overflows
+            Part 2 in [duration]
+            Done in [duration]
+            ");
+        });
+    }
+
+    #[test]
+    fn answer_value_should_drop_the_label() {
+        assert_eq!(answer_value("Sum of all part numbers: 4361"), "4361");
+        assert_eq!(answer_value("Games for 12 reds: 8: 3"), "3");
+        assert_eq!(answer_value("4361"), "4361");
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use crate::grid::Grid;
+use crate::progress::Progress;
+use crate::random::Rng;
+use crate::{Answer, DayResult};
+
+/// An example from the puzzle statement, with the expected answer to one of its parts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    pub answer: &'static str,
+}
+
+/// The label of the answer to a part, with the key under which the message catalogs of the runner translate it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Label {
+    pub key: &'static str,
+    /// The English label, which the solution puts before the value of the answer.
+    pub text: &'static str,
+}
+
+impl Label {
+    /// The answer with this label and the given value.
+    pub fn answer(&self, value: impl Display) -> String {
+        format!("{}: {value}", self.text)
+    }
+}
+
+/// Another algorithm for a part, whose answer must be the same as the one of the solution.
+#[derive(Debug, Copy, Clone)]
+pub struct Variant {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&str) -> Result<String, String>,
+}
+
+pub trait Solution: Send + Sync {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn part_one(&self, input: &str) -> Result<String, String>;
+    fn part_two(&self, input: &str) -> Result<String, String>;
+
+    /// The title of the puzzle, for the days that give it.
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The values of the accepted answers on the input of the day, by part, for the days whose answers are known.
+    fn known_answers(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The labels of the answers, by part, for the days whose answers are labelled.
+    fn labels(&self) -> &'static [Label] {
+        &[]
+    }
+
+    /// The parts that are solved: both, unless a day only solves its first part so far.
+    fn parts(&self) -> &'static [u8] {
+        &[1, 2]
+    }
+
+    fn solve_part(&self, part: u8, input: &str) -> Result<String, String> {
+        match part {
+            1 => self.part_one(input),
+            2 => self.part_two(input),
+            _ => Err(format!("Invalid part: {part}")),
+        }
+    }
+
+    /// Same as [`Solution::solve_part`], reporting how far the part is for the days whose parts can take long.
+    fn solve_part_reporting(
+        &self,
+        part: u8,
+        input: &str,
+        _progress: &Progress,
+    ) -> Result<String, String> {
+        self.solve_part(part, input)
+    }
+
+    /// Checks that an input is valid without solving the puzzle, for the days that can. The others solve part one.
+    fn validate(&self, input: &str) -> Result<(), String> {
+        self.part_one(input).map(drop)
+    }
+
+    /// The examples of the puzzle statement, to check the solution on any machine with the `selftest` subcommand.
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// The other algorithms solving the parts, to cross-check the answers.
+    fn variants(&self) -> Vec<Variant> {
+        Vec::new()
+    }
+
+    /// A random valid input of about `size` lines, cards, hands or nodes, for the days that have a generator.
+    fn generate(&self, _size: usize, _rng: &mut Rng) -> Result<String, String> {
+        Err(format!("No generator for day {}", self.day()))
+    }
+
+    /// Describes the intermediate steps behind the answer to a part, for the days that can.
+    fn explain(&self, _part: u8, _input: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Renders the puzzle for the given input, for the days that have a visualization.
+    fn visualize(&self, _input: &str) -> Result<String, String> {
+        Err(format!("No visualization for day {}", self.day()))
+    }
+
+    /// The successive states of the puzzle, for the days that can be animated.
+    fn frames(&self, _input: &str) -> Result<Vec<Grid<char>>, String> {
+        Err(format!("No animation for day {}", self.day()))
+    }
+
+    fn run(&self) -> DayResult {
+        self.run_with(self.input(), &mut |_| {})
+    }
+
+    fn run_with(&self, input: &str, on_answer: &mut dyn FnMut(&Answer)) -> DayResult {
+        self.run_parts_with(input, self.parts(), on_answer)
+    }
+
+    /// Same as [`Solution::run_with`], for the given parts only.
+    fn run_parts_with(
+        &self,
+        input: &str,
+        parts: &[u8],
+        on_answer: &mut dyn FnMut(&Answer),
+    ) -> DayResult {
+        self.run_parts_reporting(input, parts, &Progress::default(), on_answer)
+    }
+
+    /// Same as [`Solution::run_parts_with`], with the progress of the parts reported to `progress`.
+    fn run_parts_reporting(
+        &self,
+        input: &str,
+        parts: &[u8],
+        progress: &Progress,
+        on_answer: &mut dyn FnMut(&Answer),
+    ) -> DayResult {
+        let day = self.day();
+        let _span = tracing::info_span!("day", day).entered();
+        let start = Instant::now();
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let _span = tracing::info_span!("part", part).entered();
+                let part_start = Instant::now();
+                let result = self.solve_part_reporting(part, input, progress);
+                let answer = Answer {
+                    day,
+                    part,
+                    result,
+                    duration: part_start.elapsed(),
+                };
+                on_answer(&answer);
+                answer
+            })
+            .collect();
+        DayResult {
+            day,
+            answers,
+            duration: start.elapsed(),
+        }
+    }
+
+    fn execute(&self) -> DayResult {
+        self.execute_parts(self.parts())
+    }
+
+    /// Same as [`Solution::execute`], for the given parts only.
+    fn execute_parts(&self, parts: &[u8]) -> DayResult {
+        let result = self.run_parts_with(self.input(), parts, &mut |answer| print!("{answer}"));
+        print!("{}", result.footer());
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Parts;
+
+    impl Solution for Parts {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn input(&self) -> &'static str {
+            "input"
+        }
+
+        fn part_one(&self, input: &str) -> Result<String, String> {
+            Ok(format!("One: {input}"))
+        }
+
+        fn part_two(&self, input: &str) -> Result<String, String> {
+            Ok(format!("Two: {input}"))
+        }
+
+        fn solve_part_reporting(
+            &self,
+            part: u8,
+            input: &str,
+            progress: &Progress,
+        ) -> Result<String, String> {
+            progress.report(part.into(), 2);
+            self.solve_part(part, input)
+        }
+    }
+
+    #[test]
+    fn run_parts_with_should_only_run_the_given_parts() {
+        let mut seen = Vec::new();
+
+        let result = Parts.run_parts_with("text", &[2], &mut |answer| seen.push(answer.part));
+
+        assert_eq!(seen, vec![2]);
+        assert_eq!(result.answers.len(), 1);
+        assert_eq!(result.answers[0].result, Ok("Two: text".to_string()));
+    }
+
+    #[test]
+    fn run_parts_reporting_should_pass_the_progress_to_the_parts() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let progress = Progress::new(move |done, total| sender.send((done, total)).unwrap());
+
+        Parts.run_parts_reporting("text", &[1, 2], &progress, &mut |_| {});
+        drop(progress);
+
+        assert_eq!(receiver.iter().collect::<Vec<_>>(), vec![(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn run_should_run_both_parts() {
+        let result = Parts.run();
+
+        assert_eq!(
+            result
+                .answers
+                .iter()
+                .map(|answer| answer.part)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_utils::{DayResult, Solution};
+
+use crate::messages::{self, Catalog};
+
+/// Solves a day for every file of a directory, in the order of their names, and prints the answers for each file.
+pub fn run(
+    solution: &dyn Solution,
+    dir: &Path,
+    messages: Catalog,
+) -> Result<Vec<(PathBuf, DayResult)>, String> {
+    let mut paths = fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|error| format!("Cannot read {}: {error}", dir.display()))?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    let mut results = Vec::new();
+    for path in paths {
+        println!("{}", path.display());
+        match fs::read_to_string(&path) {
+            Ok(input) => {
+                let result = solution.run_with(&input, &mut |answer| {
+                    let label = messages::label(solution, answer.part);
+                    print!("{}", messages.answer(answer, label))
+                });
+                print!("{}", messages.footer(&result));
+                results.push((path, result));
+            }
+            Err(error) => eprintln!("Cannot read {}: {error}", path.display()),
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, input: &str) -> Result<String, String> {
+            Ok(input.lines().count().to_string())
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            Err("Not solved".to_string())
+        }
+    }
+
+    #[test]
+    fn run_should_solve_every_file_of_the_directory() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("b.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("a.txt"), "1\n").unwrap();
+
+        let results = run(&LineCount, &dir, Catalog::English);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let answers = results
+            .unwrap()
+            .into_iter()
+            .map(|(path, result)| {
+                (
+                    path.file_name().unwrap().to_string_lossy().into_owned(),
+                    result.answers[0].result.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            answers,
+            vec![
+                ("a.txt".to_string(), Ok("1".to_string())),
+                ("b.txt".to_string(), Ok("3".to_string())),
+            ],
+        );
+    }
+
+    #[test]
+    fn run_should_fail_on_a_missing_directory() {
+        assert!(run(
+            &LineCount,
+            Path::new("/nonexistent/inputs"),
+            Catalog::English
+        )
+        .unwrap_err()
+        .starts_with("Cannot read /nonexistent/inputs: "));
+    }
+}
@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+use aoc_utils::{Answer, Solution};
+
+use crate::{interrupt, milliseconds};
+
+/// The spread of the durations of the runs of a part.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// The statistics of the samples, or `None` if there are none. The median of an even number of samples is the
+    /// mean of the two middle ones.
+    pub fn new(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let min = *sorted.first()?;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        let mean = sorted.iter().sum::<Duration>() / u32::try_from(sorted.len()).ok()?;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / sorted.len() as f64;
+        Some(Stats {
+            min,
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// The answers and the durations of `iterations` runs of the part on the input, after `warmup` runs that are not
+/// measured.
+pub fn measure(
+    solution: &dyn Solution,
+    part: u8,
+    input: &str,
+    iterations: usize,
+    warmup: usize,
+) -> Result<Vec<(String, Duration)>, String> {
+    for _ in 0..warmup {
+        solution.solve_part(part, input)?;
+    }
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let answer = solution.solve_part(part, input)?;
+            Ok((answer, start.elapsed()))
+        })
+        .collect()
+}
+
+fn row(part: u8, runs: usize, stats: &Stats) -> String {
+    format!(
+        "{part:>4}  {runs:>5}  {:>10.3}ms  {:>10.3}ms  {:>10.3}ms  {:>10.3}ms\n",
+        milliseconds(stats.min),
+        milliseconds(stats.median),
+        milliseconds(stats.mean),
+        milliseconds(stats.stddev),
+    )
+}
+
+/// Benchmarks the parts of the day on its input, and prints a line of statistics per part. Each measured part is
+/// recorded with its median duration, for the summary of an interrupted benchmark.
+pub fn run(
+    year: u16,
+    solution: &dyn Solution,
+    parts: &[u8],
+    iterations: usize,
+    warmup: usize,
+) -> Result<(), String> {
+    println!(
+        "Part   Runs  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Min", "Median", "Mean", "Stddev"
+    );
+    for &part in parts {
+        let runs = measure(solution, part, solution.input(), iterations, warmup)
+            .map_err(|error| format!("Part {part} failed: {error}"))?;
+        let samples = runs
+            .iter()
+            .map(|(_, duration)| *duration)
+            .collect::<Vec<_>>();
+        if let (Some((answer, _)), Some(stats)) = (runs.last(), Stats::new(&samples)) {
+            print!("{}", row(part, samples.len(), &stats));
+            interrupt::record(
+                year,
+                &Answer {
+                    day: solution.day(),
+                    part,
+                    result: Ok(answer.clone()),
+                    duration: stats.median,
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn stats_should_describe_the_samples() {
+        assert_eq!(
+            Stats::new(&millis(&[5, 2, 4, 9, 4, 7, 4, 5])),
+            Some(Stats {
+                min: Duration::from_millis(2),
+                median: Duration::from_micros(4500),
+                mean: Duration::from_millis(5),
+                stddev: Duration::from_millis(2),
+            }),
+        );
+    }
+
+    #[test]
+    fn median_of_an_odd_number_of_samples_should_be_the_middle_one() {
+        let stats = Stats::new(&millis(&[9, 1, 3])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.min, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn stats_of_no_samples_should_be_none() {
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn row_should_align_the_statistics() {
+        let stats = Stats::new(&millis(&[1, 1, 1])).unwrap();
+
+        assert_eq!(
+            row(2, 3, &stats),
+            "   2      3       1.000ms       1.000ms       1.000ms       0.000ms\n"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_utils::DayResult;
+
+use crate::milliseconds;
+
+const WIDTH: usize = 40;
+const EIGHTHS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// A bar per part, scaled to the slowest one, so that the slowest days stand out.
+pub fn runtime_chart(results: &[(u16, DayResult)]) -> String {
+    let answers = results
+        .iter()
+        .flat_map(|(year, result)| result.answers.iter().map(move |answer| (year, answer)))
+        .collect::<Vec<_>>();
+    let slowest = answers
+        .iter()
+        .map(|(_, answer)| answer.duration)
+        .max()
+        .unwrap_or_default();
+    answers
+        .into_iter()
+        .map(|(year, answer)| {
+            let ratio = if slowest.is_zero() {
+                0.0
+            } else {
+                answer.duration.as_secs_f64() / slowest.as_secs_f64()
+            };
+            format!(
+                "{year} {:>2}:{} {:<WIDTH$} {:.1}ms\n",
+                answer.day,
+                answer.part,
+                bar(ratio),
+                milliseconds(answer.duration),
+            )
+        })
+        .collect()
+}
+
+fn bar(ratio: f64) -> String {
+    let eighths = (ratio * (WIDTH * 8) as f64).round() as usize;
+    let mut bar = EIGHTHS[7].to_string().repeat(eighths / 8);
+    if let Some(partial) = (eighths % 8).checked_sub(1) {
+        bar.push(EIGHTHS[partial]);
+    }
+    bar
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use aoc_utils::Answer;
+
+    use super::*;
+
+    fn answer(part: u8, millis: u64) -> Answer {
+        Answer {
+            day: 8,
+            part,
+            result: Ok(String::new()),
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn bars_should_be_scaled_to_the_slowest_part() {
+        let result = DayResult {
+            day: 8,
+            answers: vec![answer(1, 10), answer(2, 80)],
+            duration: Duration::from_millis(90),
+        };
+
+        assert_eq!(
+            runtime_chart(&[(2023, result)]),
+            format!(
+                "2023  8:1 █████{} 10.0ms\n2023  8:2 {} 80.0ms\n",
+                " ".repeat(35),
+                "█".repeat(40),
+            ),
+        );
+    }
+
+    #[test]
+    fn bar_should_use_partial_blocks() {
+        assert_eq!(bar(0.0), "");
+        assert_eq!(bar(1.0 / 320.0), "▏");
+        assert_eq!(bar(12.0 / 320.0), "█▌");
+    }
+}
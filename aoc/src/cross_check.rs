@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use aoc_utils::grid::Grid;
+use aoc_utils::random::Rng;
+use aoc_utils::{Example, Label, Progress, Solution, Variant};
+
+/// Solves each part with every variant too, and fails if they do not all give the same answer.
+pub struct CrossChecked {
+    solution: Arc<dyn Solution>,
+}
+
+impl CrossChecked {
+    pub fn new(solution: Arc<dyn Solution>) -> Self {
+        Self { solution }
+    }
+}
+
+impl Solution for CrossChecked {
+    fn day(&self) -> u8 {
+        self.solution.day()
+    }
+
+    fn input(&self) -> &'static str {
+        self.solution.input()
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        self.solve_part(1, input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        self.solve_part(2, input)
+    }
+
+    fn solve_part(&self, part: u8, input: &str) -> Result<String, String> {
+        self.solve_part_reporting(part, input, &Progress::default())
+    }
+
+    /// Only the solution reports its progress, not the variants.
+    fn solve_part_reporting(
+        &self,
+        part: u8,
+        input: &str,
+        progress: &Progress,
+    ) -> Result<String, String> {
+        let answer = self.solution.solve_part_reporting(part, input, progress);
+        let disagreements = self
+            .solution
+            .variants()
+            .into_iter()
+            .filter(|variant| variant.part == part)
+            .filter_map(|Variant { name, solve, .. }| {
+                let variant_answer = solve(input);
+                (variant_answer != answer).then(|| match variant_answer {
+                    Ok(text) => format!("{name} gives '{text}'"),
+                    Err(error) => format!("{name} fails with '{error}'"),
+                })
+            })
+            .collect::<Vec<_>>();
+        if disagreements.is_empty() {
+            answer
+        } else {
+            let answer = match answer {
+                Ok(text) => format!("'{text}'"),
+                Err(error) => format!("an error, '{error}'"),
+            };
+            Err(format!(
+                "Variants disagree: the solution gives {answer}, {}",
+                disagreements.join(", "),
+            ))
+        }
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        self.solution.validate(input)
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.solution.title()
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        self.solution.labels()
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        self.solution.known_answers()
+    }
+
+    fn parts(&self) -> &'static [u8] {
+        self.solution.parts()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        self.solution.examples()
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        self.solution.variants()
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        self.solution.generate(size, rng)
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        self.solution.explain(part, input)
+    }
+
+    fn visualize(&self, input: &str) -> Result<String, String> {
+        self.solution.visualize(input)
+    }
+
+    fn frames(&self, input: &str) -> Result<Vec<Grid<char>>, String> {
+        self.solution.frames(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Length;
+
+    impl Solution for Length {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, input: &str) -> Result<String, String> {
+            Ok(input.len().to_string())
+        }
+
+        fn part_two(&self, input: &str) -> Result<String, String> {
+            Ok(input.len().to_string())
+        }
+
+        fn variants(&self) -> Vec<Variant> {
+            vec![
+                Variant {
+                    name: "chars",
+                    part: 1,
+                    solve: |input| Ok(input.chars().count().to_string()),
+                },
+                Variant {
+                    name: "len",
+                    part: 1,
+                    solve: |input| Ok(input.len().to_string()),
+                },
+                Variant {
+                    name: "unsupported",
+                    part: 2,
+                    solve: |_| Err("Not implemented".to_string()),
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn agreeing_variants_should_keep_the_answer() {
+        assert_eq!(
+            CrossChecked::new(Arc::new(Length)).part_one("abc"),
+            Ok("3".to_string())
+        );
+    }
+
+    #[test]
+    fn disagreeing_variants_should_fail_the_part() {
+        let solution = CrossChecked::new(Arc::new(Length));
+
+        assert_eq!(
+            solution.part_one("é"),
+            Err("Variants disagree: the solution gives '2', chars gives '1'".to_string()),
+        );
+        assert_eq!(
+            solution.part_two("abc"),
+            Err(
+                "Variants disagree: the solution gives '3', unsupported fails with 'Not implemented'"
+                    .to_string()
+            ),
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use std::env;
+
+use aoc_utils::{answer_value, Registry, Solution};
+
+/// The environment variable holding the session cookie of adventofcode.com.
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Status {
+    Ok,
+    Failed(String),
+    /// The input of the day has not been committed yet.
+    Pending,
+    /// The check does not apply, such as parsing a missing input.
+    Skipped,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Failed(_) => "FAILED",
+            Status::Pending => "pending",
+            Status::Skipped => "-",
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct DayStatus {
+    year: u16,
+    day: u8,
+    input: Status,
+    parses: Status,
+    answers: Status,
+}
+
+impl DayStatus {
+    fn failures(&self) -> impl Iterator<Item = &str> {
+        [&self.input, &self.parses, &self.answers]
+            .into_iter()
+            .filter_map(|status| match status {
+                Status::Failed(error) => Some(error.as_str()),
+                _ => None,
+            })
+    }
+}
+
+/// Checks that every registered day with an input has a valid one, whose answers are the known answers of the day, and
+/// that the session token works if there is one. Fails if any check does, but not for the inputs still to commit.
+pub fn run(registry: &Registry) -> Result<(), String> {
+    let statuses = registry
+        .years()
+        .into_iter()
+        .flat_map(|year| registry.days(year).into_iter().map(move |day| (year, day)))
+        .filter_map(|(year, day)| Some(check(year, registry.get(year, day)?)))
+        .collect::<Vec<_>>();
+    print!("{}", table(&statuses));
+    let session = session_status();
+    match &session {
+        Ok(status) => println!("Session token: {status}"),
+        Err(error) => println!("Session token: FAILED: {error}"),
+    }
+    let failed = statuses
+        .iter()
+        .map(|status| status.failures().count())
+        .sum::<usize>()
+        + usize::from(session.is_err());
+    if failed > 0 {
+        Err(format!("{failed} checks failed"))
+    } else {
+        Ok(())
+    }
+}
+
+fn check(year: u16, solution: &dyn Solution) -> DayStatus {
+    let (input, parses) = check_input(solution);
+    let answers = match parses {
+        Status::Ok => check_answers(solution),
+        _ => Status::Skipped,
+    };
+    DayStatus {
+        year,
+        day: solution.day(),
+        input,
+        parses,
+        answers,
+    }
+}
+
+/// Whether the day has an input, and whether it is valid.
+fn check_input(solution: &dyn Solution) -> (Status, Status) {
+    let input = solution.input();
+    if input.trim().is_empty() {
+        return (Status::Pending, Status::Skipped);
+    }
+    let parses = match solution.validate(input) {
+        Ok(()) => Status::Ok,
+        Err(error) => Status::Failed(format!("Invalid input: {error}")),
+    };
+    (Status::Ok, parses)
+}
+
+/// Solves the parts of the day and compares their answers with the known ones.
+fn check_answers(solution: &dyn Solution) -> Status {
+    let known_answers = solution.known_answers();
+    if known_answers.is_empty() {
+        return Status::Failed("No known answers".to_string());
+    }
+    let failures = solution
+        .parts()
+        .iter()
+        .zip(known_answers)
+        .filter_map(
+            |(&part, known)| match solution.solve_part(part, solution.input()) {
+                Ok(text) if answer_value(&text) == *known => None,
+                Ok(text) => Some(format!(
+                    "Part {part} gives {}, not {known}",
+                    answer_value(&text)
+                )),
+                Err(error) => Some(format!("Part {part} fails: {error}")),
+            },
+        )
+        .collect::<Vec<_>>();
+    if failures.is_empty() {
+        Status::Ok
+    } else {
+        Status::Failed(failures.join(", "))
+    }
+}
+
+fn table(statuses: &[DayStatus]) -> String {
+    let mut table = String::from("Year  Day  Input    Parses  Answers\n");
+    for status in statuses {
+        table.push_str(&format!(
+            "{:<4}  {:>3}  {:<7}  {:<6}  {}\n",
+            status.year,
+            status.day,
+            status.input.label(),
+            status.parses.label(),
+            status.answers.label(),
+        ));
+    }
+    for status in statuses {
+        for failure in status.failures() {
+            table.push_str(&format!("{}:{} — {failure}\n", status.year, status.day));
+        }
+    }
+    table
+}
+
+/// The status of the session token, which is only checked with the HTTP client of the notify feature.
+fn session_status() -> Result<String, String> {
+    match env::var(SESSION_VARIABLE) {
+        #[cfg(feature = "notify")]
+        Ok(token) => check_session(&token).map(|()| "ok".to_string()),
+        #[cfg(not(feature = "notify"))]
+        Ok(_) => Ok("set, only checked with the notify feature".to_string()),
+        Err(_) => Ok(format!(
+            "not set ({SESSION_VARIABLE}), only needed to fetch new inputs"
+        )),
+    }
+}
+
+/// Fetches the first input of the current event with the token, which only succeeds if the token is a valid session.
+#[cfg(feature = "notify")]
+fn check_session(token: &str) -> Result<(), String> {
+    ureq::get("https://adventofcode.com/2023/day/1/input")
+        .header("Cookie", &format!("session={}", token.trim()))
+        .call()
+        .map(|_| ())
+        .map_err(|error| match error {
+            ureq::Error::StatusCode(400) => "The session has expired or is invalid".to_string(),
+            error => error.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct FakeSolution {
+        input: &'static str,
+        known_answers: &'static [&'static str],
+    }
+
+    impl Solution for FakeSolution {
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn input(&self) -> &'static str {
+            self.input
+        }
+
+        fn known_answers(&self) -> &'static [&'static str] {
+            self.known_answers
+        }
+
+        fn parts(&self) -> &'static [u8] {
+            &[1]
+        }
+
+        fn part_one(&self, input: &str) -> Result<String, String> {
+            input
+                .trim()
+                .parse::<u32>()
+                .map(|number| number.to_string())
+                .map_err(|_| format!("Not a number: {input}"))
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            Err("Not solved".to_string())
+        }
+    }
+
+    #[test]
+    fn check_should_report_each_problem() {
+        let invalid = FakeSolution {
+            input: "x",
+            known_answers: &["42"],
+        };
+        let wrong = FakeSolution {
+            input: "41",
+            known_answers: &["42"],
+        };
+        let unknown = FakeSolution {
+            input: "42",
+            known_answers: &[],
+        };
+
+        assert_eq!(
+            check(2023, &invalid).failures().collect::<Vec<_>>(),
+            vec!["Invalid input: Not a number: x"],
+        );
+        assert_eq!(
+            check(2023, &wrong).failures().collect::<Vec<_>>(),
+            vec!["Part 1 gives 41, not 42"],
+        );
+        assert_eq!(
+            check(2023, &unknown).failures().collect::<Vec<_>>(),
+            vec!["No known answers"],
+        );
+    }
+
+    #[test]
+    fn check_should_skip_what_does_not_apply() {
+        let status = check(
+            2023,
+            &FakeSolution {
+                input: "",
+                known_answers: &["42"],
+            },
+        );
+
+        assert_eq!(
+            (&status.input, &status.parses, &status.answers),
+            (&Status::Pending, &Status::Skipped, &Status::Skipped),
+        );
+        assert_eq!(status.failures().count(), 0);
+    }
+
+    #[test]
+    fn table_should_list_the_failures_after_the_statuses() {
+        let statuses = [
+            check(
+                2023,
+                &FakeSolution {
+                    input: "42",
+                    known_answers: &["42"],
+                },
+            ),
+            check(
+                2022,
+                &FakeSolution {
+                    input: "",
+                    known_answers: &[],
+                },
+            ),
+            check(
+                2021,
+                &FakeSolution {
+                    input: "x",
+                    known_answers: &[],
+                },
+            ),
+        ];
+
+        assert_eq!(
+            table(&statuses),
+            "Year  Day  Input    Parses  Answers
+2023    3  ok       ok      ok
+2022    3  pending  -       -
+2021    3  ok       FAILED  -
+2021:3 — Invalid input: Not a number: x
+",
+        );
+    }
+
+    #[test]
+    fn every_registered_day_should_have_a_valid_input() {
+        let registry = crate::registry();
+
+        for year in registry.years() {
+            for day in registry.days(year) {
+                let solution = registry.get(year, day).unwrap();
+                // Solving every day is left to the golden tests, which run in release.
+                let (input, parses) = check_input(solution);
+                assert!(
+                    matches!(input, Status::Ok | Status::Pending),
+                    "{year}:{day}: {input:?}"
+                );
+                assert!(
+                    matches!(parses, Status::Ok | Status::Skipped),
+                    "{year}:{day}: {parses:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use std::process::Command;
+
+/// The `git describe` of the working copy, if it is in a git repository.
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -0,0 +1,192 @@
+use std::env;
+use std::path::PathBuf;
+
+use aoc_utils::DayResult;
+use rusqlite::{params, Connection};
+
+use crate::git::git_commit;
+use crate::milliseconds;
+
+const DEFAULT_PATH: &str = "history.sqlite";
+
+pub struct History(Connection);
+
+#[derive(Debug, PartialEq)]
+pub struct Trend {
+    pub commit: Option<String>,
+    pub part: u8,
+    pub runs: u32,
+    pub average_ms: f64,
+    pub answer: Option<String>,
+}
+
+impl History {
+    pub fn open() -> Result<Self, String> {
+        let path = env::var_os("AOC_HISTORY")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_PATH));
+        Self::from_connection(Connection::open(path).map_err(|error| error.to_string())?)
+    }
+
+    fn from_connection(connection: Connection) -> Result<Self, String> {
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS runs (
+                    id INTEGER PRIMARY KEY,
+                    recorded_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    git_commit TEXT,
+                    year INTEGER NOT NULL,
+                    day INTEGER NOT NULL,
+                    part INTEGER NOT NULL,
+                    answer TEXT,
+                    error TEXT,
+                    duration_ms REAL NOT NULL
+                )",
+            )
+            .map_err(|error| error.to_string())?;
+        Ok(Self(connection))
+    }
+
+    pub fn record(&self, year: u16, result: &DayResult) -> Result<(), String> {
+        self.record_for_commit(git_commit().as_deref(), year, result)
+    }
+
+    fn record_for_commit(
+        &self,
+        commit: Option<&str>,
+        year: u16,
+        result: &DayResult,
+    ) -> Result<(), String> {
+        for answer in &result.answers {
+            self.0
+                .execute(
+                    "INSERT INTO runs (git_commit, year, day, part, answer, error, duration_ms)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        commit,
+                        year,
+                        answer.day,
+                        answer.part,
+                        answer.result.as_ref().ok(),
+                        answer.result.as_ref().err(),
+                        milliseconds(answer.duration),
+                    ],
+                )
+                .map_err(|error| error.to_string())?;
+        }
+        Ok(())
+    }
+
+    pub fn trends(&self, year: u16, day: u8) -> Result<Vec<Trend>, String> {
+        let mut statement = self
+            .0
+            .prepare(
+                "SELECT git_commit, part, COUNT(*), AVG(duration_ms),
+                        (SELECT COALESCE(answer, 'Error: ' || error) FROM runs AS last
+                         WHERE last.year = runs.year AND last.day = runs.day AND last.part = runs.part
+                           AND last.git_commit IS runs.git_commit
+                         ORDER BY last.id DESC LIMIT 1)
+                 FROM runs
+                 WHERE year = ?1 AND day = ?2
+                 GROUP BY git_commit, part
+                 ORDER BY MIN(id), part",
+            )
+            .map_err(|error| error.to_string())?;
+        let trends = statement
+            .query_map(params![year, day], |row| {
+                Ok(Trend {
+                    commit: row.get(0)?,
+                    part: row.get(1)?,
+                    runs: row.get(2)?,
+                    average_ms: row.get(3)?,
+                    answer: row.get(4)?,
+                })
+            })
+            .map_err(|error| error.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| error.to_string())?;
+        Ok(trends)
+    }
+}
+
+pub fn print_trends(year: u16, day: u8) -> Result<(), String> {
+    let trends = History::open()?.trends(year, day)?;
+    if trends.is_empty() {
+        println!("No recorded runs for {year} day {day}");
+        return Ok(());
+    }
+    println!(
+        "{:<16} {:>4} {:>5} {:>12}  Answer",
+        "Commit", "Part", "Runs", "Average"
+    );
+    for trend in trends {
+        println!(
+            "{:<16} {:>4} {:>5} {:>10.1}ms  {}",
+            trend.commit.as_deref().unwrap_or("-"),
+            trend.part,
+            trend.runs,
+            trend.average_ms,
+            trend.answer.as_deref().unwrap_or_default(),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use aoc_utils::Answer;
+
+    use super::*;
+
+    fn result(part_one: Result<&str, &str>, millis: u64) -> DayResult {
+        DayResult {
+            day: 6,
+            answers: vec![Answer {
+                day: 6,
+                part: 1,
+                result: part_one.map(str::to_string).map_err(str::to_string),
+                duration: Duration::from_millis(millis),
+            }],
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn trends_should_average_runs_per_commit() {
+        let history = History::from_connection(Connection::open_in_memory().unwrap()).unwrap();
+        history
+            .record_for_commit(Some("abc1234"), 2023, &result(Ok("288"), 10))
+            .unwrap();
+        history
+            .record_for_commit(Some("abc1234"), 2023, &result(Ok("288"), 20))
+            .unwrap();
+        history
+            .record_for_commit(Some("def5678"), 2023, &result(Err("Overflow"), 5))
+            .unwrap();
+        history
+            .record_for_commit(Some("def5678"), 2022, &result(Ok("1"), 1))
+            .unwrap();
+
+        assert_eq!(
+            history.trends(2023, 6).unwrap(),
+            vec![
+                Trend {
+                    commit: Some("abc1234".to_string()),
+                    part: 1,
+                    runs: 2,
+                    average_ms: 15.0,
+                    answer: Some("288".to_string()),
+                },
+                Trend {
+                    commit: Some("def5678".to_string()),
+                    part: 1,
+                    runs: 1,
+                    average_ms: 5.0,
+                    answer: Some("Error: Overflow".to_string()),
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use aoc_utils::grid::Grid;
+use aoc_utils::random::Rng;
+use aoc_utils::{Example, Label, Progress, Solution, Variant};
+
+/// Gives the contents of a file as the input of the day, instead of the committed one.
+pub struct WithInput {
+    solution: Arc<dyn Solution>,
+    input: &'static str,
+}
+
+impl WithInput {
+    /// Reads the input from the file. Its contents are leaked, as an input lives as long as the program.
+    pub fn read(solution: Arc<dyn Solution>, path: &Path) -> Result<Self, String> {
+        let input = fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+        Ok(Self::new(solution, Box::leak(input.into_boxed_str())))
+    }
+
+    pub fn new(solution: Arc<dyn Solution>, input: &'static str) -> Self {
+        Self { solution, input }
+    }
+}
+
+impl Solution for WithInput {
+    fn day(&self) -> u8 {
+        self.solution.day()
+    }
+
+    fn input(&self) -> &'static str {
+        self.input
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        self.solution.part_one(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        self.solution.part_two(input)
+    }
+
+    fn solve_part(&self, part: u8, input: &str) -> Result<String, String> {
+        self.solution.solve_part(part, input)
+    }
+
+    fn solve_part_reporting(
+        &self,
+        part: u8,
+        input: &str,
+        progress: &Progress,
+    ) -> Result<String, String> {
+        self.solution.solve_part_reporting(part, input, progress)
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        self.solution.validate(input)
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.solution.title()
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        self.solution.labels()
+    }
+
+    fn parts(&self) -> &'static [u8] {
+        self.solution.parts()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        self.solution.examples()
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        self.solution.variants()
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        self.solution.generate(size, rng)
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        self.solution.explain(part, input)
+    }
+
+    fn visualize(&self, input: &str) -> Result<String, String> {
+        self.solution.visualize(input)
+    }
+
+    fn frames(&self, input: &str) -> Result<Vec<Grid<char>>, String> {
+        self.solution.frames(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn input(&self) -> &'static str {
+            "committed"
+        }
+
+        fn part_one(&self, input: &str) -> Result<String, String> {
+            Ok(format!("Input: {input}"))
+        }
+
+        fn part_two(&self, input: &str) -> Result<String, String> {
+            Ok(input.len().to_string())
+        }
+    }
+
+    #[test]
+    fn parts_should_be_solved_on_the_given_input() {
+        let solution = WithInput::new(Arc::new(Echo), "mine");
+
+        let result = solution.run();
+
+        assert_eq!(result.answers[0].result, Ok("Input: mine".to_string()));
+        assert_eq!(result.answers[1].result, Ok("4".to_string()));
+    }
+
+    #[test]
+    fn missing_files_should_be_reported() {
+        let error = WithInput::read(Arc::new(Echo), Path::new("missing/input.txt"))
+            .err()
+            .unwrap();
+
+        assert!(error.starts_with("Failed to read missing/input.txt: "));
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_utils::Registry;
+use dialoguer::Select;
+
+/// A day picked interactively, with the part to run, or both if `None`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Choice {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+}
+
+/// What to do once the chosen day has run.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Next {
+    Rerun,
+    Pick,
+    Quit,
+}
+
+const PARTS: [&str; 3] = ["Both parts", "Part 1", "Part 2"];
+
+const NEXT: [(&str, Next); 3] = [
+    ("Run again", Next::Rerun),
+    ("Pick another day", Next::Pick),
+    ("Quit", Next::Quit),
+];
+
+/// Asks for a day and a part among the registered solutions, starting from the previous choice if any. Returns `None`
+/// if the user gives up.
+pub fn choose(registry: &Registry, previous: Option<Choice>) -> Result<Option<Choice>, String> {
+    let days = days(registry);
+    let labels = days
+        .iter()
+        .map(|(year, day)| label(registry, *year, *day))
+        .collect::<Vec<_>>();
+    let default_day = previous
+        .and_then(|previous| {
+            days.iter()
+                .position(|day| *day == (previous.year, previous.day))
+        })
+        .unwrap_or(labels.len().saturating_sub(1));
+    let Some(index) = Select::new()
+        .with_prompt("Day")
+        .items(&labels)
+        .default(default_day)
+        .interact_opt()
+        .map_err(|error| error.to_string())?
+    else {
+        return Ok(None);
+    };
+    let Some(part) = Select::new()
+        .with_prompt("Part")
+        .items(PARTS)
+        .default(
+            previous
+                .and_then(|previous| previous.part)
+                .map_or(0, usize::from),
+        )
+        .interact_opt()
+        .map_err(|error| error.to_string())?
+    else {
+        return Ok(None);
+    };
+    let (year, day) = days[index];
+    Ok(Some(Choice {
+        year,
+        day,
+        part: u8::try_from(part).ok().filter(|part| *part > 0),
+    }))
+}
+
+/// Asks whether to run the same day again, to pick another one, or to quit, which giving up also does.
+pub fn next() -> Result<Next, String> {
+    let index = Select::new()
+        .items(NEXT.map(|(label, _)| label))
+        .default(0)
+        .interact_opt()
+        .map_err(|error| error.to_string())?;
+    Ok(index.map_or(Next::Quit, |index| NEXT[index].1))
+}
+
+fn label(registry: &Registry, year: u16, day: u8) -> String {
+    match registry
+        .get(year, day)
+        .and_then(|solution| solution.title())
+    {
+        Some(title) => format!("{year} day {day}: {title}"),
+        None => format!("{year} day {day}"),
+    }
+}
+
+fn days(registry: &Registry) -> Vec<(u16, u8)> {
+    registry
+        .years()
+        .into_iter()
+        .flat_map(|year| registry.days(year).into_iter().map(move |day| (year, day)))
+        .collect()
+}
+
+#[cfg(all(test, feature = "day7"))]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "default")]
+    fn days_should_list_every_registered_day_in_order() {
+        let days = days(&crate::registry());
+
+        assert_eq!(days.first(), Some(&(2023, 1)));
+        assert_eq!(days.last(), Some(&(2023, 24)));
+    }
+
+    #[test]
+    fn label_should_give_the_title_of_the_puzzle() {
+        assert_eq!(
+            label(&crate::registry(), 2023, 7),
+            "2023 day 7: Camel Cards"
+        );
+    }
+}
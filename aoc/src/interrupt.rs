@@ -0,0 +1,100 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_utils::Answer;
+use signal_hook::consts::SIGINT;
+use signal_hook::iterator::Signals;
+
+use crate::milliseconds;
+
+/// The exit code of a process interrupted by SIGINT.
+const INTERRUPTED: i32 = 130;
+
+/// Shows the cursor and resets the colors, in case an animation or a prompt was interrupted.
+const RESTORE_TERMINAL: &str = "\x1b[?25h\x1b[0m";
+
+/// The parts completed so far, with their year.
+static COMPLETED: Mutex<Vec<(u16, Answer)>> = Mutex::new(Vec::new());
+
+/// Whether the handler is installed, as the interactive mode runs days several times.
+static INSTALLED: OnceLock<Result<(), String>> = OnceLock::new();
+
+/// Prints the parts completed so far when the run is interrupted with Ctrl-C, instead of losing them, then exits.
+pub fn install() -> Result<(), String> {
+    INSTALLED.get_or_init(handle_interruptions).clone()
+}
+
+fn handle_interruptions() -> Result<(), String> {
+    let mut signals = Signals::new([SIGINT]).map_err(|error| error.to_string())?;
+    thread::spawn(move || {
+        if signals.forever().next().is_some() {
+            if io::stdout().is_terminal() {
+                print!("{RESTORE_TERMINAL}");
+                let _ = io::stdout().flush();
+            }
+            let completed = COMPLETED.lock().unwrap_or_else(PoisonError::into_inner);
+            eprint!("{}", summary(&completed));
+            crate::exit(INTERRUPTED);
+        }
+    });
+    Ok(())
+}
+
+pub fn record(year: u16, answer: &Answer) {
+    COMPLETED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((year, answer.clone()));
+}
+
+fn summary(completed: &[(u16, Answer)]) -> String {
+    let mut summary = format!("\nInterrupted after {} parts\n", completed.len());
+    for (year, answer) in completed {
+        let result = match &answer.result {
+            Ok(text) => text.clone(),
+            Err(error) => format!("Error: {error}"),
+        };
+        summary.push_str(&format!(
+            "{year}:{}:{} — {result} ({:.1}ms)\n",
+            answer.day,
+            answer.part,
+            milliseconds(answer.duration),
+        ));
+    }
+    let total = completed
+        .iter()
+        .map(|(_, answer)| answer.duration)
+        .sum::<Duration>();
+    summary.push_str(&format!("Total {:.1}ms\n", milliseconds(total)));
+    summary
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn summary_should_list_the_completed_parts() {
+        let answer = |part, result: Result<&str, &str>| Answer {
+            day: 8,
+            part,
+            result: result.map(str::to_string).map_err(str::to_string),
+            duration: Duration::from_millis(part.into()),
+        };
+
+        assert_eq!(
+            summary(&[
+                (2023, answer(1, Ok("Steps: 6"))),
+                (2023, answer(2, Err("Unknown node: XXX")))
+            ]),
+            "
+Interrupted after 2 parts
+2023:8:1 — Steps: 6 (1.0ms)
+2023:8:2 — Error: Unknown node: XXX (2.0ms)
+Total 3.0ms
+",
+        );
+    }
+}
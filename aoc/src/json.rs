@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use aoc_utils::{answer_value, Answer, DayResult, Solution};
+use serde::Serialize;
+
+/// The answers and timings of a day, printed as one JSON document once the day is done.
+#[derive(Debug, Serialize)]
+struct Document<'a> {
+    year: u16,
+    day: u8,
+    parts: Vec<Part<'a>>,
+    duration_ns: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Part<'a> {
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<&'a str>,
+    /// The value at the end of the answer, without its label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    duration_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explanation: Option<&'a [String]>,
+}
+
+fn nanoseconds(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl<'a> Part<'a> {
+    fn from_answer(answer: &'a Answer, explanation: Option<&'a [String]>) -> Self {
+        let (text, error) = match &answer.result {
+            Ok(text) => (Some(text.as_str()), None),
+            Err(error) => (None, Some(error.as_str())),
+        };
+        Part {
+            part: answer.part,
+            answer: text,
+            value: text.map(answer_value),
+            error,
+            duration_ns: nanoseconds(answer.duration),
+            explanation,
+        }
+    }
+}
+
+fn document<'a>(year: u16, result: &'a DayResult, explanations: &'a [Vec<String>]) -> Document<'a> {
+    Document {
+        year,
+        day: result.day,
+        parts: result
+            .answers
+            .iter()
+            .enumerate()
+            .map(|(index, answer)| {
+                Part::from_answer(answer, explanations.get(index).map(Vec::as_slice))
+            })
+            .collect(),
+        duration_ns: nanoseconds(result.duration),
+    }
+}
+
+/// Runs the parts of the day and prints the document, with the explanations of the parts if asked for.
+pub fn execute(year: u16, solution: &dyn Solution, parts: &[u8], explain: bool) -> DayResult {
+    let result = solution.run_parts_with(solution.input(), parts, &mut |_| {});
+    let explanations = match explain {
+        true => parts
+            .iter()
+            .map(|part| solution.explain(*part, solution.input()))
+            .collect(),
+        false => Vec::new(),
+    };
+    println!(
+        "{}",
+        serde_json::to_string(&document(year, &result, &explanations)).unwrap()
+    );
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn result() -> DayResult {
+        DayResult {
+            day: 6,
+            answers: vec![
+                Answer {
+                    day: 6,
+                    part: 1,
+                    result: Ok("Product of all ways to win races: 288".to_string()),
+                    duration: Duration::from_nanos(1500),
+                },
+                Answer {
+                    day: 6,
+                    part: 2,
+                    result: Err("Missing Time: line".to_string()),
+                    duration: Duration::from_micros(2),
+                },
+            ],
+            duration: Duration::from_micros(4),
+        }
+    }
+
+    #[test]
+    fn day_should_be_one_json_document() {
+        assert_eq!(
+            serde_json::to_string(&document(2023, &result(), &[])).unwrap(),
+            concat!(
+                r#"{"year":2023,"day":6,"parts":["#,
+                r#"{"part":1,"answer":"Product of all ways to win races: 288","value":"288","duration_ns":1500},"#,
+                r#"{"part":2,"error":"Missing Time: line","duration_ns":2000}"#,
+                r#"],"duration_ns":4000}"#
+            ),
+        );
+    }
+
+    #[test]
+    fn explanations_should_go_with_their_part() {
+        let result = result();
+        let explanations = [vec!["Race 1: 4 ways".to_string()], Vec::new()];
+
+        let document = document(2023, &result, &explanations);
+
+        assert_eq!(
+            serde_json::to_string(&document.parts[0]).unwrap(),
+            r#"{"part":1,"answer":"Product of all ways to win races: 288","value":"288","duration_ns":1500,"explanation":["Race 1: 4 ways"]}"#,
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_utils::{Answer, DayResult, Solution};
+use serde::Serialize;
+
+use crate::milliseconds;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    DayStarted {
+        year: u16,
+        day: u8,
+    },
+    PartFinished {
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &'a str,
+        duration_ms: f64,
+    },
+    PartFailed {
+        year: u16,
+        day: u8,
+        part: u8,
+        error: &'a str,
+        duration_ms: f64,
+    },
+    PartExplained {
+        year: u16,
+        day: u8,
+        part: u8,
+        lines: &'a [String],
+    },
+    DayFinished {
+        year: u16,
+        day: u8,
+        duration_ms: f64,
+    },
+}
+
+impl<'a> Event<'a> {
+    fn from_answer(year: u16, answer: &'a Answer) -> Self {
+        let duration_ms = milliseconds(answer.duration);
+        match &answer.result {
+            Ok(text) => Event::PartFinished {
+                year,
+                day: answer.day,
+                part: answer.part,
+                answer: text,
+                duration_ms,
+            },
+            Err(error) => Event::PartFailed {
+                year,
+                day: answer.day,
+                part: answer.part,
+                error,
+                duration_ms,
+            },
+        }
+    }
+}
+
+fn emit(event: &Event) {
+    println!("{}", serde_json::to_string(event).unwrap());
+}
+
+pub fn execute(year: u16, solution: &dyn Solution, parts: &[u8]) -> DayResult {
+    let day = solution.day();
+    emit(&Event::DayStarted { year, day });
+    let result = solution.run_parts_with(solution.input(), parts, &mut |answer| {
+        emit(&Event::from_answer(year, answer))
+    });
+    emit(&Event::DayFinished {
+        year,
+        day,
+        duration_ms: milliseconds(result.duration),
+    });
+    result
+}
+
+pub fn explain(year: u16, day: u8, part: u8, lines: &[String]) {
+    emit(&Event::PartExplained {
+        year,
+        day,
+        part,
+        lines,
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn answer(result: Result<&str, &str>) -> Answer {
+        Answer {
+            day: 6,
+            part: 2,
+            result: result.map(str::to_string).map_err(str::to_string),
+            duration: Duration::from_micros(1500),
+        }
+    }
+
+    #[test]
+    fn finished_part_should_be_one_json_line() {
+        let answer = answer(Ok("Ways to win the race: 71503"));
+
+        assert_eq!(
+            serde_json::to_string(&Event::from_answer(2023, &answer)).unwrap(),
+            r#"{"event":"part_finished","year":2023,"day":6,"part":2,"answer":"Ways to win the race: 71503","duration_ms":1.5}"#,
+        );
+    }
+
+    #[test]
+    fn failed_part_should_carry_the_error() {
+        let answer = answer(Err("Missing Time: line"));
+
+        assert_eq!(
+            serde_json::to_string(&Event::from_answer(2023, &answer)).unwrap(),
+            r#"{"event":"part_failed","year":2023,"day":6,"part":2,"error":"Missing Time: line","duration_ms":1.5}"#,
+        );
+    }
+
+    #[test]
+    fn explanation_should_carry_all_lines() {
+        let lines = ["Game 1 is possible".to_string()];
+        let event = Event::PartExplained {
+            year: 2023,
+            day: 2,
+            part: 1,
+            lines: &lines,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event":"part_explained","year":2023,"day":2,"part":1,"lines":["Game 1 is possible"]}"#,
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_utils::Registry;
+
+/// The registered days of the years, with their puzzle title, the parts they solve, and whether their input is
+/// committed. The days without input are listed again at the end, as their answers cannot be checked.
+pub fn table(registry: &Registry, years: &[u16]) -> String {
+    let mut missing = Vec::new();
+    let mut table = format!(
+        "{:<4}  {:>3}  {:<32}  {:<5}  {}\n",
+        "Year", "Day", "Title", "Parts", "Input"
+    );
+    for &year in years {
+        for day in registry.days(year) {
+            let Some(solution) = registry.get(year, day) else {
+                continue;
+            };
+            let parts = solution
+                .parts()
+                .iter()
+                .map(u8::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let input = match solution.input().is_empty() {
+                true => {
+                    missing.push(format!("{year}:{day}"));
+                    "missing"
+                }
+                false => "yes",
+            };
+            table.push_str(&format!(
+                "{year:<4}  {day:>3}  {:<32}  {parts:<5}  {input}\n",
+                solution.title().unwrap_or("-")
+            ));
+        }
+    }
+    if !missing.is_empty() {
+        table.push_str(&format!(
+            "No input for {}, whose answers are not checked\n",
+            missing.join(", ")
+        ));
+    }
+    table
+}
+
+/// The days that have a solution in the year, on one line.
+pub fn days_line(registry: &Registry, year: u16) -> String {
+    let days = registry.days(year);
+    if days.is_empty() {
+        return format!("No day has a solution in {year}");
+    }
+    let days = days.iter().map(u8::to_string).collect::<Vec<_>>();
+    format!("Days with a solution in {year}: {}", days.join(", "))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "default")]
+    fn table_should_list_the_days_in_order() {
+        let table = table(&crate::registry(), &[2023]);
+        let mut lines = table.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("Year  Day  Title                             Parts  Input")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("2023    1  Trebuchet?!                       1, 2   yes")
+        );
+        assert_eq!(
+            lines.next_back(),
+            Some(
+                "No input for 2023:9, 2023:10, 2023:16, 2023:17, 2023:20, 2023:24, whose answers are not checked"
+            )
+        );
+        assert_eq!(
+            lines.next_back(),
+            Some("2023   24  Never Tell Me The Odds            1, 2   missing")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "default")]
+    fn days_line_should_list_the_days_of_the_year() {
+        assert_eq!(
+            days_line(&crate::registry(), 2023),
+            "Days with a solution in 2023: 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 17, 20, 24"
+        );
+        assert_eq!(
+            days_line(&crate::registry(), 2015),
+            "No day has a solution in 2015"
+        );
+    }
+
+    #[test]
+    fn table_of_a_year_without_days_should_only_have_the_header() {
+        assert_eq!(table(&crate::registry(), &[2015]).lines().count(), 1);
+    }
+}
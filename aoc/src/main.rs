@@ -0,0 +1,503 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "day2")]
+use advent2023::day2::Day2;
+use aoc_utils::random::Rng;
+use aoc_utils::{input, render, Answer, DayResult, Registry, Solution};
+
+use crate::messages::Catalog;
+use crate::options::{Command, Days, Format, Options};
+
+mod batch;
+mod bench;
+mod chart;
+mod cross_check;
+mod doctor;
+mod git;
+#[cfg(feature = "history")]
+mod history;
+mod input_file;
+mod interactive;
+mod interrupt;
+mod json;
+mod jsonl;
+mod list;
+mod messages;
+#[cfg(feature = "notify")]
+mod notify;
+mod options;
+#[cfg(feature = "plugins")]
+mod plugins;
+mod progress;
+mod run_log;
+mod selftest;
+mod stats;
+mod style;
+mod summary;
+#[cfg(feature = "otlp")]
+mod telemetry;
+mod timeout;
+
+const FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// The exit code of a run with invalid arguments or an unknown day. A part that fails exits with 1, and a solution
+/// that panics with the 101 of any Rust program.
+const USAGE_ERROR: i32 = 2;
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    advent2022::register(&mut registry);
+    advent2023::register(&mut registry);
+    advent2024::register(&mut registry);
+    #[cfg(feature = "plugins")]
+    plugins::load(&mut registry);
+    registry
+}
+
+/// Runs the days of the options, and returns whether every part succeeded.
+fn run(options: Options) -> bool {
+    #[cfg(not(feature = "notify"))]
+    if options.notify.is_some() {
+        usage_error("--notify requires the notify feature", options.year);
+    }
+    let mut registry = registry();
+    if let Some(day2) = day2_with_cube_limits(&options) {
+        registry.register(advent2023::YEAR, day2);
+    }
+    let _span = tracing::info_span!("run", year = options.year).entered();
+    let days = match &options.days {
+        Days::One(day) => {
+            existing(&registry, options.year, *day);
+            vec![*day]
+        }
+        Days::Many(days) => {
+            let days = runnable(&registry, options.year, days);
+            if days.is_empty() {
+                usage_error("None of the days can be run", options.year);
+            }
+            days
+        }
+        Days::All => runnable(&registry, options.year, &registry.days(options.year)),
+        Days::Latest => match registry
+            .days(options.year)
+            .into_iter()
+            .rev()
+            .find(|day| skip_reason(&registry, options.year, *day).is_none())
+        {
+            Some(day) => vec![day],
+            None => usage_error("None of the days can be run", options.year),
+        },
+    };
+    let solutions = days
+        .into_iter()
+        .filter_map(|day| registry.get_shared(options.year, day))
+        .map(|solution| match &options.input {
+            Some(path) => input_file::WithInput::read(solution, path)
+                .map(|solution| Arc::new(solution) as Arc<dyn Solution>),
+            None => Ok(solution),
+        })
+        .collect::<Result<Vec<_>, _>>();
+    let solutions = match solutions {
+        Ok(solutions) => solutions,
+        Err(error) => {
+            eprintln!("{error}");
+            exit(1);
+        }
+    };
+    let solutions = solutions
+        .into_iter()
+        .map(|solution| match options.cross_check {
+            true => Arc::new(cross_check::CrossChecked::new(solution)),
+            false => solution,
+        })
+        .map(|solution| match options.timeout {
+            Some(timeout) => Arc::new(timeout::WithTimeout::new(solution, timeout)),
+            None => solution,
+        })
+        .collect::<Vec<_>>();
+    if options.example {
+        let mut failed = false;
+        for solution in solutions {
+            failed |= !run_examples(solution.as_ref(), options.parts(), options.messages);
+        }
+        return !failed;
+    }
+    if let Some(dir) = &options.inputs_dir {
+        let mut failed = false;
+        for solution in solutions {
+            if let Err(error) = batch::run(solution.as_ref(), dir, options.messages) {
+                eprintln!("{error}");
+                failed = true;
+            }
+        }
+        return !failed;
+    }
+    if options.format == Format::Text {
+        if let Err(error) = interrupt::install() {
+            eprintln!("Failed to handle Ctrl-C: {error}");
+        }
+    }
+    let results = solutions
+        .iter()
+        .map(|solution| (options.year, run_day(&options, solution.as_ref())))
+        .collect::<Vec<_>>();
+    if results.len() > 1 && options.format == Format::Text {
+        print!(
+            "{}",
+            summary::summary(&results, options.budget, options.part_budget)
+        );
+    }
+    #[cfg(feature = "notify")]
+    if let Some(url) = &options.notify {
+        if let Err(error) = notify::send(url, options.notify_format, &results) {
+            eprintln!("Failed to notify {url}: {error}");
+        }
+    }
+    results
+        .iter()
+        .all(|(_, result)| result.answers.iter().all(|answer| answer.result.is_ok()))
+}
+
+/// The days that have a solution and an input, in order. The others are skipped with a note.
+fn runnable(registry: &Registry, year: u16, days: &[u8]) -> Vec<u8> {
+    days.iter()
+        .copied()
+        .filter(|day| match skip_reason(registry, year, *day) {
+            Some(reason) => {
+                eprintln!("Skipping day {day}, which {reason}");
+                false
+            }
+            None => true,
+        })
+        .collect()
+}
+
+/// Why a day cannot be run, if it cannot.
+fn skip_reason(registry: &Registry, year: u16, day: u8) -> Option<&'static str> {
+    match registry.get(year, day) {
+        None => Some("has no solution"),
+        Some(solution) if solution.input().is_empty() => Some("has no input yet"),
+        Some(_) => None,
+    }
+}
+
+/// Solves the examples of the puzzle statement, to compare the answers with the puzzle text. Returns whether every
+/// example could be solved.
+/// Solves the examples of the parts, and returns whether every one succeeded.
+fn run_examples(solution: &dyn Solution, parts: &[u8], messages: Catalog) -> bool {
+    let examples = solution
+        .examples()
+        .into_iter()
+        .filter(|example| parts.contains(&example.part))
+        .collect::<Vec<_>>();
+    if examples.is_empty() {
+        eprintln!("No examples for day {}", solution.day());
+    }
+    let mut solved = true;
+    for example in examples {
+        let start = Instant::now();
+        let result = solution.solve_part(example.part, example.input);
+        solved &= result.is_ok();
+        print!(
+            "{}",
+            messages.answer(
+                &Answer {
+                    day: solution.day(),
+                    part: example.part,
+                    result,
+                    duration: start.elapsed(),
+                },
+                messages::label(solution, example.part),
+            )
+        );
+    }
+    solved
+}
+
+fn run_day(options: &Options, solution: &dyn Solution) -> DayResult {
+    if options.animate {
+        let animation = solution.frames(solution.input()).and_then(|frames| {
+            render::animate(frames, FRAME_DELAY).map_err(|error| error.to_string())
+        });
+        if let Err(error) = animation {
+            eprintln!("{error}");
+        }
+    }
+    let result = match options.format {
+        Format::Text => {
+            let style = style::Style::new(options.color, options.part_budget);
+            print!("{}", style.header(options.year, solution));
+            let bar = progress::Bar::default();
+            let result = solution.run_parts_reporting(
+                solution.input(),
+                options.parts(),
+                &bar.progress(),
+                &mut |answer| {
+                    bar.clear();
+                    let known = style::known_answer(solution, answer.part);
+                    print!(
+                        "{}",
+                        style.answer(
+                            &options
+                                .messages
+                                .answer(answer, messages::label(solution, answer.part)),
+                            answer,
+                            known
+                        )
+                    );
+                    interrupt::record(options.year, answer);
+                },
+            );
+            bar.finish();
+            print!("{}", options.messages.footer(&result));
+            result
+        }
+        Format::Json => json::execute(options.year, solution, options.parts(), options.explain),
+        Format::Jsonl => jsonl::execute(options.year, solution, options.parts()),
+    };
+    if options.explain && options.format != Format::Json {
+        for &part in options.parts() {
+            let lines = solution.explain(part, solution.input());
+            match options.format {
+                Format::Text => print_explanation(part, &lines),
+                Format::Jsonl => jsonl::explain(options.year, solution.day(), part, &lines),
+                Format::Json => {}
+            }
+        }
+    }
+    #[cfg(feature = "history")]
+    if let Err(error) =
+        history::History::open().and_then(|history| history.record(options.year, &result))
+    {
+        eprintln!("Failed to record the run: {error}");
+    }
+    if let Some(path) = &options.log_file {
+        if let Err(error) = run_log::append(path, options.year, &result) {
+            eprintln!("Failed to log the run: {error}");
+        }
+    }
+    result
+}
+
+#[cfg(feature = "day2")]
+fn day2_with_cube_limits(options: &Options) -> Option<Box<dyn Solution>> {
+    if options.red.is_none() && options.green.is_none() && options.blue.is_none() {
+        return None;
+    }
+    let defaults = Day2::default();
+    Some(Box::new(Day2 {
+        red: options.red.unwrap_or(defaults.red),
+        green: options.green.unwrap_or(defaults.green),
+        blue: options.blue.unwrap_or(defaults.blue),
+    }))
+}
+
+#[cfg(not(feature = "day2"))]
+fn day2_with_cube_limits(options: &Options) -> Option<Box<dyn Solution>> {
+    if options.red.is_some() || options.green.is_some() || options.blue.is_some() {
+        usage_error(
+            "--red, --green and --blue need the day2 feature",
+            options.year,
+        );
+    }
+    None
+}
+
+/// Exits with the code once the traces are exported, which `std::process::exit` alone would lose.
+fn exit(code: i32) -> ! {
+    #[cfg(feature = "otlp")]
+    telemetry::shutdown();
+    std::process::exit(code)
+}
+
+/// Exits with 1 if a part failed.
+fn exit_if(failed: bool) {
+    if failed {
+        exit(1);
+    }
+}
+
+/// Prints the error with the usage and the days that have a solution, and exits.
+fn usage_error(error: &str, year: u16) -> ! {
+    eprintln!(
+        "{error}\n\n{}\n{}",
+        options::USAGE,
+        list::days_line(&registry(), year)
+    );
+    exit(USAGE_ERROR);
+}
+
+/// The solution of the day, or a usage error if there is none.
+fn existing(registry: &Registry, year: u16, day: u8) -> &dyn Solution {
+    registry
+        .get(year, day)
+        .unwrap_or_else(|| usage_error(&format!("No solution for day {day} of {year}"), year))
+}
+
+/// Asks for a day and a part, runs them, and offers to run them again or to pick another day until the user quits.
+fn pick_and_run() {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        usage_error("Missing day", options::DEFAULT_YEAR);
+    }
+    let registry = registry();
+    let mut previous = None;
+    loop {
+        let choice = match interactive::choose(&registry, previous) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return,
+            Err(error) => {
+                eprintln!("{error}");
+                return;
+            }
+        };
+        loop {
+            run(Options {
+                part: choice.part,
+                ..Options::new(choice.year, Days::One(choice.day))
+            });
+            match interactive::next() {
+                Ok(interactive::Next::Rerun) => {}
+                Ok(interactive::Next::Pick) => break,
+                Ok(interactive::Next::Quit) => return,
+                Err(error) => {
+                    eprintln!("{error}");
+                    return;
+                }
+            }
+        }
+        previous = Some(choice);
+    }
+}
+
+fn print_explanation(part: u8, lines: &[String]) {
+    if !lines.is_empty() {
+        println!("Part {part}:");
+        for line in lines {
+            println!("  {line}");
+        }
+    }
+}
+
+fn input_stats(year: u16, day: u8) {
+    let registry = registry();
+    let solution = existing(&registry, year, day);
+    println!("Input:\n{}", input::input_stats(solution.input()));
+    for example in solution.examples() {
+        println!(
+            "Example of part {}:\n{}",
+            example.part,
+            input::input_stats(example.input)
+        );
+    }
+}
+
+fn generate(year: u16, day: u8, size: usize, seed: u64) {
+    let registry = registry();
+    match existing(&registry, year, day).generate(size, &mut Rng::new(seed)) {
+        Ok(input) => print!("{input}"),
+        Err(error) => {
+            eprintln!("{error}");
+            exit(1);
+        }
+    }
+}
+
+fn bench(year: u16, day: u8, part: Option<u8>, iterations: usize, warmup: usize) {
+    let registry = registry();
+    let solution = existing(&registry, year, day);
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    if let Err(error) = interrupt::install() {
+        eprintln!("Failed to handle Ctrl-C: {error}");
+    }
+    if let Err(error) = bench::run(year, solution, &parts, iterations, warmup) {
+        eprintln!("{error}");
+        exit(1);
+    }
+}
+
+fn visualize(year: u16, day: u8) {
+    let registry = registry();
+    let solution = existing(&registry, year, day);
+    match solution.visualize(solution.input()) {
+        Ok(image) => print!("{image}"),
+        Err(error) => {
+            eprintln!("{error}");
+            exit(1);
+        }
+    }
+}
+
+#[cfg(feature = "history")]
+fn show_history(year: u16, day: u8) {
+    if let Err(error) = history::print_trends(year, day) {
+        eprintln!("Failed to read the history: {error}");
+        exit(1);
+    }
+}
+
+#[cfg(not(feature = "history"))]
+fn show_history(year: u16, _day: u8) {
+    usage_error("The history subcommand requires the history feature", year);
+}
+
+fn main() {
+    #[cfg(feature = "otlp")]
+    let _telemetry = telemetry::init()
+        .map_err(|error| eprintln!("Failed to set up telemetry: {error}"))
+        .ok();
+
+    match Command::from_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => exit_if(!run(options)),
+        Ok(Command::History { year, day }) => show_history(year, day),
+        Ok(Command::Visualize { year, day }) => visualize(year, day),
+        Ok(Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        }) => generate(year, day, size, seed),
+        Ok(Command::Bench {
+            year,
+            day,
+            part,
+            iterations,
+            warmup,
+        }) => bench(year, day, part, iterations, warmup),
+        Ok(Command::Stats { readme }) => {
+            if let Err(error) = interrupt::install() {
+                eprintln!("Failed to handle Ctrl-C: {error}");
+            }
+            if let Err(error) = stats::run(&registry(), readme.as_deref()) {
+                eprintln!("Failed to compute the stats: {error}");
+                exit(1);
+            }
+        }
+        Ok(Command::InputStats { year, day }) => input_stats(year, day),
+        Ok(Command::Interactive) => pick_and_run(),
+        Ok(Command::List { year }) => {
+            let registry = registry();
+            let years = year.map_or_else(|| registry.years(), |year| vec![year]);
+            print!("{}", list::table(&registry, &years));
+        }
+        Ok(Command::Doctor) => {
+            if let Err(error) = doctor::run(&registry()) {
+                eprintln!("{error}");
+                exit(1);
+            }
+        }
+        Ok(Command::Selftest) => {
+            if let Err(error) = selftest::run(&registry()) {
+                eprintln!("{error}");
+                exit(1);
+            }
+        }
+        Err(error) => usage_error(&error, options::DEFAULT_YEAR),
+    }
+}
@@ -0,0 +1,206 @@
+use aoc_utils::{answer_value, Answer, DayResult, Label, Solution};
+
+/// How the answers and the timings are worded.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Catalog {
+    English,
+    French,
+    /// Only the values and the timings, on one line per part.
+    Terse,
+}
+
+/// The French labels of the answers, by message key.
+const FRENCH_LABELS: &[(&str, &str)] = &[
+    (
+        "calibration-sum",
+        "Somme de toutes les valeurs de calibration",
+    ),
+    (
+        "fixed-calibration-sum",
+        "Somme de toutes les valeurs de calibration corrigées",
+    ),
+    (
+        "possible-games-sum",
+        "Somme des identifiants des parties possibles",
+    ),
+    (
+        "minimum-powers-sum",
+        "Somme des puissances minimales de toutes les parties",
+    ),
+    ("part-numbers-sum", "Somme de tous les numéros de pièces"),
+    ("gear-ratios-sum", "Somme de tous les rapports d'engrenages"),
+    (
+        "scratchcard-points-sum",
+        "Somme des points de toutes les cartes à gratter",
+    ),
+    ("scratchcard-count", "Nombre total de cartes à gratter"),
+    ("minimal-location", "Emplacement minimal"),
+    (
+        "minimal-location-with-ranges",
+        "Emplacement minimal avec les intervalles",
+    ),
+    (
+        "ways-to-win-product",
+        "Produit des façons de gagner les courses",
+    ),
+    ("ways-to-win", "Façons de gagner la course"),
+    ("total-winnings", "Gains totaux"),
+    ("total-winnings-with-jokers", "Gains totaux avec les jokers"),
+    ("wasteland-steps", "Pas pour traverser le désert"),
+    (
+        "wasteland-ghost-steps",
+        "Pas pour traverser le désert en fantôme",
+    ),
+    ("extrapolated-values-sum", "Somme des valeurs extrapolées"),
+    (
+        "backward-extrapolated-values-sum",
+        "Somme des valeurs extrapolées vers le passé",
+    ),
+    (
+        "farthest-loop-point-steps",
+        "Pas jusqu'au point le plus éloigné de la boucle",
+    ),
+    ("enclosed-tiles", "Tuiles enfermées par la boucle"),
+    ("energized-tiles", "Tuiles énergisées"),
+    ("max-energized-tiles", "Nombre maximal de tuiles énergisées"),
+    ("least-heat-loss", "Perte de chaleur minimale"),
+    (
+        "least-heat-loss-ultra",
+        "Perte de chaleur minimale avec les ultra-creusets",
+    ),
+    ("pulses-product", "Produit des impulsions basses et hautes"),
+    (
+        "machine-start-presses",
+        "Appuis sur le bouton pour démarrer la machine",
+    ),
+    ("test-area-crossings", "Croisements dans la zone de test"),
+    (
+        "initial-position-sum",
+        "Somme des coordonnées de la position initiale",
+    ),
+];
+
+impl Catalog {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "en" => Ok(Self::English),
+            "fr" => Ok(Self::French),
+            "terse" => Ok(Self::Terse),
+            _ => Err(format!("Unknown messages: {name}")),
+        }
+    }
+
+    /// The answer to a part with its duration, with its label in the language of the catalog if the part has one.
+    pub fn answer(self, answer: &Answer, label: Option<Label>) -> String {
+        let Answer { day, part, .. } = answer;
+        let millis = answer.duration.as_millis();
+        match (self, &answer.result) {
+            (Self::English, _) => answer.to_string(),
+            (Self::French, Ok(text)) => {
+                let text = match label.and_then(|label| french(label.key)) {
+                    Some(label) => format!("{label} : {}", answer_value(text)),
+                    None => text.clone(),
+                };
+                format!("{day}:{part} — {text}\nPartie {part} en {millis}ms\n")
+            }
+            (Self::French, Err(error)) => {
+                format!("{day}:{part} — Erreur : {error}\nPartie {part} en {millis}ms\n")
+            }
+            (Self::Terse, Ok(text)) => format!("{day}:{part} {} {millis}ms\n", answer_value(text)),
+            (Self::Terse, Err(error)) => format!("{day}:{part} error: {error} {millis}ms\n"),
+        }
+    }
+
+    /// The line after the answers of a day.
+    pub fn footer(self, result: &DayResult) -> String {
+        match self {
+            Self::English => result.footer(),
+            Self::French => format!("Terminé en {}ms\n", result.duration.as_millis()),
+            Self::Terse => String::new(),
+        }
+    }
+}
+
+/// The label of the answer to a part, if the day labels its answers.
+pub fn label(solution: &dyn Solution, part: u8) -> Option<Label> {
+    let index = usize::from(part).checked_sub(1)?;
+    solution.labels().get(index).copied()
+}
+
+fn french(key: &str) -> Option<&'static str> {
+    FRENCH_LABELS
+        .iter()
+        .find(|(french_key, _)| *french_key == key)
+        .map(|(_, label)| *label)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn answer(result: Result<&str, &str>) -> Answer {
+        Answer {
+            day: 7,
+            part: 2,
+            result: result.map(str::to_string).map_err(str::to_string),
+            duration: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "day7")]
+    fn english_should_be_the_default_output() {
+        let answer = answer(Ok("Total winnings with jokers: 5905"));
+
+        assert_eq!(
+            Catalog::English.answer(&answer, label(&advent2023::day7::Day7, 2)),
+            answer.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day7")]
+    fn french_should_translate_the_labels_by_key() {
+        let jokers = label(&advent2023::day7::Day7, 2);
+
+        assert_eq!(
+            Catalog::French.answer(&answer(Ok("Total winnings with jokers: 5905")), jokers),
+            "7:2 — Gains totaux avec les jokers : 5905\nPartie 2 en 3ms\n",
+        );
+        assert_eq!(
+            Catalog::French.answer(&answer(Ok("Unlabelled: 5905")), None),
+            "7:2 — Unlabelled: 5905\nPartie 2 en 3ms\n",
+        );
+        assert_eq!(
+            Catalog::French.answer(&answer(Err("Total winnings overflow")), jokers),
+            "7:2 — Erreur : Total winnings overflow\nPartie 2 en 3ms\n",
+        );
+    }
+
+    #[test]
+    fn every_answer_label_should_have_a_french_translation() {
+        let registry = crate::registry();
+
+        for year in registry.years() {
+            for day in registry.days(year) {
+                for label in registry.get(year, day).unwrap().labels() {
+                    assert!(french(label.key).is_some(), "{}", label.key);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn terse_should_only_keep_the_values() {
+        assert_eq!(
+            Catalog::Terse.answer(&answer(Ok("Total winnings with jokers: 5905")), None),
+            "7:2 5905 3ms\n",
+        );
+        assert_eq!(
+            Catalog::Terse.answer(&answer(Err("Total winnings overflow")), None),
+            "7:2 error: Total winnings overflow 3ms\n",
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_utils::DayResult;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::milliseconds;
+use crate::options::NotifyFormat;
+
+/// The days of a run, sent in a single notification.
+#[derive(Debug, Serialize)]
+struct RunSummary<'a> {
+    days: Vec<Summary<'a>>,
+    failures: usize,
+    duration_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct Summary<'a> {
+    year: u16,
+    day: u8,
+    answers: Vec<PartSummary<'a>>,
+    failures: usize,
+    duration_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct PartSummary<'a> {
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    duration_ms: f64,
+}
+
+impl<'a> Summary<'a> {
+    fn new(year: u16, result: &'a DayResult) -> Self {
+        Self {
+            year,
+            day: result.day,
+            answers: result
+                .answers
+                .iter()
+                .map(|answer| PartSummary {
+                    part: answer.part,
+                    answer: answer.result.as_deref().ok(),
+                    error: answer.result.as_ref().err().map(String::as_str),
+                    duration_ms: milliseconds(answer.duration),
+                })
+                .collect(),
+            failures: result
+                .answers
+                .iter()
+                .filter(|answer| answer.result.is_err())
+                .count(),
+            duration_ms: milliseconds(result.duration),
+        }
+    }
+}
+
+impl<'a> RunSummary<'a> {
+    fn new(results: &'a [(u16, DayResult)]) -> Self {
+        let days = results
+            .iter()
+            .map(|(year, result)| Summary::new(*year, result))
+            .collect::<Vec<_>>();
+        Self {
+            failures: days.iter().map(|day| day.failures).sum(),
+            duration_ms: days.iter().map(|day| day.duration_ms).sum(),
+            days,
+        }
+    }
+}
+
+fn payload(format: NotifyFormat, results: &[(u16, DayResult)]) -> Value {
+    match format {
+        NotifyFormat::Json => json!(RunSummary::new(results)),
+        NotifyFormat::Slack => json!({ "text": message(results) }),
+        NotifyFormat::Discord => json!({ "content": message(results) }),
+    }
+}
+
+fn message(results: &[(u16, DayResult)]) -> String {
+    results
+        .iter()
+        .map(|(year, result)| {
+            format!(
+                "Advent of Code {year}, day {}\n```\n{result}```",
+                result.day
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Posts a single summary of all the days of a run to the webhook.
+pub fn send(url: &str, format: NotifyFormat, results: &[(u16, DayResult)]) -> Result<(), String> {
+    ureq::post(url)
+        .send_json(payload(format, results))
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use aoc_utils::Answer;
+
+    use super::*;
+
+    fn result() -> DayResult {
+        DayResult {
+            day: 6,
+            answers: vec![
+                Answer {
+                    day: 6,
+                    part: 1,
+                    result: Ok("Product of all ways to win races: 288".to_string()),
+                    duration: Duration::from_millis(1),
+                },
+                Answer {
+                    day: 6,
+                    part: 2,
+                    result: Err("Missing Time: line".to_string()),
+                    duration: Duration::from_millis(2),
+                },
+            ],
+            duration: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn json_payload_should_summarize_answers_and_failures() {
+        assert_eq!(
+            payload(NotifyFormat::Json, &[(2023, result())]),
+            json!({
+                "days": [{
+                    "year": 2023,
+                    "day": 6,
+                    "answers": [
+                        {"part": 1, "answer": "Product of all ways to win races: 288", "duration_ms": 1.0},
+                        {"part": 2, "error": "Missing Time: line", "duration_ms": 2.0},
+                    ],
+                    "failures": 1,
+                    "duration_ms": 3.0,
+                }],
+                "failures": 1,
+                "duration_ms": 3.0,
+            }),
+        );
+    }
+
+    #[test]
+    fn payloads_should_cover_every_day_of_the_run() {
+        let results = [(2023, result()), (2023, result())];
+
+        let json = payload(NotifyFormat::Json, &results);
+        assert_eq!(json["days"].as_array().map(Vec::len), Some(2));
+        assert_eq!(json["failures"], json!(2));
+        assert_eq!(json["duration_ms"], json!(6.0));
+        assert_eq!(
+            payload(NotifyFormat::Slack, &results)["text"]
+                .as_str()
+                .map(|text| text.matches("Advent of Code 2023, day 6").count()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn chat_payloads_should_contain_the_text_output() {
+        let text = "Advent of Code 2023, day 6\n```\n6:1 — Product of all ways to win races: 288\nPart 1 in 1ms\n6:2 — Error: Missing Time: line\nPart 2 in 2ms\nDone in 3ms\n```";
+
+        assert_eq!(
+            payload(NotifyFormat::Slack, &[(2023, result())]),
+            json!({ "text": text })
+        );
+        assert_eq!(
+            payload(NotifyFormat::Discord, &[(2023, result())]),
+            json!({ "content": text })
+        );
+    }
+}
@@ -0,0 +1,1030 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::messages::Catalog;
+use crate::style::ColorChoice;
+
+pub const DEFAULT_YEAR: u16 = 2023;
+pub const DEFAULT_README: &str = "README.md";
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+pub const DEFAULT_PART_BUDGET: Duration = Duration::from_millis(500);
+pub const DEFAULT_GENERATED_SIZE: usize = 1000;
+pub const DEFAULT_BENCH_ITERATIONS: usize = 10;
+pub const DEFAULT_BENCH_WARMUP: usize = 1;
+
+pub const USAGE: &str = "Usage: aoc [YEAR] DAY [PART] [OPTIONS]
+       aoc [YEAR] all|latest|FIRST-LAST|DAY,DAY... [PART] [OPTIONS]
+       aoc list [YEAR]
+       aoc doctor|selftest|stats [--write-readme-table]
+       aoc gen|bench|visualize|history|stats [YEAR] DAY
+
+DAY is a number from 1 to 25, `day5` or a date such as `2023-12-05`, and PART is 1 or 2.
+Options: --part, --input, --inputs-dir, --example, --format text|json|jsonl, --messages en|fr|terse,
+         --color auto|always|never, --explain, --animate, --cross-check, --timeout, --budget, --part-budget,
+         --log-file, --notify, --notify-format, --red, --green, --blue";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    /// One JSON document per day, with the durations in nanoseconds.
+    Json,
+    Jsonl,
+}
+
+impl Format {
+    fn parse(format: &str) -> Result<Self, String> {
+        match format {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "jsonl" => Ok(Self::Jsonl),
+            _ => Err(format!("Unknown format: {format}")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NotifyFormat {
+    Json,
+    Slack,
+    Discord,
+}
+
+impl NotifyFormat {
+    fn parse(format: &str) -> Result<Self, String> {
+        match format {
+            "json" => Ok(Self::Json),
+            "slack" => Ok(Self::Slack),
+            "discord" => Ok(Self::Discord),
+            _ => Err(format!("Unknown notification format: {format}")),
+        }
+    }
+}
+
+/// The days to run.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Days {
+    One(u8),
+    /// The days of ranges such as `3-8` and lists such as `1,4,7`, in order and without duplicates.
+    Many(Vec<u8>),
+    All,
+    /// The last day with a solution.
+    Latest,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Options {
+    pub year: u16,
+    pub days: Days,
+    pub format: Format,
+    pub notify: Option<String>,
+    pub notify_format: NotifyFormat,
+    pub explain: bool,
+    pub animate: bool,
+    /// Overrides of the cubes in the bag for part one of 2023 day 2.
+    pub red: Option<u32>,
+    pub green: Option<u32>,
+    pub blue: Option<u32>,
+    /// The time the days are expected to run in, when several are run.
+    pub budget: Duration,
+    /// The time each part is expected to run in, when several days are run.
+    pub part_budget: Duration,
+    /// A directory of inputs to solve instead of the committed input.
+    pub inputs_dir: Option<PathBuf>,
+    /// The time after which a part is abandoned.
+    pub timeout: Option<Duration>,
+    /// A file to append a JSON line to for every part run.
+    pub log_file: Option<PathBuf>,
+    /// Whether to check the answers against the other algorithms of the days that have some.
+    pub cross_check: bool,
+    /// Whether to solve the examples of the puzzle statement instead of the input.
+    pub example: bool,
+    /// How the answers and the timings are worded in the text output.
+    pub messages: Catalog,
+    /// The only part to run, both otherwise.
+    pub part: Option<u8>,
+    /// A file to solve the day on instead of the committed input.
+    pub input: Option<PathBuf>,
+    /// When to color the text output.
+    pub color: ColorChoice,
+}
+
+impl Options {
+    /// The default options to run the given days.
+    pub fn new(year: u16, days: Days) -> Self {
+        Self {
+            year,
+            days,
+            format: Format::Text,
+            notify: None,
+            notify_format: NotifyFormat::Json,
+            explain: false,
+            animate: false,
+            red: None,
+            green: None,
+            blue: None,
+            budget: DEFAULT_BUDGET,
+            part_budget: DEFAULT_PART_BUDGET,
+            inputs_dir: None,
+            timeout: None,
+            log_file: None,
+            cross_check: false,
+            example: false,
+            messages: Catalog::English,
+            part: None,
+            input: None,
+            color: ColorChoice::Auto,
+        }
+    }
+
+    /// The parts to run.
+    pub fn parts(&self) -> &[u8] {
+        match &self.part {
+            Some(part) => std::slice::from_ref(part),
+            None => &[1, 2],
+        }
+    }
+
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut positionals = Vec::new();
+        let mut format = Format::Text;
+        let mut notify = None;
+        let mut notify_format = NotifyFormat::Json;
+        let mut explain = false;
+        let mut animate = false;
+        let mut red = None;
+        let mut green = None;
+        let mut blue = None;
+        let mut budget = DEFAULT_BUDGET;
+        let mut part_budget = DEFAULT_PART_BUDGET;
+        let mut inputs_dir = None;
+        let mut timeout = None;
+        let mut log_file = None;
+        let mut cross_check = false;
+        let mut example = false;
+        let mut messages = Catalog::English;
+        let mut part = None;
+        let mut input = None;
+        let mut color = ColorChoice::Auto;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if let Some(value) = flag_value(&arg, "--format", &mut args) {
+                format = Format::parse(&value?)?;
+            } else if let Some(value) = flag_value(&arg, "--notify", &mut args) {
+                notify = Some(value?);
+            } else if let Some(value) = flag_value(&arg, "--messages", &mut args) {
+                messages = Catalog::parse(&value?)?;
+            } else if let Some(value) = flag_value(&arg, "--color", &mut args) {
+                color = ColorChoice::parse(&value?)?;
+            } else if let Some(value) = flag_value(&arg, "--notify-format", &mut args) {
+                notify_format = NotifyFormat::parse(&value?)?;
+            } else if arg == "--explain" {
+                explain = true;
+            } else if arg == "--animate" {
+                animate = true;
+            } else if arg == "--cross-check" {
+                cross_check = true;
+            } else if arg == "--example" {
+                example = true;
+            } else if let Some(value) = flag_value(&arg, "--part", &mut args) {
+                part = Some(parse_part(&value?)?);
+            } else if let Some(value) = flag_value(&arg, "--red", &mut args) {
+                red = Some(parse_number("--red", &value?)?);
+            } else if let Some(value) = flag_value(&arg, "--green", &mut args) {
+                green = Some(parse_number("--green", &value?)?);
+            } else if let Some(value) = flag_value(&arg, "--blue", &mut args) {
+                blue = Some(parse_number("--blue", &value?)?);
+            } else if let Some(value) = flag_value(&arg, "--budget", &mut args) {
+                budget = Duration::from_millis(parse_number("--budget", &value?)?.into());
+            } else if let Some(value) = flag_value(&arg, "--part-budget", &mut args) {
+                part_budget = Duration::from_millis(parse_number("--part-budget", &value?)?.into());
+            } else if let Some(value) = flag_value(&arg, "--inputs-dir", &mut args) {
+                inputs_dir = Some(PathBuf::from(value?));
+            } else if let Some(value) = flag_value(&arg, "--input", &mut args) {
+                input = Some(PathBuf::from(value?));
+            } else if let Some(value) = flag_value(&arg, "--log-file", &mut args) {
+                log_file = Some(PathBuf::from(value?));
+            } else if let Some(value) = flag_value(&arg, "--timeout", &mut args) {
+                timeout = Some(Duration::from_secs(
+                    parse_number("--timeout", &value?)?.into(),
+                ));
+            } else if arg.starts_with("--") {
+                return Err(format!("Unknown option: {arg}"));
+            } else {
+                positionals.push(arg);
+            }
+        }
+
+        let (year, days, positional_part) = parse_year_days_and_part(&positionals)?;
+        if part.is_some() && positional_part.is_some() {
+            return Err("The part is given twice".to_string());
+        }
+        if input.is_some() && !matches!(days, Days::One(_)) {
+            return Err("--input needs a single day".to_string());
+        }
+        if input.is_some() && inputs_dir.is_some() {
+            return Err("--input and --inputs-dir cannot be combined".to_string());
+        }
+        Ok(Self {
+            year,
+            days,
+            format,
+            notify,
+            notify_format,
+            explain,
+            animate,
+            red,
+            green,
+            blue,
+            budget,
+            part_budget,
+            inputs_dir,
+            timeout,
+            log_file,
+            cross_check,
+            example,
+            messages,
+            part: part.or(positional_part),
+            input,
+            color,
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Run(Options),
+    History {
+        year: u16,
+        day: u8,
+    },
+    /// Picks the day to run from a list, when run on a terminal without arguments.
+    Interactive,
+    Selftest,
+    /// Checks the inputs, the recorded answers and the session token.
+    Doctor,
+    /// The registered days of a year, or of every year.
+    List {
+        year: Option<u16>,
+    },
+    Stats {
+        readme: Option<PathBuf>,
+    },
+    /// Statistics of the input and of the examples of a day.
+    InputStats {
+        year: u16,
+        day: u8,
+    },
+    Visualize {
+        year: u16,
+        day: u8,
+    },
+    /// A random input for a day, to test the solutions on inputs larger than the puzzle ones.
+    Generate {
+        year: u16,
+        day: u8,
+        size: usize,
+        seed: u64,
+    },
+    /// Runs the parts of a day several times, after warm-up runs, and reports statistics of their durations.
+    Bench {
+        year: u16,
+        day: u8,
+        part: Option<u8>,
+        iterations: usize,
+        warmup: usize,
+    },
+}
+
+impl Command {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let args = args.into_iter().collect::<Vec<_>>();
+        match args.first().map(String::as_str) {
+            None => Ok(Command::Interactive),
+            Some("--interactive") if args.len() == 1 => Ok(Command::Interactive),
+            Some("history") => {
+                let (year, day) = parse_year_and_day(&args[1..])?;
+                Ok(Command::History { year, day })
+            }
+            Some("selftest") if args.len() == 1 => Ok(Command::Selftest),
+            Some("selftest") => Err("selftest takes no arguments".to_string()),
+            Some("doctor") if args.len() == 1 => Ok(Command::Doctor),
+            Some("doctor") => Err("doctor takes no arguments".to_string()),
+            Some("stats") => parse_stats(&args[1..]),
+            Some("list") => match &args[1..] {
+                [] => Ok(Command::List { year: None }),
+                [year] => year
+                    .parse()
+                    .map(|year| Command::List { year: Some(year) })
+                    .map_err(|_| format!("Invalid year: {year}")),
+                _ => Err("list takes at most a year".to_string()),
+            },
+            Some("gen") => parse_generate(&args[1..]),
+            Some("bench") => parse_bench(&args[1..]),
+            Some("visualize") => {
+                let (year, day) = parse_year_and_day(&args[1..])?;
+                Ok(Command::Visualize { year, day })
+            }
+            _ => Options::from_args(args).map(Command::Run),
+        }
+    }
+}
+
+fn parse_stats(args: &[String]) -> Result<Command, String> {
+    if args.first().is_some_and(|arg| !arg.starts_with("--")) {
+        let (year, day) = parse_year_and_day(args)?;
+        return Ok(Command::InputStats { year, day });
+    }
+    let readme = match args {
+        [] => None,
+        [flag] if flag == "--write-readme-table" => Some(PathBuf::from(DEFAULT_README)),
+        [flag, path] if flag == "--write-readme-table" => Some(PathBuf::from(path)),
+        [flag] if flag.starts_with("--write-readme-table=") => {
+            Some(PathBuf::from(&flag["--write-readme-table=".len()..]))
+        }
+        _ => return Err(format!("Invalid stats arguments: {}", args.join(" "))),
+    };
+    Ok(Command::Stats { readme })
+}
+
+fn parse_generate(args: &[String]) -> Result<Command, String> {
+    let mut positionals = Vec::new();
+    let mut size = DEFAULT_GENERATED_SIZE;
+    let mut seed = 0;
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        if let Some(value) = flag_value(&arg, "--size", &mut args) {
+            size = match parse_number("--size", &value?)? {
+                0 => return Err("The size must be at least 1".to_string()),
+                size => size as usize,
+            };
+        } else if let Some(value) = flag_value(&arg, "--seed", &mut args) {
+            let value = value?;
+            seed = value
+                .parse()
+                .map_err(|_| format!("Invalid value for --seed: {value}"))?;
+        } else {
+            positionals.push(arg);
+        }
+    }
+    let (year, day) = parse_year_and_day(&positionals)?;
+    Ok(Command::Generate {
+        year,
+        day,
+        size,
+        seed,
+    })
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let mut positionals = Vec::new();
+    let mut iterations = DEFAULT_BENCH_ITERATIONS;
+    let mut warmup = DEFAULT_BENCH_WARMUP;
+    let mut part = None;
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        if let Some(value) = flag_value(&arg, "--iterations", &mut args) {
+            iterations = match parse_number("--iterations", &value?)? {
+                0 => return Err("The iterations must be at least 1".to_string()),
+                iterations => iterations as usize,
+            };
+        } else if let Some(value) = flag_value(&arg, "--warmup", &mut args) {
+            warmup = parse_number("--warmup", &value?)? as usize;
+        } else if let Some(value) = flag_value(&arg, "--part", &mut args) {
+            part = Some(parse_part(&value?)?);
+        } else {
+            positionals.push(arg);
+        }
+    }
+    let (year, day, positional_part) = match parse_year_days_and_part(&positionals)? {
+        (year, Days::One(day), part) => (year, day, part),
+        _ => return Err("A single day is expected".to_string()),
+    };
+    if part.is_some() && positional_part.is_some() {
+        return Err("The part is given twice".to_string());
+    }
+    Ok(Command::Bench {
+        year,
+        day,
+        part: part.or(positional_part),
+        iterations,
+        warmup,
+    })
+}
+
+/// The value of `flag` if `arg` is that flag, given either as `--flag=value` or as `--flag value`.
+fn flag_value<I: Iterator<Item = String>>(
+    arg: &str,
+    flag: &str,
+    args: &mut I,
+) -> Option<Result<String, String>> {
+    let rest = arg.strip_prefix(flag)?;
+    if rest.is_empty() {
+        Some(
+            args.next()
+                .ok_or_else(|| format!("Missing value for {flag}")),
+        )
+    } else {
+        rest.strip_prefix('=').map(|value| Ok(value.to_string()))
+    }
+}
+
+fn parse_number(flag: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {flag}: {value}"))
+}
+
+/// A single day, as `5`, `05`, `day5` or a date such as `2023-12-05`, with the year of the date if any.
+fn parse_day(day: &str) -> Result<(Option<u16>, u8), String> {
+    let (year, number) = match day.split('-').collect::<Vec<_>>()[..] {
+        [year, "12", number] => {
+            let year = year.parse().map_err(|_| format!("Invalid date: {day}"))?;
+            (Some(year), number)
+        }
+        [_, _, _] => return Err(format!("Not a date of December: {day}")),
+        _ => (None, day.strip_prefix("day").unwrap_or(day)),
+    };
+    match number.parse::<u32>() {
+        Ok(number @ 1..=25) => Ok((year, number as u8)),
+        Ok(_) => Err(format!("Day out of range (1 to 25): {day}")),
+        Err(_) => Err(format!("Invalid day: {day}")),
+    }
+}
+
+fn parse_days(days: &str) -> Result<(Option<u16>, Days), String> {
+    match days {
+        "all" => Ok((None, Days::All)),
+        "latest" => Ok((None, Days::Latest)),
+        _ if days.contains(',') || days.split('-').count() == 2 => {
+            let mut many = Vec::new();
+            for item in days.split(',') {
+                many.extend(parse_range(item)?);
+            }
+            many.sort_unstable();
+            many.dedup();
+            Ok((None, Days::Many(many)))
+        }
+        _ => parse_day(days).map(|(year, day)| (year, Days::One(day))),
+    }
+}
+
+/// The days of `3-8`, or the single day of `3`. Dates are not accepted, as the year is given separately.
+fn parse_range(range: &str) -> Result<RangeInclusive<u8>, String> {
+    let day = |day: &str| match parse_day(day)? {
+        (None, day) => Ok(day),
+        (Some(_), _) => Err(format!("Invalid day in a range or list: {day}")),
+    };
+    match range.split_once('-') {
+        Some((first, last)) => {
+            let (first, last) = (day(first)?, day(last)?);
+            if first > last {
+                return Err(format!("Empty range of days: {range}"));
+            }
+            Ok(first..=last)
+        }
+        None => day(range).map(|day| day..=day),
+    }
+}
+
+fn parse_year_and_days(positionals: &[String]) -> Result<(u16, Days), String> {
+    let (year, (date_year, days)) = match positionals {
+        [days] => (None, parse_days(days)?),
+        [year, days] => {
+            let year = year
+                .parse::<u16>()
+                .map_err(|_| format!("Invalid year: {year}"))?;
+            (Some(year), parse_days(days)?)
+        }
+        [] => return Err("Missing day".to_string()),
+        _ => return Err(format!("Too many arguments: {}", positionals.join(" "))),
+    };
+    match (year, date_year) {
+        (Some(year), Some(date_year)) if year != date_year => {
+            Err(format!("The date is in {date_year}, not in {year}"))
+        }
+        _ => Ok((year.or(date_year).unwrap_or(DEFAULT_YEAR), days)),
+    }
+}
+
+/// The year, the days and the part of `5`, `2022 5`, `5 2` or `2022 5 2`: a first number of four digits is a year.
+fn parse_year_days_and_part(positionals: &[String]) -> Result<(u16, Days, Option<u8>), String> {
+    let is_year = |arg: &str| arg.len() == 4 && arg.bytes().all(|byte| byte.is_ascii_digit());
+    let (year_and_days, part) = match positionals {
+        [first, part] if !is_year(first) => (&positionals[..1], Some(part)),
+        [_, _, part] => (&positionals[..2], Some(part)),
+        _ => (positionals, None),
+    };
+    let (year, days) = parse_year_and_days(year_and_days)?;
+    Ok((year, days, part.map(|part| parse_part(part)).transpose()?))
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Invalid part (1 or 2): {part}")),
+    }
+}
+
+fn parse_year_and_day(positionals: &[String]) -> Result<(u16, u8), String> {
+    match parse_year_and_days(positionals)? {
+        (year, Days::One(day)) => Ok((year, day)),
+        _ => Err("A single day is expected".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn options(args: &[&str]) -> Result<Options, String> {
+        Options::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn day_should_default_to_the_2023_text_output() {
+        assert_eq!(
+            options(&["6"]),
+            Ok(Options {
+                year: 2023,
+                days: Days::One(6),
+                format: Format::Text,
+                notify: None,
+                notify_format: NotifyFormat::Json,
+                explain: false,
+                animate: false,
+                red: None,
+                green: None,
+                blue: None,
+                budget: Duration::from_secs(1),
+                part_budget: Duration::from_millis(500),
+                inputs_dir: None,
+                timeout: None,
+                log_file: None,
+                cross_check: false,
+                example: false,
+                messages: Catalog::English,
+                part: None,
+                input: None,
+                color: ColorChoice::Auto,
+            }),
+        );
+    }
+
+    #[test]
+    fn inputs_dir_should_take_a_path() {
+        assert_eq!(
+            options(&["5", "--inputs-dir=inputs/day5"]).map(|o| o.inputs_dir),
+            Ok(Some(PathBuf::from("inputs/day5"))),
+        );
+    }
+
+    #[test]
+    fn unknown_options_should_be_rejected() {
+        assert_eq!(
+            options(&["5", "--verbose"]),
+            Err("Unknown option: --verbose".to_string())
+        );
+    }
+
+    #[test]
+    fn input_should_take_a_path_for_a_single_day() {
+        assert_eq!(
+            options(&["5", "--input", "my-input.txt"]).map(|o| o.input),
+            Ok(Some(PathBuf::from("my-input.txt")))
+        );
+        assert_eq!(
+            options(&["all", "--input=my-input.txt"]).map(|o| o.input),
+            Err("--input needs a single day".to_string())
+        );
+        assert_eq!(
+            options(&["5", "--input", "a.txt", "--inputs-dir", "inputs"]).map(|o| o.input),
+            Err("--input and --inputs-dir cannot be combined".to_string())
+        );
+    }
+
+    #[test]
+    fn log_file_should_take_a_path() {
+        assert_eq!(
+            options(&["8", "--log-file", "runs.jsonl"]).map(|o| o.log_file),
+            Ok(Some(PathBuf::from("runs.jsonl"))),
+        );
+    }
+
+    #[test]
+    fn timeout_should_be_in_seconds() {
+        assert_eq!(
+            options(&["8", "--timeout", "10"]).map(|o| o.timeout),
+            Ok(Some(Duration::from_secs(10))),
+        );
+    }
+
+    #[test]
+    fn budget_should_be_in_milliseconds() {
+        assert_eq!(
+            options(&["all", "--budget", "250"]).map(|o| o.budget),
+            Ok(Duration::from_millis(250)),
+        );
+        assert_eq!(
+            options(&["all", "--part-budget=100"]).map(|o| o.part_budget),
+            Ok(Duration::from_millis(100)),
+        );
+    }
+
+    #[test]
+    fn year_can_be_given_before_the_day() {
+        assert_eq!(
+            options(&["2022", "1"]).map(|o| (o.year, o.days)),
+            Ok((2022, Days::One(1)))
+        );
+    }
+
+    #[test]
+    fn part_can_be_given_after_the_day_or_as_a_flag() {
+        let part = |args: &[&str]| options(args).map(|o| (o.year, o.days, o.part));
+
+        assert_eq!(part(&["5", "2"]), Ok((2023, Days::One(5), Some(2))));
+        assert_eq!(part(&["2022", "5", "1"]), Ok((2022, Days::One(5), Some(1))));
+        assert_eq!(
+            part(&["day5", "--part", "2"]),
+            Ok((2023, Days::One(5), Some(2)))
+        );
+        assert_eq!(part(&["all", "2"]), Ok((2023, Days::All, Some(2))));
+        assert_eq!(
+            part(&["5", "3"]),
+            Err("Invalid part (1 or 2): 3".to_string())
+        );
+        assert_eq!(
+            part(&["5", "2", "--part", "2"]),
+            Err("The part is given twice".to_string())
+        );
+    }
+
+    #[test]
+    fn parts_should_default_to_both() {
+        assert_eq!(options(&["5"]).unwrap().parts(), &[1, 2]);
+        assert_eq!(options(&["5", "2"]).unwrap().parts(), &[2]);
+    }
+
+    #[test]
+    fn format_can_be_given_anywhere() {
+        assert_eq!(
+            options(&["--format", "jsonl", "6"]).map(|o| o.format),
+            Ok(Format::Jsonl),
+        );
+        assert_eq!(
+            options(&["6", "--format=jsonl"]).map(|o| o.format),
+            Ok(Format::Jsonl),
+        );
+    }
+
+    #[test]
+    fn format_should_be_text_json_or_jsonl() {
+        assert_eq!(
+            options(&["6", "--format", "json"]).map(|o| o.format),
+            Ok(Format::Json),
+        );
+        assert_eq!(
+            options(&["6", "--format", "xml"]).map(|o| o.format),
+            Err("Unknown format: xml".to_string()),
+        );
+    }
+
+    #[test]
+    fn notify_should_take_a_url_and_a_payload_format() {
+        assert_eq!(
+            options(&[
+                "6",
+                "--notify",
+                "https://example.com/hook",
+                "--notify-format=slack"
+            ])
+            .map(|o| (o.notify, o.notify_format)),
+            Ok((
+                Some("https://example.com/hook".to_string()),
+                NotifyFormat::Slack
+            )),
+        );
+    }
+
+    #[test]
+    fn explain_should_be_a_flag() {
+        assert_eq!(options(&["2", "--explain"]).map(|o| o.explain), Ok(true));
+    }
+
+    #[test]
+    fn color_should_choose_when_to_color_the_output() {
+        assert_eq!(
+            options(&["7", "--color=never"]).map(|o| o.color),
+            Ok(ColorChoice::Never)
+        );
+        assert_eq!(
+            options(&["7", "--color", "rainbow"]),
+            Err("Unknown color choice: rainbow".to_string())
+        );
+    }
+
+    #[test]
+    fn messages_should_choose_the_wording() {
+        assert_eq!(
+            options(&["7", "--messages", "terse"]).map(|o| o.messages),
+            Ok(Catalog::Terse)
+        );
+        assert_eq!(
+            options(&["7", "--messages=de"]),
+            Err("Unknown messages: de".to_string())
+        );
+    }
+
+    #[test]
+    fn example_should_be_a_flag() {
+        assert_eq!(options(&["7", "--example"]).map(|o| o.example), Ok(true));
+    }
+
+    #[test]
+    fn cross_check_should_be_a_flag() {
+        assert_eq!(
+            options(&["5", "--cross-check"]).map(|o| o.cross_check),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn animate_should_be_a_flag() {
+        assert_eq!(options(&["--animate", "10"]).map(|o| o.animate), Ok(true));
+    }
+
+    #[test]
+    fn cube_limits_should_be_numbers() {
+        assert_eq!(
+            options(&["2", "--red", "20", "--blue=7"]).map(|o| (o.red, o.green, o.blue)),
+            Ok((Some(20), None, Some(7))),
+        );
+        assert_eq!(
+            options(&["2", "--green", "many"]),
+            Err("Invalid value for --green: many".to_string()),
+        );
+    }
+
+    #[test]
+    fn history_should_be_a_subcommand() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            command(&["history", "8"]),
+            Ok(Command::History { year: 2023, day: 8 }),
+        );
+        assert_eq!(
+            command(&["history", "2022", "1"]),
+            Ok(Command::History { year: 2022, day: 1 }),
+        );
+        assert!(matches!(command(&["8"]), Ok(Command::Run(_))));
+    }
+
+    #[test]
+    fn visualize_should_be_a_subcommand() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            command(&["visualize", "3"]),
+            Ok(Command::Visualize { year: 2023, day: 3 }),
+        );
+        assert_eq!(command(&["visualize"]), Err("Missing day".to_string()));
+    }
+
+    #[test]
+    fn interactive_should_be_the_default_command() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(command(&[]), Ok(Command::Interactive));
+        assert_eq!(command(&["--interactive"]), Ok(Command::Interactive));
+    }
+
+    #[test]
+    fn doctor_should_be_a_subcommand_without_arguments() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(command(&["doctor"]), Ok(Command::Doctor));
+        assert_eq!(
+            command(&["doctor", "5"]),
+            Err("doctor takes no arguments".to_string())
+        );
+    }
+
+    #[test]
+    fn selftest_should_be_a_subcommand_without_arguments() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(command(&["selftest"]), Ok(Command::Selftest));
+        assert!(command(&["selftest", "3"]).is_err());
+    }
+
+    #[test]
+    fn stats_should_write_the_readme_table_on_demand() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(command(&["stats"]), Ok(Command::Stats { readme: None }));
+        assert_eq!(
+            command(&["stats", "--write-readme-table"]),
+            Ok(Command::Stats {
+                readme: Some(PathBuf::from("README.md"))
+            }),
+        );
+        assert_eq!(
+            command(&["stats", "--write-readme-table", "docs/STATS.md"]),
+            Ok(Command::Stats {
+                readme: Some(PathBuf::from("docs/STATS.md"))
+            }),
+        );
+        assert_eq!(
+            command(&["stats", "--write-readme-table=docs/STATS.md"]),
+            Ok(Command::Stats {
+                readme: Some(PathBuf::from("docs/STATS.md"))
+            }),
+        );
+        assert!(command(&["stats", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn stats_of_a_day_should_describe_its_input() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            command(&["stats", "day3"]),
+            Ok(Command::InputStats { year: 2023, day: 3 }),
+        );
+        assert_eq!(
+            command(&["stats", "2022", "1"]),
+            Ok(Command::InputStats { year: 2022, day: 1 }),
+        );
+    }
+
+    #[test]
+    fn list_should_take_an_optional_year() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(command(&["list"]), Ok(Command::List { year: None }));
+        assert_eq!(
+            command(&["list", "2022"]),
+            Ok(Command::List { year: Some(2022) })
+        );
+        assert_eq!(
+            command(&["list", "day5"]),
+            Err("Invalid year: day5".to_string())
+        );
+    }
+
+    #[test]
+    fn bench_should_take_a_day_a_part_and_the_runs() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            command(&["bench", "5"]),
+            Ok(Command::Bench {
+                year: 2023,
+                day: 5,
+                part: None,
+                iterations: DEFAULT_BENCH_ITERATIONS,
+                warmup: DEFAULT_BENCH_WARMUP,
+            }),
+        );
+        assert_eq!(
+            command(&[
+                "bench",
+                "2022",
+                "5",
+                "2",
+                "--iterations",
+                "100",
+                "--warmup=0"
+            ]),
+            Ok(Command::Bench {
+                year: 2022,
+                day: 5,
+                part: Some(2),
+                iterations: 100,
+                warmup: 0,
+            }),
+        );
+        assert_eq!(
+            command(&["bench", "5", "--iterations", "0"]),
+            Err("The iterations must be at least 1".to_string()),
+        );
+        assert_eq!(
+            command(&["bench", "all"]),
+            Err("A single day is expected".to_string()),
+        );
+    }
+
+    #[test]
+    fn gen_should_take_a_day_a_size_and_a_seed() {
+        let command = |args: &[&str]| Command::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            command(&["gen", "7"]),
+            Ok(Command::Generate {
+                year: 2023,
+                day: 7,
+                size: DEFAULT_GENERATED_SIZE,
+                seed: 0,
+            }),
+        );
+        assert_eq!(
+            command(&["gen", "8", "--size", "5000", "--seed=42"]),
+            Ok(Command::Generate {
+                year: 2023,
+                day: 8,
+                size: 5000,
+                seed: 42,
+            }),
+        );
+        assert_eq!(
+            command(&["gen", "2", "--size", "0"]),
+            Err("The size must be at least 1".to_string()),
+        );
+        assert_eq!(
+            command(&["gen", "2", "--seed", "-1"]),
+            Err("Invalid value for --seed: -1".to_string()),
+        );
+    }
+
+    #[test]
+    fn unknown_format_should_be_rejected() {
+        assert_eq!(
+            options(&["6", "--format", "xml"]),
+            Err("Unknown format: xml".to_string()),
+        );
+        assert_eq!(
+            options(&["6", "--format"]),
+            Err("Missing value for --format".to_string()),
+        );
+    }
+
+    #[test]
+    fn day_can_be_given_in_several_formats() {
+        for day in ["5", "05", "day5", "day05", "2023-12-05"] {
+            assert_eq!(
+                options(&[day]).map(|o| (o.year, o.days)),
+                Ok((2023, Days::One(5))),
+                "{day}",
+            );
+        }
+        assert_eq!(
+            options(&["2022-12-01"]).map(|o| (o.year, o.days)),
+            Ok((2022, Days::One(1))),
+        );
+        assert_eq!(options(&["all"]).map(|o| o.days), Ok(Days::All));
+        assert_eq!(
+            options(&["2022", "latest"]).map(|o| (o.year, o.days)),
+            Ok((2022, Days::Latest)),
+        );
+    }
+
+    #[test]
+    fn days_can_be_ranges_and_lists() {
+        let days = |arg: &str| options(&[arg]).map(|o| o.days);
+
+        assert_eq!(days("3-8"), Ok(Days::Many(vec![3, 4, 5, 6, 7, 8])));
+        assert_eq!(days("7,1,4"), Ok(Days::Many(vec![1, 4, 7])));
+        assert_eq!(days("1-3,day2,10"), Ok(Days::Many(vec![1, 2, 3, 10])));
+        assert_eq!(
+            options(&["2022", "1,2", "2"]).map(|o| (o.year, o.days, o.part)),
+            Ok((2022, Days::Many(vec![1, 2]), Some(2)))
+        );
+        assert_eq!(days("8-3"), Err("Empty range of days: 8-3".to_string()));
+        assert_eq!(
+            days("3-26"),
+            Err("Day out of range (1 to 25): 26".to_string())
+        );
+        assert_eq!(days("1,x"), Err("Invalid day: x".to_string()));
+    }
+
+    #[test]
+    fn invalid_days_should_be_explained() {
+        let error = |day: &str| options(&[day]).err();
+
+        assert_eq!(
+            error("26"),
+            Some("Day out of range (1 to 25): 26".to_string())
+        );
+        assert_eq!(
+            error("day0"),
+            Some("Day out of range (1 to 25): day0".to_string())
+        );
+        assert_eq!(error("first"), Some("Invalid day: first".to_string()));
+        assert_eq!(
+            error("2023-11-05"),
+            Some("Not a date of December: 2023-11-05".to_string()),
+        );
+        assert_eq!(
+            options(&["2022", "2023-12-05"]).err(),
+            Some("The date is in 2023, not in 2022".to_string()),
+        );
+        assert_eq!(
+            Command::from_args(["visualize".to_string(), "all".to_string()]),
+            Err("A single day is expected".to_string()),
+        );
+    }
+}
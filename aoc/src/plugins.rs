@@ -0,0 +1,84 @@
+use std::env;
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+use aoc_utils::{plugin, Registry};
+use libloading::Library;
+
+const DEFAULT_DIRECTORY: &str = "plugins";
+
+/// The function a plugin exports, unmangled, as `aoc_plugin_abi`, which returns the `plugin::ABI` it was built with.
+type Abi = extern "C" fn() -> u64;
+
+/// The function a plugin exports, unmangled, as `register`. Plugins must be built with the same compiler and
+/// `aoc-utils` version as the binary since the registry crosses the library boundary with the Rust ABI, which
+/// `aoc_plugin_abi` tells before it is called.
+type Register = fn(&mut Registry);
+
+pub fn load(registry: &mut Registry) {
+    let directory = env::var_os("AOC_PLUGINS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DIRECTORY));
+    for path in plugin_paths(&directory) {
+        if let Err(error) = load_plugin(&path, registry) {
+            eprintln!("Failed to load plugin {}: {error}", path.display());
+        }
+    }
+}
+
+fn plugin_paths(directory: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension() == Some(env::consts::DLL_EXTENSION.as_ref()))
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+fn load_plugin(path: &Path, registry: &mut Registry) -> Result<(), String> {
+    // SAFETY: plugins are trusted code, loading one runs its initialisers.
+    let library = unsafe { Library::new(path) }.map_err(|error| error.to_string())?;
+    // SAFETY: the symbol is expected to have the `Abi` signature, which only uses the C ABI.
+    let abi = unsafe { library.get::<Abi>(b"aoc_plugin_abi") }.map_err(|_| {
+        "No aoc_plugin_abi, the plugin must use aoc_utils::export_plugin!".to_string()
+    })?;
+    if abi() != plugin::ABI {
+        return Err("Built with another compiler or aoc-utils version than aoc".to_string());
+    }
+    // SAFETY: the symbol is expected to have the `Register` signature, see above, and the ABI is the same.
+    let register =
+        unsafe { library.get::<Register>(b"register") }.map_err(|error| error.to_string())?;
+    register(registry);
+    // The registered solutions point into the library, it must stay loaded until the end of the program.
+    mem::forget(library);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn plugin_paths_should_only_keep_shared_libraries() {
+        let directory = env::temp_dir().join(format!("aoc-plugins-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let library = |name: &str| directory.join(format!("{name}.{}", env::consts::DLL_EXTENSION));
+        for path in [library("b"), library("a"), directory.join("README.md")] {
+            fs::write(path, "").unwrap();
+        }
+
+        let paths = plugin_paths(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(paths, vec![library("a"), library("b")]);
+    }
+
+    #[test]
+    fn plugin_paths_should_be_empty_without_directory() {
+        assert!(plugin_paths(Path::new("/does/not/exist")).is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::{self, IsTerminal};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use aoc_utils::Progress;
+
+const WIDTH: u64 = 30;
+const REDRAW: Duration = Duration::from_millis(100);
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+/// A progress bar on stderr for the parts that report their progress, when stderr is a terminal.
+#[derive(Debug, Default)]
+pub struct Bar {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// When the bar was last drawn, if it is on screen.
+    drawn: Option<Instant>,
+    /// Whether the day is done, so that a part abandoned after a timeout does not draw over the next days.
+    finished: bool,
+}
+
+impl Bar {
+    /// The progress to give to the parts, which redraws the bar at most every tenth of a second.
+    pub fn progress(&self) -> Progress {
+        if !io::stderr().is_terminal() {
+            return Progress::default();
+        }
+        let state = Arc::clone(&self.state);
+        Progress::new(move |done, total| {
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.finished || state.drawn.is_some_and(|drawn| drawn.elapsed() < REDRAW) {
+                return;
+            }
+            eprint!("{CLEAR_LINE}{}", line(done, total));
+            state.drawn = Some(Instant::now());
+        })
+    }
+
+    /// Erases the bar, before an answer is printed.
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.drawn.take().is_some() {
+            eprint!("{CLEAR_LINE}");
+        }
+    }
+
+    /// Erases the bar for good, once the day is done.
+    pub fn finish(&self) {
+        self.clear();
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .finished = true;
+    }
+}
+
+fn line(done: u64, total: u64) -> String {
+    let done = u128::from(done.min(total));
+    let total = u128::from(total.max(1));
+    let filled = (done * u128::from(WIDTH) / total) as usize;
+    format!(
+        "[{}{}] {}%",
+        "#".repeat(filled),
+        " ".repeat(WIDTH as usize - filled),
+        done * 100 / total
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn line_should_fill_the_bar_with_the_steps_done() {
+        assert_eq!(line(0, 10), format!("[{}] 0%", " ".repeat(30)));
+        assert_eq!(
+            line(5, 10),
+            format!("[{}{}] 50%", "#".repeat(15), " ".repeat(15))
+        );
+        assert_eq!(line(12, 10), format!("[{}] 100%", "#".repeat(30)));
+        assert_eq!(
+            line(u64::MAX - 1, u64::MAX),
+            format!("[{}] 99%", "#".repeat(29) + " ")
+        );
+    }
+}
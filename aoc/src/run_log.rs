@@ -0,0 +1,111 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_utils::DayResult;
+use serde::Serialize;
+
+use crate::git::git_commit;
+use crate::milliseconds;
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    timestamp: u64,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer_hash: Option<String>,
+    error: Option<&'a str>,
+    duration_ms: f64,
+    git_describe: Option<&'a str>,
+}
+
+/// Appends a JSON line per part of the run to the file, which is created if needed.
+pub fn append(path: &Path, year: u16, result: &DayResult) -> Result<(), String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let lines = records(timestamp, git_commit().as_deref(), year, result);
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|error| format!("Cannot write to {}: {error}", path.display()))
+}
+
+fn records(timestamp: u64, git_describe: Option<&str>, year: u16, result: &DayResult) -> String {
+    result
+        .answers
+        .iter()
+        .map(|answer| {
+            let record = Record {
+                timestamp,
+                year,
+                day: answer.day,
+                part: answer.part,
+                answer_hash: answer.result.as_deref().ok().map(hash),
+                error: answer.result.as_ref().err().map(String::as_str),
+                duration_ms: milliseconds(answer.duration),
+                git_describe,
+            };
+            format!("{}\n", serde_json::to_string(&record).unwrap())
+        })
+        .collect()
+}
+
+/// The 64-bit FNV-1a hash of the answer, which stays the same across builds, unlike the hasher of the standard library.
+fn hash(answer: &str) -> String {
+    let hash = answer.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use aoc_utils::Answer;
+
+    use super::*;
+
+    #[test]
+    fn records_should_have_a_line_per_part() {
+        let result = DayResult {
+            day: 6,
+            answers: vec![
+                Answer {
+                    day: 6,
+                    part: 1,
+                    result: Ok("a".to_string()),
+                    duration: Duration::from_micros(1500),
+                },
+                Answer {
+                    day: 6,
+                    part: 2,
+                    result: Err("Missing Time: line".to_string()),
+                    duration: Duration::from_millis(2),
+                },
+            ],
+            duration: Duration::from_micros(3500),
+        };
+
+        assert_eq!(
+            records(1700000000, Some("8531e8d"), 2023, &result),
+            [
+                r#"{"timestamp":1700000000,"year":2023,"day":6,"part":1,"answer_hash":"af63dc4c8601ec8c","error":null,"duration_ms":1.5,"git_describe":"8531e8d"}"#,
+                r#"{"timestamp":1700000000,"year":2023,"day":6,"part":2,"answer_hash":null,"error":"Missing Time: line","duration_ms":2.0,"git_describe":"8531e8d"}"#,
+                "",
+            ]
+            .join("\n"),
+        );
+    }
+
+    #[test]
+    fn hash_should_be_fnv1a() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_utils::{Example, Registry, Solution};
+
+/// Solves the examples of every day and prints whether each one gives the expected answer. Fails if any does not.
+pub fn run(registry: &Registry) -> Result<(), String> {
+    let mut checked = 0;
+    let mut failed = 0;
+    for year in registry.years() {
+        for day in registry.days(year) {
+            let Some(solution) = registry.get(year, day) else {
+                continue;
+            };
+            for example in solution.examples() {
+                checked += 1;
+                match check(solution, &example) {
+                    Ok(()) => println!("{year}:{day}:{} — ok", example.part),
+                    Err(error) => {
+                        failed += 1;
+                        println!("{year}:{day}:{} — FAILED: {error}", example.part);
+                    }
+                }
+            }
+        }
+    }
+    if failed > 0 {
+        Err(format!("{failed} of {checked} examples failed"))
+    } else {
+        println!("All {checked} examples passed");
+        Ok(())
+    }
+}
+
+fn check(solution: &dyn Solution, example: &Example) -> Result<(), String> {
+    let answer = solution.solve_part(example.part, example.input)?;
+    if answer == example.answer {
+        Ok(())
+    } else {
+        Err(format!("expected '{}', got '{answer}'", example.answer))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct FakeSolution;
+
+    impl Solution for FakeSolution {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, input: &str) -> Result<String, String> {
+            Ok(format!("Length: {}", input.len()))
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            Err("Not solved".to_string())
+        }
+    }
+
+    fn example(part: u8, answer: &'static str) -> Example {
+        Example {
+            part,
+            input: "abc",
+            answer,
+        }
+    }
+
+    #[test]
+    fn check_should_compare_the_whole_answer() {
+        assert_eq!(check(&FakeSolution, &example(1, "Length: 3")), Ok(()));
+        assert_eq!(
+            check(&FakeSolution, &example(1, "Length: 4")),
+            Err("expected 'Length: 4', got 'Length: 3'".to_string()),
+        );
+        assert_eq!(
+            check(&FakeSolution, &example(2, "Length: 3")),
+            Err("Not solved".to_string()),
+        );
+    }
+
+    #[test]
+    fn examples_of_every_registered_day_should_pass() {
+        assert_eq!(run(&crate::registry()), Ok(()));
+    }
+}
@@ -0,0 +1,138 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_utils::{DayResult, Registry};
+
+use crate::chart::runtime_chart;
+use crate::interrupt;
+
+const START_MARKER: &str = "<!-- stats:start -->";
+const END_MARKER: &str = "<!-- stats:end -->";
+
+pub fn run(registry: &Registry, readme: Option<&Path>) -> Result<(), String> {
+    let results = registry
+        .years()
+        .into_iter()
+        .flat_map(|year| {
+            crate::runnable(registry, year, &registry.days(year))
+                .into_iter()
+                .map(move |day| (year, day))
+        })
+        .filter_map(|(year, day)| {
+            let solution = registry.get(year, day)?;
+            let result = solution.run_with(solution.input(), &mut |answer| {
+                interrupt::record(year, answer)
+            });
+            Some((year, result))
+        })
+        .collect::<Vec<_>>();
+    let table = table(&results);
+    match readme {
+        Some(path) => {
+            let markdown = fs::read_to_string(path)
+                .map_err(|error| format!("Cannot read {}: {error}", path.display()))?;
+            fs::write(path, replace_section(&markdown, &table)?)
+                .map_err(|error| format!("Cannot write {}: {error}", path.display()))?;
+        }
+        None => println!("{table}"),
+    }
+    print!("{}", runtime_chart(&results));
+    Ok(())
+}
+
+fn table(results: &[(u16, DayResult)]) -> String {
+    let mut table =
+        String::from("| Year | Day | Part 1 | Part 2 | Time |\n|---|---|---|---|---|\n");
+    for (year, result) in results {
+        let answers = (1..=2)
+            .map(|part| {
+                result
+                    .answers
+                    .iter()
+                    .find(|answer| answer.part == part)
+                    .map(|answer| match &answer.result {
+                        Ok(text) => text.replace('|', "\\|"),
+                        Err(error) => format!("Error: {}", error.replace('|', "\\|")),
+                    })
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        table.push_str(&format!(
+            "| {year} | {} | {} | {} | {}ms |\n",
+            result.day,
+            answers[0],
+            answers[1],
+            result.duration.as_millis(),
+        ));
+    }
+    table
+}
+
+fn replace_section(markdown: &str, table: &str) -> Result<String, String> {
+    let start = markdown
+        .find(START_MARKER)
+        .ok_or_else(|| format!("Missing {START_MARKER} marker"))?
+        + START_MARKER.len();
+    let end = start
+        + markdown[start..]
+            .find(END_MARKER)
+            .ok_or_else(|| format!("Missing {END_MARKER} marker"))?;
+    Ok(format!(
+        "{}\n{table}{}",
+        &markdown[..start],
+        &markdown[end..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use aoc_utils::Answer;
+
+    use super::*;
+
+    fn answer(part: u8, result: Result<&str, &str>) -> Answer {
+        Answer {
+            day: 6,
+            part,
+            result: result.map(str::to_string).map_err(str::to_string),
+            duration: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn table_should_have_a_row_per_day() {
+        let result = DayResult {
+            day: 6,
+            answers: vec![answer(1, Ok("Answer: 288")), answer(2, Err("a | b"))],
+            duration: Duration::from_millis(3),
+        };
+
+        assert_eq!(
+            table(&[(2023, result)]),
+            "| Year | Day | Part 1 | Part 2 | Time |
+|---|---|---|---|---|
+| 2023 | 6 | Answer: 288 | Error: a \\| b | 3ms |
+",
+        );
+    }
+
+    #[test]
+    fn replace_section_should_only_replace_between_markers() {
+        let markdown = "# Title\n<!-- stats:start -->\nold\n<!-- stats:end -->\nFooter\n";
+
+        assert_eq!(
+            replace_section(markdown, "new\n"),
+            Ok("# Title\n<!-- stats:start -->\nnew\n<!-- stats:end -->\nFooter\n".to_string()),
+        );
+    }
+
+    #[test]
+    fn replace_section_should_fail_without_markers() {
+        assert_eq!(
+            replace_section("# Title\n", "new\n"),
+            Err("Missing <!-- stats:start --> marker".to_string()),
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+
+use aoc_utils::{answer_value, Answer, Solution};
+
+const BOLD: &str = "\x1b[1m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// When to color the text output.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ColorChoice {
+    /// When the output is a terminal and `NO_COLOR` is not set.
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn parse(choice: &str) -> Result<Self, String> {
+        match choice {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!("Unknown color choice: {choice}")),
+        }
+    }
+}
+
+/// Decorates the text output with a header per day, a mark on the answers whose value is known, and the timings of the
+/// slow parts in yellow, or in red over the part budget. Without colors, the output is left as it is.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Style {
+    colored: bool,
+    part_budget: Duration,
+}
+
+impl Style {
+    pub fn new(choice: ColorChoice, part_budget: Duration) -> Self {
+        let colored = match choice {
+            ColorChoice::Auto => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        };
+        Self {
+            colored,
+            part_budget,
+        }
+    }
+
+    /// The line before the answers of a day.
+    pub fn header(self, year: u16, solution: &dyn Solution) -> String {
+        if !self.colored {
+            return String::new();
+        }
+        let title = match solution.title() {
+            Some(title) => format!("{year} day {}: {title}", solution.day()),
+            None => format!("{year} day {}", solution.day()),
+        };
+        format!("{BOLD}{title}{RESET}\n")
+    }
+
+    /// The text of an answer, with a check mark if its value is the known one, a cross if it is not, and its timing in
+    /// color if the part is slow.
+    pub fn answer(self, text: &str, answer: &Answer, known: Option<&str>) -> String {
+        if !self.colored {
+            return text.to_string();
+        }
+        let mark = match (&answer.result, known) {
+            (Ok(result), Some(known)) if answer_value(result) == known => {
+                format!(" {GREEN}✓{RESET}")
+            }
+            (Ok(_), Some(known)) => format!(" {RED}✗ expected {known}{RESET}"),
+            (Err(_), _) => format!(" {RED}✗{RESET}"),
+            (Ok(_), None) => String::new(),
+        };
+        let timing = |line: &str| match self.timing(answer.duration) {
+            Some(color) => format!("{color}{line}{RESET}"),
+            None => line.to_string(),
+        };
+        // The terse messages give the value and the timing on the same line.
+        let text = text.trim_end_matches('\n');
+        match text.split_once('\n') {
+            Some((result, duration)) => format!("{result}{mark}\n{}\n", timing(duration)),
+            None => format!("{}{mark}\n", timing(text)),
+        }
+    }
+
+    /// The color of the timing of a part: red over the part budget, yellow over a fifth of it.
+    fn timing(self, duration: Duration) -> Option<&'static str> {
+        if duration > self.part_budget {
+            Some(RED)
+        } else if duration > self.part_budget / 5 {
+            Some(YELLOW)
+        } else {
+            None
+        }
+    }
+}
+
+/// The known value of the answer to a part, if any.
+pub fn known_answer(solution: &dyn Solution, part: u8) -> Option<&'static str> {
+    let index = usize::from(part).checked_sub(1)?;
+    solution.known_answers().get(index).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const COLORED: Style = Style {
+        colored: true,
+        part_budget: Duration::from_millis(500),
+    };
+
+    fn answer(result: Result<&str, &str>, millis: u64) -> Answer {
+        Answer {
+            day: 7,
+            part: 2,
+            result: result.map(str::to_string).map_err(str::to_string),
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn color_should_be_auto_always_or_never() {
+        assert_eq!(ColorChoice::parse("always"), Ok(ColorChoice::Always));
+        assert_eq!(
+            ColorChoice::parse("sometimes"),
+            Err("Unknown color choice: sometimes".to_string())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day7")]
+    fn answer_should_be_left_as_it_is_without_colors() {
+        let style = Style::new(ColorChoice::Never, Duration::from_millis(500));
+        let answer = answer(Ok("Total winnings: 5905"), 800);
+
+        assert_eq!(
+            style.answer(&answer.to_string(), &answer, Some("6440")),
+            answer.to_string()
+        );
+        assert_eq!(style.header(2023, &advent2023::day7::Day7), "");
+    }
+
+    #[test]
+    fn answer_should_be_checked_against_the_known_value() {
+        let text = "7:2 — Total winnings: 5905\nPart 2 in 3ms\n";
+
+        assert_eq!(
+            COLORED.answer(text, &answer(Ok("Total winnings: 5905"), 3), Some("5905")),
+            "7:2 — Total winnings: 5905 \x1b[32m✓\x1b[0m\nPart 2 in 3ms\n"
+        );
+        assert_eq!(
+            COLORED.answer(text, &answer(Ok("Total winnings: 5905"), 3), Some("6440")),
+            "7:2 — Total winnings: 5905 \x1b[31m✗ expected 6440\x1b[0m\nPart 2 in 3ms\n"
+        );
+        assert_eq!(
+            COLORED.answer(text, &answer(Ok("Total winnings: 5905"), 3), None),
+            text
+        );
+    }
+
+    #[test]
+    fn slow_parts_should_have_their_timing_in_yellow_or_red() {
+        let text = "7:2 5905 300ms\n";
+
+        assert_eq!(
+            COLORED.answer(text, &answer(Ok("5905"), 300), None),
+            "\x1b[33m7:2 5905 300ms\x1b[0m\n"
+        );
+        assert_eq!(
+            COLORED.answer(text, &answer(Err("Overflow"), 600), None),
+            "\x1b[31m7:2 5905 300ms\x1b[0m \x1b[31m✗\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day7")]
+    fn header_should_give_the_title_of_the_day() {
+        assert_eq!(
+            COLORED.header(2023, &advent2023::day7::Day7),
+            "\x1b[1m2023 day 7: Camel Cards\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day7")]
+    fn known_answer_should_be_looked_up_by_part() {
+        let solution = advent2023::day7::Day7;
+
+        assert_eq!(known_answer(&solution, 2), Some("250087440"));
+        assert_eq!(known_answer(&solution, 0), None);
+        assert_eq!(known_answer(&solution, 3), None);
+    }
+}
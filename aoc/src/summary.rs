@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use aoc_utils::DayResult;
+
+use crate::milliseconds;
+
+/// The parts ranked from the slowest to the fastest, with their share of the total runtime, and whether the total fits
+/// in the budget. The parts slower than the part budget are flagged.
+pub fn summary(results: &[(u16, DayResult)], budget: Duration, part_budget: Duration) -> String {
+    let mut answers = results
+        .iter()
+        .flat_map(|(year, result)| result.answers.iter().map(move |answer| (year, answer)))
+        .collect::<Vec<_>>();
+    answers.sort_by_key(|(_, answer)| std::cmp::Reverse(answer.duration));
+    let total = answers
+        .iter()
+        .map(|(_, answer)| answer.duration)
+        .sum::<Duration>();
+
+    let mut summary = String::from("Rank  Year  Day  Part        Time   Share\n");
+    for (rank, (year, answer)) in answers.iter().enumerate() {
+        let share = if total.is_zero() {
+            0.0
+        } else {
+            answer.duration.as_secs_f64() / total.as_secs_f64() * 100.0
+        };
+        let flag = if answer.duration > part_budget {
+            "  over budget"
+        } else {
+            ""
+        };
+        summary.push_str(&format!(
+            "{:>4}  {year}  {:>3}  {:>4}  {:>8.1}ms  {share:>5.1}%{flag}\n",
+            rank + 1,
+            answer.day,
+            answer.part,
+            milliseconds(answer.duration),
+        ));
+    }
+    let over_budget = answers
+        .iter()
+        .filter(|(_, answer)| answer.duration > part_budget)
+        .count();
+    if over_budget > 0 {
+        summary.push_str(&format!(
+            "{over_budget} parts over the budget of {:.0}ms per part\n",
+            milliseconds(part_budget),
+        ));
+    }
+    let verdict = if total <= budget { "within" } else { "over" };
+    summary.push_str(&format!(
+        "Total {:.1}ms, {verdict} the budget of {:.0}ms\n",
+        milliseconds(total),
+        milliseconds(budget),
+    ));
+    summary
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::Answer;
+
+    use super::*;
+
+    fn result(day: u8, part_millis: [u64; 2]) -> (u16, DayResult) {
+        let answers = [1, 2]
+            .into_iter()
+            .zip(part_millis)
+            .map(|(part, millis)| Answer {
+                day,
+                part,
+                result: Ok(String::new()),
+                duration: Duration::from_millis(millis),
+            })
+            .collect();
+        (
+            2023,
+            DayResult {
+                day,
+                answers,
+                duration: Duration::from_millis(part_millis.iter().sum()),
+            },
+        )
+    }
+
+    #[test]
+    fn summary_should_rank_parts_from_the_slowest() {
+        let results = [result(6, [10, 240]), result(8, [50, 700])];
+
+        assert_eq!(
+            summary(&results, Duration::from_secs(1), Duration::from_secs(1)),
+            "Rank  Year  Day  Part        Time   Share
+   1  2023    8     2     700.0ms   70.0%
+   2  2023    6     2     240.0ms   24.0%
+   3  2023    8     1      50.0ms    5.0%
+   4  2023    6     1      10.0ms    1.0%
+Total 1000.0ms, within the budget of 1000ms
+",
+        );
+    }
+
+    #[test]
+    fn summary_should_tell_when_the_budget_is_exceeded() {
+        let summary = summary(
+            &[result(8, [50, 700])],
+            Duration::from_millis(500),
+            Duration::from_secs(1),
+        );
+
+        assert!(summary.ends_with("Total 750.0ms, over the budget of 500ms\n"));
+    }
+
+    #[test]
+    fn summary_should_flag_the_parts_over_the_part_budget() {
+        let results = [result(6, [10, 240]), result(8, [50, 700])];
+
+        assert_eq!(
+            summary(&results, Duration::from_secs(1), Duration::from_millis(200)),
+            "Rank  Year  Day  Part        Time   Share
+   1  2023    8     2     700.0ms   70.0%  over budget
+   2  2023    6     2     240.0ms   24.0%  over budget
+   3  2023    8     1      50.0ms    5.0%
+   4  2023    6     1      10.0ms    1.0%
+2 parts over the budget of 200ms per part
+Total 1000.0ms, within the budget of 1000ms
+",
+        );
+    }
+}
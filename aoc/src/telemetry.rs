@@ -0,0 +1,48 @@
+use std::sync::{Mutex, PoisonError};
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::SpanExporter;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// The provider whose traces are still to export, taken by the first shutdown.
+static PROVIDER: Mutex<Option<SdkTracerProvider>> = Mutex::new(None);
+
+/// Exports the traces when dropped.
+pub struct Telemetry;
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        shutdown();
+    }
+}
+
+/// Exports the pending traces. `std::process::exit` does not run the destructors, so it must be called before exiting.
+pub fn shutdown() {
+    let provider = PROVIDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if let Some(Err(error)) = provider.map(|provider| provider.shutdown()) {
+        eprintln!("Failed to export traces: {error}");
+    }
+}
+
+pub fn init() -> Result<Telemetry, String> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .build()
+        .map_err(|error| error.to_string())?;
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name("aoc").build())
+        .build();
+    tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("aoc")))
+        .try_init()
+        .map_err(|error| error.to_string())?;
+    *PROVIDER.lock().unwrap_or_else(PoisonError::into_inner) = Some(provider);
+    Ok(Telemetry)
+}
@@ -0,0 +1,170 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aoc_utils::grid::Grid;
+use aoc_utils::random::Rng;
+use aoc_utils::{Example, Label, Progress, Solution};
+
+/// Solves each part on a worker thread, which is abandoned with an error if it does not answer in time.
+pub struct WithTimeout {
+    solution: Arc<dyn Solution>,
+    timeout: Duration,
+}
+
+impl WithTimeout {
+    pub fn new(solution: Arc<dyn Solution>, timeout: Duration) -> Self {
+        Self { solution, timeout }
+    }
+}
+
+impl Solution for WithTimeout {
+    fn day(&self) -> u8 {
+        self.solution.day()
+    }
+
+    fn input(&self) -> &'static str {
+        self.solution.input()
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        self.solve_part(1, input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        self.solve_part(2, input)
+    }
+
+    fn solve_part(&self, part: u8, input: &str) -> Result<String, String> {
+        self.solve_part_reporting(part, input, &Progress::default())
+    }
+
+    fn solve_part_reporting(
+        &self,
+        part: u8,
+        input: &str,
+        progress: &Progress,
+    ) -> Result<String, String> {
+        let solution = Arc::clone(&self.solution);
+        let input = input.to_string();
+        let progress = progress.clone();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || sender.send(solution.solve_part_reporting(part, &input, &progress)));
+        receiver
+            .recv_timeout(self.timeout)
+            .unwrap_or_else(|error| match error {
+                RecvTimeoutError::Timeout => {
+                    Err(format!("Timed out after {}s", self.timeout.as_secs_f64()))
+                }
+                RecvTimeoutError::Disconnected => Err("Panicked".to_string()),
+            })
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        self.solution.validate(input)
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.solution.title()
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        self.solution.labels()
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        self.solution.known_answers()
+    }
+
+    fn parts(&self) -> &'static [u8] {
+        self.solution.parts()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        self.solution.examples()
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        self.solution.generate(size, rng)
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        self.solution.explain(part, input)
+    }
+
+    fn visualize(&self, input: &str) -> Result<String, String> {
+        self.solution.visualize(input)
+    }
+
+    fn frames(&self, input: &str) -> Result<Vec<Grid<char>>, String> {
+        self.solution.frames(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct SlowSolution;
+
+    impl Solution for SlowSolution {
+        fn day(&self) -> u8 {
+            12
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, _input: &str) -> Result<String, String> {
+            Ok("Fast".to_string())
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            thread::sleep(Duration::from_secs(5));
+            Ok("Slow".to_string())
+        }
+    }
+
+    struct PanickingSolution;
+
+    impl Solution for PanickingSolution {
+        fn day(&self) -> u8 {
+            23
+        }
+
+        fn input(&self) -> &'static str {
+            ""
+        }
+
+        fn part_one(&self, _input: &str) -> Result<String, String> {
+            panic!("Brute force gave up")
+        }
+
+        fn part_two(&self, _input: &str) -> Result<String, String> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn parts_over_the_timeout_should_be_abandoned() {
+        let solution = WithTimeout::new(Arc::new(SlowSolution), Duration::from_millis(50));
+
+        let result = solution.run();
+
+        assert_eq!(result.answers[0].result, Ok("Fast".to_string()));
+        assert_eq!(
+            result.answers[1].result,
+            Err("Timed out after 0.05s".to_string())
+        );
+        assert!(result.duration < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn panicking_parts_should_fail() {
+        let solution = WithTimeout::new(Arc::new(PanickingSolution), Duration::from_secs(1));
+
+        assert_eq!(solution.part_one(""), Err("Panicked".to_string()));
+    }
+}
@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use advent2023::day1::{calibration_sum, calibration_sums, fixed_calibration_sum, Day1};
+use aoc_utils::Solution;
+
+fn both_parts(c: &mut Criterion) {
+    let input = Day1.input();
+    let mut group = c.benchmark_group("day1");
+    group.bench_function("two passes", |b| {
+        b.iter(|| {
+            (
+                calibration_sum(black_box(input)),
+                fixed_calibration_sum(black_box(input)),
+            )
+        })
+    });
+    group.bench_function("single pass", |b| {
+        b.iter(|| calibration_sums(black_box(input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, both_parts);
+criterion_main!(benches);
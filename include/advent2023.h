@@ -0,0 +1,32 @@
+#ifndef ADVENT2023_H
+#define ADVENT2023_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define ADVENT2023_OK 0
+#define ADVENT2023_ERROR 1
+#define ADVENT2023_INVALID_ARGUMENT (-1)
+#define ADVENT2023_BUFFER_TOO_SMALL (-2)
+#define ADVENT2023_PANIC (-3)
+
+/*
+ * Solves a part of a day for the given UTF-8 input.
+ *
+ * The answer, or the error message, is written to `out` as a NUL-terminated UTF-8 string.
+ * `out_len` holds the capacity of `out` and receives the length of the message, without the
+ * terminating NUL, so that a caller can retry with a larger buffer when
+ * ADVENT2023_BUFFER_TOO_SMALL is returned.
+ */
+int32_t advent2023_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t input_len, uint8_t *out,
+                         size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
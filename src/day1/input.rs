@@ -1,4 +1,4 @@
-pub const INPUT: &[u8] = b"heightseven4two5
+pub const INPUT: &str = "heightseven4two5
 npskfdstpk2knsm
 djnrmpxjbsbpgzvtjkhq6pkkfshx
 kgsddxsevensevenlcmkdlcgtfbqxmlnkhbnvhshkckppn2
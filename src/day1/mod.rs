@@ -1,109 +1,366 @@
-use std::sync::OnceLock;
+use std::ops::RangeInclusive;
 
-use crate::input::read_lines;
-use crate::Solution;
+use aoc_utils::checked::CheckedIterator;
+use aoc_utils::input::non_empty_lines;
+use aoc_utils::random::Rng;
 
-mod input;
+use crate::{Example, Label, Solution, Variant};
 
-fn calibration_document() -> &'static Vec<String> {
-    static CALIBRATION_DOCUMENT: OnceLock<Vec<String>> = OnceLock::new();
-    CALIBRATION_DOCUMENT.get_or_init(|| read_lines(input::INPUT).collect())
-}
+mod input;
 
 pub struct Day1;
 
+const EXAMPLE1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+const EXAMPLE2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "calibration-sum",
+        text: "Sum of all of the calibration values",
+    },
+    Label {
+        key: "fixed-calibration-sum",
+        text: "Sum of all of the fixed calibration values",
+    },
+];
+
 impl Solution for Day1 {
     fn day(&self) -> u8 {
         1
     }
 
-    fn part_one(&self) -> String {
-        format!(
-            "Sum of all of the calibration values: {}",
-            sum_of_calibration_values(calibration_document())
-        )
+    fn title(&self) -> Option<&'static str> {
+        Some("Trebuchet?!")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["56049", "54530"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let sum = calibration_sum(input)?;
+        Ok(ANSWER_LABELS[0].answer(sum))
     }
 
-    fn part_two(&self) -> String {
-        format!(
-            "Sum of all of the fixed calibration values: {}",
-            sum_of_fixed_calibration_values(calibration_document())
-        )
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let sum = fixed_calibration_sum(input)?;
+        Ok(ANSWER_LABELS[1].answer(sum))
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE1,
+                answer: "Sum of all of the calibration values: 142",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE2,
+                answer: "Sum of all of the fixed calibration values: 281",
+            },
+        ]
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![
+            Variant {
+                name: "single pass",
+                part: 1,
+                solve: |input| {
+                    let (sum, _) = calibration_sums(input)?;
+                    Ok(ANSWER_LABELS[0].answer(sum))
+                },
+            },
+            Variant {
+                name: "single pass",
+                part: 2,
+                solve: |input| {
+                    let (_, sum) = calibration_sums(input)?;
+                    Ok(ANSWER_LABELS[1].answer(sum))
+                },
+            },
+        ]
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        Ok(random_document(size, rng))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        non_empty_lines(input)
+            .map(|line| explain_line(line, DigitSet::Strict, part == 2))
+            .collect()
+    }
+}
+
+/// Lines of letters, digits and spelled out digits, each with at least one digit.
+fn random_document(lines: usize, rng: &mut Rng) -> String {
+    let digit_names = Language::English.words();
+    let mut document = String::new();
+    for _ in 0..lines {
+        let mut tokens = (0..rng.range(2..=12))
+            .map(|_| match rng.range(0..=3) {
+                0 => rng.range(1..=9).to_string(),
+                1 => digit_names[rng.range(1..=9) as usize].to_string(),
+                _ => char::from(b'a' + rng.range(0..=25) as u8).to_string(),
+            })
+            .collect::<Vec<_>>();
+        let digit = rng.range(1..=9).to_string();
+        tokens.insert(rng.index(tokens.len() + 1), digit);
+        document.push_str(&tokens.concat());
+        document.push('\n');
+    }
+    document
+}
+
+pub fn calibration_sum(input: &str) -> Result<u32, String> {
+    calibration_values(non_empty_lines(input), DigitSet::Strict, None)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .checked_sum()
+        .ok_or_else(|| "Sum of calibration values overflows".to_string())
+}
+
+pub fn fixed_calibration_sum(input: &str) -> Result<u32, String> {
+    calibration_values(
+        non_empty_lines(input),
+        DigitSet::Strict,
+        Some(Language::English),
+    )
+    .collect::<Result<Vec<_>, _>>()?
+    .into_iter()
+    .checked_sum()
+    .ok_or_else(|| "Sum of fixed calibration values overflows".to_string())
 }
 
-fn sum_of_calibration_values<I: IntoIterator<Item = &'static String>>(lines: I) -> u32 {
+/// Both [`calibration_sum`] and [`fixed_calibration_sum`], looking for digits and spelled out digits at the same time
+/// so that each line is read only once from each end.
+pub fn calibration_sums(input: &str) -> Result<(u32, u32), String> {
+    let digit_names = Language::English.digit_names();
+    let mut sum = 0u32;
+    let mut fixed_sum = 0u32;
+    for line in non_empty_lines(input) {
+        let bytes = line.as_bytes();
+        let (first, fixed_first) = first_digits(bytes, 0..bytes.len(), digit_names);
+        let (last, fixed_last) = first_digits(bytes, (0..bytes.len()).rev(), digit_names);
+        let (Some(first), Some(last)) = (first, last) else {
+            return Err(format!("No digit in line: {line}"));
+        };
+        let (Some(fixed_first), Some(fixed_last)) = (fixed_first, fixed_last) else {
+            return Err(format!("No digit in line: {line}"));
+        };
+        sum = sum
+            .checked_add(first * 10 + last)
+            .ok_or_else(|| "Sum of calibration values overflows".to_string())?;
+        fixed_sum = fixed_sum
+            .checked_add(fixed_first * 10 + fixed_last)
+            .ok_or_else(|| "Sum of fixed calibration values overflows".to_string())?;
+    }
+    Ok((sum, fixed_sum))
+}
+
+/// The first digit found at the given indices, and the first digit or spelled out digit.
+fn first_digits<I: Iterator<Item = usize>>(
+    line: &[u8],
+    indices: I,
+    digit_names: [&[u8]; 10],
+) -> (Option<u32>, Option<u32>) {
+    let mut fixed = None;
+    for index in indices {
+        if let Some(digit) = find_digit(&line[index], DigitSet::Strict) {
+            return (Some(digit), fixed.or(Some(digit)));
+        }
+        if fixed.is_none() {
+            fixed = find_letter_digit_at_index(line, index, digit_names, DigitSet::Strict);
+        }
+    }
+    (None, fixed)
+}
+
+/// The calibration value of each line, with the digits spelled out in `words` too if any, or an error for the lines
+/// without digits.
+pub fn calibration_values<'a, I, S>(
+    lines: I,
+    digits: DigitSet,
+    words: Option<Language<'a>>,
+) -> impl Iterator<Item = Result<u32, String>> + 'a
+where
+    I: IntoIterator<Item = S>,
+    I::IntoIter: 'a,
+    S: AsRef<str>,
+{
+    let digit_names = words.map_or([&[][..]; 10], Language::digit_names);
+    lines.into_iter().map(move |line| {
+        let line = line.as_ref();
+        calibration_value(line.as_bytes(), digit_names, digits)
+            .ok_or_else(|| format!("No digit in line: {line}"))
+    })
+}
+
+pub fn sum_of_calibration_values<I, S>(lines: I, digits: DigitSet) -> Option<u32>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
     lines
         .into_iter()
-        .map(|line| parse_calibration_value(line))
-        .sum()
+        .map(|line| parse_calibration_value(line.as_ref(), digits))
+        .checked_sum()
 }
 
-fn sum_of_fixed_calibration_values<I: IntoIterator<Item = &'static String>>(lines: I) -> u32 {
+pub fn sum_of_fixed_calibration_values<I, S>(lines: I, digits: DigitSet) -> Option<u32>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    sum_of_fixed_calibration_values_with(Language::English, lines, digits)
+}
+
+/// Same as [`sum_of_fixed_calibration_values`], with the digits spelled out in the given language.
+pub fn sum_of_fixed_calibration_values_with<I, S>(
+    words: Language,
+    lines: I,
+    digits: DigitSet,
+) -> Option<u32>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let names = words.digit_names();
     lines
         .into_iter()
-        .map(|line| parse_calibration_value_with_letter_digits(line))
-        .sum()
+        .map(|line| calibration_value(line.as_ref().as_bytes(), names, digits).unwrap())
+        .checked_sum()
 }
 
-fn parse_calibration_value(line: &str) -> u32 {
-    let first_digit = line.chars().find(|c| ('1'..='9').contains(c)).unwrap();
-    let second_digit = line
-        .chars()
-        .rev()
-        .find(|c| ('1'..='9').contains(c))
-        .unwrap();
-    to_u32(first_digit) * 10 + to_u32(second_digit)
+/// The words spelling out the digits in a calibration line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Language<'a> {
+    English,
+    French,
+    /// The words for zero to nine, in that order. Empty words are never matched.
+    Custom([&'a str; 10]),
+}
+
+impl<'a> Language<'a> {
+    pub fn words(self) -> [&'a str; 10] {
+        match self {
+            Language::English => [
+                "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+            ],
+            Language::French => [
+                "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+            ],
+            Language::Custom(words) => words,
+        }
+    }
+
+    fn digit_names(self) -> [&'a [u8]; 10] {
+        self.words().map(str::as_bytes)
+    }
 }
 
-fn to_u32(digit: char) -> u32 {
-    (digit as u32) - 0x30
+/// The digits accepted in a calibration line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DigitSet {
+    /// Only 1 to 9, as in the puzzle.
+    Strict,
+    /// 0 to 9, including `0` and `zero`.
+    Lenient,
 }
 
-fn parse_calibration_value_with_letter_digits(line: &str) -> u32 {
-    let line = line.as_bytes();
-    let first_digit = find_first_digit(line);
-    let second_digit = find_last_digit(line);
+impl DigitSet {
+    fn digits(self) -> RangeInclusive<u32> {
+        match self {
+            DigitSet::Strict => 1..=9,
+            DigitSet::Lenient => 0..=9,
+        }
+    }
+
+    fn contains(self, digit: u32) -> bool {
+        self.digits().contains(&digit)
+    }
+}
+
+/// Panics if the line has no digit, see [`calibration_values`] otherwise.
+pub fn parse_calibration_value(line: &str, digits: DigitSet) -> u32 {
+    let to_digit = |c: char| c.to_digit(10).filter(|digit| digits.contains(*digit));
+    let first_digit = line.chars().find_map(to_digit).unwrap();
+    let second_digit = line.chars().rev().find_map(to_digit).unwrap();
     first_digit * 10 + second_digit
 }
 
-const DIGIT_NAMES: [&[u8]; 10] = [
-    b"zero", b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
-];
+/// Panics if the line has no digit, see [`calibration_values`] otherwise.
+pub fn parse_calibration_value_with_letter_digits(line: &str, digits: DigitSet) -> u32 {
+    calibration_value(line.as_bytes(), Language::English.digit_names(), digits).unwrap()
+}
 
-fn find_first_digit(line: &[u8]) -> u32 {
-    (0..line.len())
-        .find_map(|index| {
-            find_digit(&line[index])
-                .or_else(|| find_letter_digit_at_index(line, index, DIGIT_NAMES))
-        })
-        .unwrap()
+fn calibration_value(line: &[u8], digit_names: [&[u8]; 10], digits: DigitSet) -> Option<u32> {
+    let first_digit = find_first_digit(line, digit_names, digits)?;
+    let second_digit = find_last_digit(line, digit_names, digits)?;
+    Some(first_digit * 10 + second_digit)
+}
+
+fn find_first_digit(line: &[u8], digit_names: [&[u8]; 10], digits: DigitSet) -> Option<u32> {
+    (0..line.len()).find_map(|index| find_digit_at_index(line, index, digit_names, digits))
 }
 
-fn find_last_digit(line: &[u8]) -> u32 {
+fn find_last_digit(line: &[u8], digit_names: [&[u8]; 10], digits: DigitSet) -> Option<u32> {
     (0..line.len())
         .rev()
-        .find_map(|index| {
-            find_digit(&line[index])
-                .or_else(|| find_letter_digit_at_index(line, index, DIGIT_NAMES))
-        })
-        .unwrap()
+        .find_map(|index| find_digit_at_index(line, index, digit_names, digits))
 }
 
-fn find_digit(c: &u8) -> Option<u32> {
-    if (b'1'..=b'9').contains(c) {
-        Some((*c - 0x30) as u32)
-    } else {
-        None
-    }
+fn find_digit_at_index(
+    line: &[u8],
+    index: usize,
+    digit_names: [&[u8]; 10],
+    digits: DigitSet,
+) -> Option<u32> {
+    find_digit(&line[index], digits)
+        .or_else(|| find_letter_digit_at_index(line, index, digit_names, digits))
 }
 
-fn find_letter_digit_at_index(line: &[u8], index: usize, digit_names: [&[u8]; 10]) -> Option<u32> {
-    (1..=9).find(|digit| has_digit(line, index, digit_names[*digit as usize]))
+fn find_digit(c: &u8, digits: DigitSet) -> Option<u32> {
+    (*c as char)
+        .to_digit(10)
+        .filter(|digit| digits.contains(*digit))
+}
+
+fn find_letter_digit_at_index(
+    line: &[u8],
+    index: usize,
+    digit_names: [&[u8]; 10],
+    digits: DigitSet,
+) -> Option<u32> {
+    digits
+        .digits()
+        .find(|digit| has_digit(line, index, digit_names[*digit as usize]))
 }
 
 fn has_digit(line: &[u8], index: usize, digit_letters: &[u8]) -> bool {
-    if line.len() < index + digit_letters.len() {
+    if digit_letters.is_empty() || line.len() < index + digit_letters.len() {
         return false;
     }
     for i in 0..digit_letters.len() {
@@ -114,73 +371,100 @@ fn has_digit(line: &[u8], index: usize, digit_letters: &[u8]) -> bool {
     true
 }
 
+fn explain_line(line: &str, digits: DigitSet, letter_digits: bool) -> String {
+    let bytes = line.as_bytes();
+    let digit_names = Language::English.digit_names();
+    let describe = |index: usize| {
+        if let Some(digit) = find_digit(&bytes[index], digits) {
+            return Some((digit, format!("'{digit}' at {index}")));
+        }
+        if !letter_digits {
+            return None;
+        }
+        find_letter_digit_at_index(bytes, index, digit_names, digits).map(|digit| {
+            let name = String::from_utf8_lossy(digit_names[digit as usize]);
+            (digit, format!("\"{name}\" at {index}"))
+        })
+    };
+    let first = (0..bytes.len()).find_map(describe);
+    let last = (0..bytes.len()).rev().find_map(describe);
+    match first.zip(last) {
+        Some(((first, first_match), (last, last_match))) => format!(
+            "{line}: first {first_match}, last {last_match}, value {}",
+            first * 10 + last,
+        ),
+        None => format!("{line}: no digit"),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
-    fn example1() -> &'static Vec<String> {
-        static EXAMPLE: OnceLock<Vec<String>> = OnceLock::new();
-        EXAMPLE.get_or_init(|| {
-            read_lines(
-                b"1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet
-"
-                .as_slice(),
-            )
-            .collect()
-        })
+    fn example1() -> Vec<&'static str> {
+        non_empty_lines(EXAMPLE1).collect()
     }
 
-    fn example2() -> &'static Vec<String> {
-        static EXAMPLE: OnceLock<Vec<String>> = OnceLock::new();
-        EXAMPLE.get_or_init(|| {
-            read_lines(
-                b"two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen
-"
-                .as_slice(),
-            )
-            .collect()
-        })
+    fn example2() -> Vec<&'static str> {
+        non_empty_lines(EXAMPLE2).collect()
     }
 
     #[test]
     fn parse_calibration_value_should_return_12_for_the_first_line_of_example1() {
-        assert_eq!(parse_calibration_value(&example1()[0]), 12);
+        assert_eq!(parse_calibration_value(example1()[0], DigitSet::Strict), 12);
     }
 
     #[test]
     fn parse_calibration_value_should_return_38_for_the_second_line_of_example1() {
-        assert_eq!(parse_calibration_value(&example1()[1]), 38);
+        assert_eq!(parse_calibration_value(example1()[1], DigitSet::Strict), 38);
     }
 
     #[test]
     fn parse_calibration_value_should_return_15_for_the_third_line_of_example1() {
-        assert_eq!(parse_calibration_value(&example1()[2]), 15);
+        assert_eq!(parse_calibration_value(example1()[2], DigitSet::Strict), 15);
     }
 
     #[test]
     fn parse_calibration_value_should_return_77_for_the_fourth_line_of_example1() {
-        assert_eq!(parse_calibration_value(&example1()[3]), 77);
+        assert_eq!(parse_calibration_value(example1()[3], DigitSet::Strict), 77);
     }
 
     #[test]
     fn part1_example() {
-        assert_eq!(sum_of_calibration_values(example1()), 142);
+        assert_eq!(
+            sum_of_calibration_values(example1(), DigitSet::Strict),
+            Some(142)
+        );
+    }
+
+    #[test]
+    fn calibration_sums_should_compute_both_parts_at_once() {
+        assert_eq!(calibration_sums(&example1().join("\n")), Ok((142, 142)));
+        assert_eq!(calibration_sums("two1nine\nxtwone3four"), Ok((44, 53)));
+    }
+
+    #[test]
+    fn calibration_sums_should_agree_with_both_parts_on_input() {
+        assert_eq!(
+            calibration_sums(input::INPUT),
+            calibration_sum(input::INPUT)
+                .and_then(|sum| Ok((sum, fixed_calibration_sum(input::INPUT)?))),
+        );
+    }
+
+    #[test]
+    fn calibration_sums_should_report_lines_without_digits() {
+        assert_eq!(
+            calibration_sums("1abc2\neightwothree"),
+            Err("No digit in line: eightwothree".to_string()),
+        );
     }
 
     #[test]
     fn parse_calibration_value_with_letter_digits_should_return_29_for_the_first_line_of_example2()
     {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[0]),
+            parse_calibration_value_with_letter_digits(example2()[0], DigitSet::Strict),
             29,
         );
     }
@@ -189,7 +473,7 @@ zoneight234
     fn parse_calibration_value_with_letter_digits_should_return_83_for_the_second_line_of_example2()
     {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[1]),
+            parse_calibration_value_with_letter_digits(example2()[1], DigitSet::Strict),
             83,
         );
     }
@@ -198,7 +482,7 @@ zoneight234
     fn parse_calibration_value_with_letter_digits_should_return_13_for_the_third_line_of_example2()
     {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[2]),
+            parse_calibration_value_with_letter_digits(example2()[2], DigitSet::Strict),
             13,
         );
     }
@@ -207,7 +491,7 @@ zoneight234
     fn parse_calibration_value_with_letter_digits_should_return_24_for_the_fourth_line_of_example2()
     {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[3]),
+            parse_calibration_value_with_letter_digits(example2()[3], DigitSet::Strict),
             24,
         );
     }
@@ -216,7 +500,7 @@ zoneight234
     fn parse_calibration_value_with_letter_digits_should_return_42_for_the_fifth_line_of_example2()
     {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[4]),
+            parse_calibration_value_with_letter_digits(example2()[4], DigitSet::Strict),
             42,
         );
     }
@@ -225,7 +509,7 @@ zoneight234
     fn parse_calibration_value_with_letter_digits_should_return_14_for_the_sixth_line_of_example2()
     {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[5]),
+            parse_calibration_value_with_letter_digits(example2()[5], DigitSet::Strict),
             14,
         );
     }
@@ -234,13 +518,147 @@ zoneight234
     fn parse_calibration_value_with_letter_digits_should_return_76_for_the_seventh_line_of_example2(
     ) {
         assert_eq!(
-            parse_calibration_value_with_letter_digits(&example2()[6]),
+            parse_calibration_value_with_letter_digits(example2()[6], DigitSet::Strict),
             76,
         );
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(sum_of_fixed_calibration_values(example2()), 281);
+        assert_eq!(
+            sum_of_fixed_calibration_values(example2(), DigitSet::Strict),
+            Some(281)
+        );
+    }
+
+    #[test]
+    fn parse_calibration_value_should_ignore_0_in_strict_mode() {
+        assert_eq!(parse_calibration_value("0ab5", DigitSet::Strict), 55);
+    }
+
+    #[test]
+    fn parse_calibration_value_should_accept_0_in_lenient_mode() {
+        assert_eq!(parse_calibration_value("0ab5", DigitSet::Lenient), 5);
+    }
+
+    #[test]
+    fn parse_calibration_value_with_letter_digits_should_ignore_zero_in_strict_mode() {
+        assert_eq!(
+            parse_calibration_value_with_letter_digits("zero1two0", DigitSet::Strict),
+            12,
+        );
+    }
+
+    #[test]
+    fn parse_calibration_value_with_letter_digits_should_accept_zero_in_lenient_mode() {
+        assert_eq!(
+            parse_calibration_value_with_letter_digits("zero1twozero", DigitSet::Lenient),
+            0,
+        );
+        assert_eq!(
+            parse_calibration_value_with_letter_digits("threezero", DigitSet::Lenient),
+            30,
+        );
+    }
+
+    #[test]
+    fn sum_of_fixed_calibration_values_with_should_read_french_digits() {
+        assert_eq!(
+            sum_of_fixed_calibration_values_with(
+                Language::French,
+                ["deux1neuf", "huitrois", "zéro7"],
+                DigitSet::Lenient,
+            ),
+            Some(29 + 83 + 7),
+        );
+    }
+
+    #[test]
+    fn sum_of_fixed_calibration_values_with_should_read_custom_digits() {
+        let words = [
+            "nul", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+        ];
+
+        assert_eq!(
+            sum_of_fixed_calibration_values_with(
+                Language::Custom(words),
+                ["tweeacht", "zeven3", "one4"],
+                DigitSet::Strict,
+            ),
+            Some(28 + 73 + 44),
+        );
+    }
+
+    #[test]
+    fn english_should_be_the_default_language() {
+        assert_eq!(
+            sum_of_fixed_calibration_values_with(Language::English, example2(), DigitSet::Strict),
+            sum_of_fixed_calibration_values(example2(), DigitSet::Strict),
+        );
+    }
+
+    #[test]
+    fn calibration_values_should_give_the_value_of_each_line() {
+        assert_eq!(
+            calibration_values(example1(), DigitSet::Strict, None).collect::<Vec<_>>(),
+            vec![Ok(12), Ok(38), Ok(15), Ok(77)],
+        );
+        assert_eq!(
+            calibration_values(example2(), DigitSet::Strict, Some(Language::English))
+                .collect::<Vec<_>>(),
+            vec![Ok(29), Ok(83), Ok(13), Ok(24), Ok(42), Ok(14), Ok(76)],
+        );
+    }
+
+    #[test]
+    fn calibration_values_should_report_lines_without_digits() {
+        assert_eq!(
+            calibration_values(["a1", "abc"], DigitSet::Strict, None).collect::<Vec<_>>(),
+            vec![Ok(11), Err("No digit in line: abc".to_string())],
+        );
+    }
+
+    #[test]
+    fn calibration_sum_should_fail_on_lines_without_digits() {
+        assert_eq!(
+            calibration_sum("1abc2\nabc\n"),
+            Err("No digit in line: abc".to_string()),
+        );
+        assert_eq!(
+            fixed_calibration_sum("two\nxyz"),
+            Err("No digit in line: xyz".to_string()),
+        );
+    }
+
+    #[test]
+    fn explain_should_show_where_the_digits_come_from() {
+        assert_eq!(
+            Day1.explain(1, "treb7uchet\nabc"),
+            vec![
+                "treb7uchet: first '7' at 4, last '7' at 4, value 77",
+                "abc: no digit"
+            ],
+        );
+        assert_eq!(
+            Day1.explain(2, "eightwo3\nzoneight"),
+            vec![
+                "eightwo3: first \"eight\" at 0, last '3' at 7, value 83",
+                "zoneight: first \"one\" at 1, last \"eight\" at 3, value 18",
+            ],
+        );
+    }
+
+    #[test]
+    fn generated_document_should_have_a_digit_on_every_line() {
+        let document = random_document(100, &mut Rng::new(1));
+
+        assert_eq!(non_empty_lines(&document).count(), 100);
+        assert_eq!(
+            calibration_sums(&document),
+            Ok((
+                calibration_sum(&document).unwrap(),
+                fixed_calibration_sum(&document).unwrap(),
+            ))
+        );
     }
 }
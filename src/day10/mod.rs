@@ -0,0 +1,422 @@
+use aoc_utils::grid::Grid;
+use aoc_utils::input::read_grid;
+
+use crate::{Example, Label, Solution};
+
+mod input;
+
+pub struct Day10;
+
+const SQUARE_LOOP: &str = "
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+
+const COMPLEX_LOOP: &str = "
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+";
+
+const ENCLOSED_TILES: &str = "
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+const LARGER_ENCLOSED_TILES: &str = "
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "farthest-loop-point-steps",
+        text: "Steps to the farthest point of the loop",
+    },
+    Label {
+        key: "enclosed-tiles",
+        text: "Tiles enclosed by the loop",
+    },
+];
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Pipe Maze")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let steps = farthest_point_steps(input)?;
+        Ok(ANSWER_LABELS[0].answer(steps))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let tiles = enclosed_tiles_count(input)?;
+        Ok(ANSWER_LABELS[1].answer(tiles))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_maze(input)?.find_loop().map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: SQUARE_LOOP,
+                answer: "Steps to the farthest point of the loop: 4",
+            },
+            Example {
+                part: 1,
+                input: COMPLEX_LOOP,
+                answer: "Steps to the farthest point of the loop: 8",
+            },
+            Example {
+                part: 2,
+                input: ENCLOSED_TILES,
+                answer: "Tiles enclosed by the loop: 4",
+            },
+            Example {
+                part: 2,
+                input: LARGER_ENCLOSED_TILES,
+                answer: "Tiles enclosed by the loop: 8",
+            },
+        ]
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse_maze(input: &str) -> Result<Maze, String> {
+    let grid = read_grid(input)?;
+    let mut start = None;
+    for (y, row) in grid.rows().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            match tile {
+                b'S' if start.is_some() => return Err("Several starting positions".to_string()),
+                b'S' => start = Some((x, y)),
+                b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' => {}
+                _ => {
+                    return Err(format!(
+                        "Invalid tile '{}' at line {}, column {}",
+                        char::from(*tile),
+                        y + 1,
+                        x + 1
+                    ))
+                }
+            }
+        }
+    }
+    let start = start.ok_or_else(|| "No starting position".to_string())?;
+    Ok(Maze { grid, start })
+}
+
+pub fn farthest_point_steps(input: &str) -> Result<usize, String> {
+    Ok(parse_maze(input)?.find_loop()?.len() / 2)
+}
+
+pub fn enclosed_tiles_count(input: &str) -> Result<usize, String> {
+    let main_loop = parse_maze(input)?.find_loop()?;
+    Ok(enclosed_tiles(&main_loop))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+/// The directions a pipe connects, or `None` for the ground and the starting position.
+fn connections(tile: u8) -> Option<[Direction; 2]> {
+    match tile {
+        b'|' => Some([Direction::North, Direction::South]),
+        b'-' => Some([Direction::East, Direction::West]),
+        b'L' => Some([Direction::North, Direction::East]),
+        b'J' => Some([Direction::North, Direction::West]),
+        b'7' => Some([Direction::South, Direction::West]),
+        b'F' => Some([Direction::East, Direction::South]),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Maze {
+    grid: Grid<u8>,
+    start: (usize, usize),
+}
+
+impl Maze {
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+
+    fn neighbour(&self, (x, y): (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let position = match direction {
+            Direction::North => (x, y.checked_sub(1)?),
+            Direction::East => (x + 1, y),
+            Direction::South => (x, y + 1),
+            Direction::West => (x.checked_sub(1)?, y),
+        };
+        self.grid.get(position.0, position.1).map(|_| position)
+    }
+
+    fn connects(&self, position: (usize, usize), direction: Direction) -> bool {
+        self.grid
+            .get(position.0, position.1)
+            .and_then(|tile| connections(*tile))
+            .is_some_and(|directions| directions.contains(&direction))
+    }
+
+    /// The directions of the pipes that connect to the starting position, which may include pipes that are not part of
+    /// the loop.
+    pub fn start_connections(&self) -> Vec<Direction> {
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| {
+                self.neighbour(self.start, *direction)
+                    .is_some_and(|neighbour| self.connects(neighbour, direction.opposite()))
+            })
+            .collect()
+    }
+
+    /// The positions of the loop going through the starting position, from it. Each pipe that connects to the starting
+    /// position is followed until one of them leads back to it.
+    pub fn find_loop(&self) -> Result<Vec<(usize, usize)>, String> {
+        let directions = self.start_connections();
+        if directions.len() < 2 {
+            return Err(format!(
+                "{} pipes connect to the starting position instead of 2",
+                directions.len()
+            ));
+        }
+        let mut error = String::new();
+        for direction in directions {
+            match self.follow_loop(direction) {
+                Ok(main_loop) => return Ok(main_loop),
+                Err(failure) => error = failure,
+            }
+        }
+        Err(error)
+    }
+
+    /// The positions visited from the starting position in the direction, up to the starting position.
+    fn follow_loop(&self, mut direction: Direction) -> Result<Vec<(usize, usize)>, String> {
+        let mut position = self.start;
+        let mut main_loop = vec![position];
+        loop {
+            position = self
+                .neighbour(position, direction)
+                .ok_or("The loop leaves the maze")?;
+            if position == self.start {
+                return Ok(main_loop);
+            }
+            let [first, second] = self
+                .grid
+                .get(position.0, position.1)
+                .and_then(|tile| connections(*tile))
+                .filter(|directions| directions.contains(&direction.opposite()))
+                .ok_or_else(|| {
+                    format!(
+                        "The loop is broken at line {}, column {}",
+                        position.1 + 1,
+                        position.0 + 1
+                    )
+                })?;
+            direction = if first == direction.opposite() {
+                second
+            } else {
+                first
+            };
+            main_loop.push(position);
+        }
+    }
+}
+
+/// The number of tiles inside the loop, from its area (shoelace formula) and the number of tiles on its boundary
+/// (Pick's theorem).
+fn enclosed_tiles(main_loop: &[(usize, usize)]) -> usize {
+    let double_area = main_loop
+        .iter()
+        .zip(main_loop.iter().cycle().skip(1))
+        .map(|(&(x1, y1), &(x2, y2))| (x1 * y2) as i64 - (x2 * y1) as i64)
+        .sum::<i64>()
+        .unsigned_abs() as usize;
+    (double_area + 2).saturating_sub(main_loop.len()) / 2
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_maze_should_find_the_starting_position() {
+        assert_eq!(parse_maze(SQUARE_LOOP).unwrap().start(), (1, 1));
+        assert_eq!(parse_maze(COMPLEX_LOOP).unwrap().start(), (0, 2));
+    }
+
+    #[test]
+    fn parse_maze_should_reject_invalid_mazes() {
+        assert_eq!(
+            parse_maze(".S\n.X"),
+            Err("Invalid tile 'X' at line 2, column 2".to_string())
+        );
+        assert_eq!(
+            parse_maze("..\n.."),
+            Err("No starting position".to_string())
+        );
+        assert_eq!(
+            parse_maze("S.\n.S"),
+            Err("Several starting positions".to_string())
+        );
+    }
+
+    #[test]
+    fn start_connections_should_ignore_pipes_that_do_not_connect_back() {
+        let maze = parse_maze(SQUARE_LOOP).unwrap();
+
+        assert_eq!(
+            maze.start_connections(),
+            vec![Direction::East, Direction::South]
+        );
+    }
+
+    #[test]
+    fn find_loop_should_ignore_stray_pipes_next_to_the_start() {
+        let maze = parse_maze(
+            "
+.....
+-S-7.
+.|.|.
+.L-J.
+.....
+",
+        )
+        .unwrap();
+
+        assert_eq!(maze.start_connections().len(), 3);
+        assert_eq!(maze.find_loop().map(|main_loop| main_loop.len()), Ok(8));
+    }
+
+    #[test]
+    fn find_loop_should_report_a_broken_loop() {
+        let maze = parse_maze(
+            "S-7
+|.|
+L-.",
+        )
+        .unwrap();
+
+        assert_eq!(
+            maze.find_loop(),
+            Err("The loop is broken at line 3, column 3".to_string())
+        );
+    }
+
+    #[test]
+    fn find_loop_should_report_a_missing_loop() {
+        let maze = parse_maze("S-\n..").unwrap();
+
+        assert_eq!(
+            maze.find_loop(),
+            Err("1 pipes connect to the starting position instead of 2".to_string())
+        );
+    }
+
+    #[test]
+    fn farthest_point_steps_should_return_4_for_square_loop() {
+        assert_eq!(farthest_point_steps(SQUARE_LOOP), Ok(4));
+    }
+
+    #[test]
+    fn farthest_point_steps_should_return_8_for_complex_loop() {
+        assert_eq!(farthest_point_steps(COMPLEX_LOOP), Ok(8));
+    }
+
+    #[test]
+    fn enclosed_tiles_count_should_return_1_for_square_loop() {
+        assert_eq!(enclosed_tiles_count(SQUARE_LOOP), Ok(1));
+    }
+
+    #[test]
+    fn enclosed_tiles_count_should_return_1_for_complex_loop() {
+        assert_eq!(enclosed_tiles_count(COMPLEX_LOOP), Ok(1));
+    }
+
+    #[test]
+    fn enclosed_tiles_count_should_return_4_for_example() {
+        assert_eq!(enclosed_tiles_count(ENCLOSED_TILES), Ok(4));
+    }
+
+    #[test]
+    fn enclosed_tiles_count_should_return_8_for_larger_example() {
+        assert_eq!(enclosed_tiles_count(LARGER_ENCLOSED_TILES), Ok(8));
+    }
+
+    #[test]
+    fn enclosed_tiles_count_should_return_10_for_example_with_junk() {
+        let input = "
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+        assert_eq!(enclosed_tiles_count(input), Ok(10));
+    }
+}
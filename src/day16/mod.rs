@@ -0,0 +1,330 @@
+use aoc_utils::grid::Grid;
+use aoc_utils::input::read_grid;
+
+#[cfg(feature = "parallel")]
+use crate::Variant;
+use crate::{Example, Label, Solution};
+
+mod input;
+
+pub struct Day16;
+
+const EXAMPLE: &str = r"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "energized-tiles",
+        text: "Energized tiles",
+    },
+    Label {
+        key: "max-energized-tiles",
+        text: "Maximum energized tiles",
+    },
+];
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("The Floor Will Be Lava")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let contraption = parse_contraption(input)?;
+        let tiles = energized_tiles(&contraption, Beam::TOP_LEFT);
+        Ok(ANSWER_LABELS[0].answer(tiles))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let contraption = parse_contraption(input)?;
+        #[cfg(feature = "parallel")]
+        let tiles = max_energized_tiles_par(&contraption);
+        #[cfg(not(feature = "parallel"))]
+        let tiles = max_energized_tiles(&contraption);
+        Ok(ANSWER_LABELS[1].answer(tiles))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_contraption(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Energized tiles: 46",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Maximum energized tiles: 51",
+            },
+        ]
+    }
+
+    #[cfg(feature = "parallel")]
+    fn variants(&self) -> Vec<Variant> {
+        vec![Variant {
+            name: "sequential",
+            part: 2,
+            solve: |input| {
+                let tiles = max_energized_tiles(&parse_contraption(input)?);
+                Ok(ANSWER_LABELS[1].answer(tiles))
+            },
+        }]
+    }
+}
+
+/// The grid of mirrors and splitters. Only `.`, `/`, `\`, `|` and `-` are accepted.
+#[tracing::instrument(skip_all)]
+pub fn parse_contraption(input: &str) -> Result<Grid<u8>, String> {
+    let grid = read_grid(input)?;
+    for (y, row) in grid.rows().enumerate() {
+        if let Some(x) = row
+            .iter()
+            .position(|tile| !matches!(tile, b'.' | b'/' | b'\\' | b'|' | b'-'))
+        {
+            return Err(format!(
+                "Invalid tile '{}' at line {}, column {}",
+                char::from(row[x]),
+                y + 1,
+                x + 1
+            ));
+        }
+    }
+    Ok(grid)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// The directions of the beam after it meets the tile.
+    fn through(self, tile: u8) -> (Direction, Option<Direction>) {
+        use Direction::*;
+
+        match (tile, self) {
+            (b'/', Up) => (Right, None),
+            (b'/', Right) => (Up, None),
+            (b'/', Down) => (Left, None),
+            (b'/', Left) => (Down, None),
+            (b'\\', Up) => (Left, None),
+            (b'\\', Left) => (Up, None),
+            (b'\\', Down) => (Right, None),
+            (b'\\', Right) => (Down, None),
+            (b'|', Left | Right) => (Up, Some(Down)),
+            (b'-', Up | Down) => (Left, Some(Right)),
+            _ => (self, None),
+        }
+    }
+}
+
+/// A beam entering a tile.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Beam {
+    pub x: usize,
+    pub y: usize,
+    pub direction: Direction,
+}
+
+impl Beam {
+    /// The beam of part one, entering the top-left corner heading right.
+    pub const TOP_LEFT: Beam = Beam {
+        x: 0,
+        y: 0,
+        direction: Direction::Right,
+    };
+
+    fn towards(self, direction: Direction, grid: &Grid<u8>) -> Option<Beam> {
+        let (x, y) = match direction {
+            Direction::Up => (self.x, self.y.checked_sub(1)?),
+            Direction::Right => (self.x + 1, self.y),
+            Direction::Down => (self.x, self.y + 1),
+            Direction::Left => (self.x.checked_sub(1)?, self.y),
+        };
+        grid.get(x, y).map(|_| Beam { x, y, direction })
+    }
+}
+
+/// The number of tiles that at least one beam goes through, starting from the given one.
+pub fn energized_tiles(contraption: &Grid<u8>, start: Beam) -> usize {
+    let mut seen = Grid::filled(contraption.width(), contraption.height(), 0u8);
+    let mut beams = Vec::new();
+    if contraption.get(start.x, start.y).is_some() {
+        beams.push(start);
+    }
+    while let Some(beam) = beams.pop() {
+        let Some(directions) = seen.get_mut(beam.x, beam.y) else {
+            continue;
+        };
+        if *directions & beam.direction.bit() != 0 {
+            continue;
+        }
+        *directions |= beam.direction.bit();
+        let (first, second) = beam
+            .direction
+            .through(contraption.get(beam.x, beam.y).copied().unwrap_or(b'.'));
+        beams.extend(beam.towards(first, contraption));
+        if let Some(second) = second {
+            beams.extend(beam.towards(second, contraption));
+        }
+    }
+    seen.rows()
+        .flatten()
+        .filter(|directions| **directions != 0)
+        .count()
+}
+
+/// The beams entering the contraption from any tile of its edges, heading inwards.
+pub fn edge_beams(contraption: &Grid<u8>) -> Vec<Beam> {
+    let (width, height) = (contraption.width(), contraption.height());
+    let beam = |x, y, direction| Beam { x, y, direction };
+    (0..width)
+        .flat_map(|x| {
+            [
+                beam(x, 0, Direction::Down),
+                beam(x, height.saturating_sub(1), Direction::Up),
+            ]
+        })
+        .chain((0..height).flat_map(|y| {
+            [
+                beam(0, y, Direction::Right),
+                beam(width.saturating_sub(1), y, Direction::Left),
+            ]
+        }))
+        .collect()
+}
+
+/// The largest number of energized tiles over all the beams entering from an edge.
+pub fn max_energized_tiles(contraption: &Grid<u8>) -> usize {
+    edge_beams(contraption)
+        .into_iter()
+        .map(|beam| energized_tiles(contraption, beam))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Same as [`max_energized_tiles`], with the entry points tried in parallel.
+#[cfg(feature = "parallel")]
+pub fn max_energized_tiles_par(contraption: &Grid<u8>) -> usize {
+    use rayon::prelude::*;
+
+    edge_beams(contraption)
+        .into_par_iter()
+        .map(|beam| energized_tiles(contraption, beam))
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        parse_contraption(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn parse_contraption_should_reject_unknown_tiles() {
+        assert_eq!(
+            parse_contraption("./\n|#"),
+            Err("Invalid tile '#' at line 2, column 2".to_string())
+        );
+    }
+
+    #[test]
+    fn energized_tiles_should_return_46_for_example() {
+        assert_eq!(energized_tiles(&example(), Beam::TOP_LEFT), 46);
+    }
+
+    #[test]
+    fn energized_tiles_should_follow_a_mirror_in_the_first_tile() {
+        let contraption = parse_contraption(
+            r"
+\..
+...
+...
+",
+        )
+        .unwrap();
+
+        assert_eq!(energized_tiles(&contraption, Beam::TOP_LEFT), 3);
+    }
+
+    #[test]
+    fn energized_tiles_should_stop_on_loops() {
+        let contraption = parse_contraption(
+            r"
+./\
+.|.
+.\/
+",
+        )
+        .unwrap();
+        let beam = Beam {
+            x: 0,
+            y: 1,
+            direction: Direction::Right,
+        };
+
+        assert_eq!(energized_tiles(&contraption, beam), 7);
+    }
+
+    #[test]
+    fn energized_tiles_from_the_best_entry_point_of_example() {
+        let beam = Beam {
+            x: 3,
+            y: 0,
+            direction: Direction::Down,
+        };
+
+        assert_eq!(energized_tiles(&example(), beam), 51);
+    }
+
+    #[test]
+    fn edge_beams_should_enter_from_every_tile_of_the_edges() {
+        assert_eq!(edge_beams(&example()).len(), 40);
+    }
+
+    #[test]
+    fn max_energized_tiles_should_return_51_for_example() {
+        assert_eq!(max_energized_tiles(&example()), 51);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn max_energized_tiles_par_should_match_max_energized_tiles() {
+        assert_eq!(max_energized_tiles_par(&example()), 51);
+    }
+}
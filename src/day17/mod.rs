@@ -0,0 +1,277 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc_utils::grid::Grid;
+use aoc_utils::input::read_digit_grid;
+
+use crate::{Example, Label, Solution};
+
+mod input;
+
+pub struct Day17;
+
+const EXAMPLE: &str = "
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+const UNFORTUNATE_PATH: &str = "
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "least-heat-loss",
+        text: "Least heat loss",
+    },
+    Label {
+        key: "least-heat-loss-ultra",
+        text: "Least heat loss with ultra crucibles",
+    },
+];
+
+impl Solution for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Clumsy Crucible")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let heat_loss = least_heat_loss(input, Crucible::REGULAR)?;
+        Ok(ANSWER_LABELS[0].answer(heat_loss))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let heat_loss = least_heat_loss(input, Crucible::ULTRA)?;
+        Ok(ANSWER_LABELS[1].answer(heat_loss))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        read_digit_grid(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Least heat loss: 102",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Least heat loss with ultra crucibles: 94",
+            },
+            Example {
+                part: 2,
+                input: UNFORTUNATE_PATH,
+                answer: "Least heat loss with ultra crucibles: 71",
+            },
+        ]
+    }
+}
+
+pub fn least_heat_loss(input: &str, crucible: Crucible) -> Result<u32, String> {
+    let city = read_digit_grid(input)?;
+    crucible
+        .least_heat_loss(&city)
+        .ok_or_else(|| "The crucible cannot reach the factory".to_string())
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn turns(self) -> [Direction; 2] {
+        match self {
+            Direction::Up | Direction::Down => [Direction::Left, Direction::Right],
+            Direction::Right | Direction::Left => [Direction::Up, Direction::Down],
+        }
+    }
+}
+
+/// A crucible on a city block, with the direction it came from and the number of blocks it moved straight.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct State {
+    x: usize,
+    y: usize,
+    direction: Direction,
+    run: usize,
+}
+
+impl State {
+    fn moved(self, direction: Direction, city: &Grid<u8>) -> Option<State> {
+        let (x, y) = match direction {
+            Direction::Up => (self.x, self.y.checked_sub(1)?),
+            Direction::Right => (self.x + 1, self.y),
+            Direction::Down => (self.x, self.y + 1),
+            Direction::Left => (self.x.checked_sub(1)?, self.y),
+        };
+        city.get(x, y)?;
+        let run = if direction == self.direction {
+            self.run + 1
+        } else {
+            1
+        };
+        Some(State {
+            x,
+            y,
+            direction,
+            run,
+        })
+    }
+}
+
+/// How many blocks a crucible must and can move in a straight line before turning.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Crucible {
+    pub min_run: usize,
+    pub max_run: usize,
+}
+
+impl Crucible {
+    /// The crucible of part one, which turns after at most three blocks.
+    pub const REGULAR: Crucible = Crucible {
+        min_run: 1,
+        max_run: 3,
+    };
+
+    /// The crucible of part two, which moves four to ten blocks before turning or stopping.
+    pub const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+    };
+
+    /// The least heat lost between the top-left block and the bottom-right one, with Dijkstra's algorithm over the
+    /// position, the direction and the length of the current run. `None` if the crucible cannot stop there.
+    pub fn least_heat_loss(&self, city: &Grid<u8>) -> Option<u32> {
+        let (width, height) = (city.width(), city.height());
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let states_per_block = 4 * (self.max_run + 1);
+        let mut losses = vec![u32::MAX; width * height * states_per_block];
+        let index = |state: &State| {
+            (state.y * width + state.x) * states_per_block
+                + state.direction as usize * (self.max_run + 1)
+                + state.run
+        };
+        let mut queue = BinaryHeap::new();
+        for direction in [Direction::Right, Direction::Down] {
+            let start = State {
+                x: 0,
+                y: 0,
+                direction,
+                run: 0,
+            };
+            losses[index(&start)] = 0;
+            queue.push(Reverse((0, start)));
+        }
+        while let Some(Reverse((loss, state))) = queue.pop() {
+            if (state.x, state.y) == (width - 1, height - 1) && state.run >= self.min_run {
+                return Some(loss);
+            }
+            if loss > losses[index(&state)] {
+                continue;
+            }
+            for next in self.moves(state, city) {
+                let next_loss = loss + u32::from(*city.get(next.x, next.y)?);
+                if next_loss < losses[index(&next)] {
+                    losses[index(&next)] = next_loss;
+                    queue.push(Reverse((next_loss, next)));
+                }
+            }
+        }
+        None
+    }
+
+    fn moves(&self, state: State, city: &Grid<u8>) -> Vec<State> {
+        let mut directions = Vec::with_capacity(3);
+        if state.run < self.max_run {
+            directions.push(state.direction);
+        }
+        if state.run >= self.min_run {
+            directions.extend(state.direction.turns());
+        }
+        directions
+            .into_iter()
+            .filter_map(|direction| state.moved(direction, city))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn least_heat_loss_should_return_102_for_example() {
+        assert_eq!(least_heat_loss(EXAMPLE, Crucible::REGULAR), Ok(102));
+    }
+
+    #[test]
+    fn least_heat_loss_should_return_94_for_example_with_ultra_crucibles() {
+        assert_eq!(least_heat_loss(EXAMPLE, Crucible::ULTRA), Ok(94));
+    }
+
+    #[test]
+    fn least_heat_loss_should_return_71_for_unfortunate_path_with_ultra_crucibles() {
+        assert_eq!(least_heat_loss(UNFORTUNATE_PATH, Crucible::ULTRA), Ok(71));
+    }
+
+    #[test]
+    fn least_heat_loss_should_turn_after_the_longest_run() {
+        assert_eq!(
+            least_heat_loss("11111", Crucible::REGULAR),
+            Err("The crucible cannot reach the factory".to_string())
+        );
+        assert_eq!(least_heat_loss("1111\n9991", Crucible::REGULAR), Ok(4));
+    }
+
+    #[test]
+    fn least_heat_loss_should_not_stop_before_the_shortest_run() {
+        assert_eq!(
+            least_heat_loss("119\n991", Crucible::ULTRA),
+            Err("The crucible cannot reach the factory".to_string())
+        );
+    }
+
+    #[test]
+    fn least_heat_loss_should_reject_invalid_digits() {
+        assert_eq!(
+            least_heat_loss("12\n3x", Crucible::REGULAR),
+            Err("Line 2: Invalid digit 'x' at column 2".to_string())
+        );
+    }
+}
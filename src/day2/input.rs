@@ -1,4 +1,4 @@
-pub const INPUT: &[u8] = b"Game 1: 1 green, 2 blue; 13 red, 2 blue, 3 green; 4 green, 14 red
+pub const INPUT: &str = "Game 1: 1 green, 2 blue; 13 red, 2 blue, 3 green; 4 green, 14 red
 Game 2: 2 blue, 11 green; 4 blue, 12 red, 4 green; 7 red, 1 blue, 9 green; 10 green, 12 red, 6 blue
 Game 3: 1 blue, 12 green, 2 red; 9 red, 16 green; 1 red, 10 green, 1 blue; 1 red, 14 green
 Game 4: 8 green, 18 blue; 4 green, 14 blue, 2 red; 3 blue, 5 green, 11 red
@@ -1,78 +1,330 @@
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
-use std::sync::OnceLock;
 
-use crate::input::{read_lines, FilterNotEmpty, ParseExt};
+use itertools::Itertools;
 use nom::bytes::complete::tag;
 use nom::character::complete::{alpha1, digit1};
+use nom::combinator::{map_opt, map_res};
 use nom::multi::separated_list0;
 use nom::sequence::tuple;
 use nom::IResult;
 
-use crate::Solution;
+use aoc_utils::checked::CheckedIterator;
+use aoc_utils::input::parse_lines;
+use aoc_utils::random::Rng;
+
+use crate::{Example, Label, Solution};
 
 mod input;
 
-fn games() -> &'static Vec<Game> {
-    static GAMES: OnceLock<Vec<Game>> = OnceLock::new();
-    GAMES.get_or_init(|| {
-        read_lines(input::INPUT)
-            .filter_not_empty()
-            .parse()
-            .collect()
-    })
+/// The number of cubes of each color in the bag for part one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Day2 {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
 }
 
-pub struct Day2;
+impl Default for Day2 {
+    fn default() -> Self {
+        Self {
+            red: 12,
+            green: 13,
+            blue: 14,
+        }
+    }
+}
+
+const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "possible-games-sum",
+        text: "Sum of IDs of possible games",
+    },
+    Label {
+        key: "minimum-powers-sum",
+        text: "Sum of minimum powers of all games",
+    },
+];
 
 impl Solution for Day2 {
     fn day(&self) -> u8 {
         2
     }
 
-    fn part_one(&self) -> String {
-        format!(
-            "Sum of IDs of possible games for 12 reds, 13 greens, and 14 blues: {}",
-            sum_of_possible_game_ids(games(), 12, 13, 14),
-        )
+    fn title(&self) -> Option<&'static str> {
+        Some("Cube Conundrum")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    /// The answers are only known for the limits of the puzzle.
+    fn known_answers(&self) -> &'static [&'static str] {
+        if *self == Self::default() {
+            &["2541", "66016"]
+        } else {
+            &[]
+        }
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let Day2 { red, green, blue } = *self;
+        let sum = possible_games_sum_with(input, red, green, blue)?;
+        Ok(format!(
+            "{} for {red} reds, {green} greens, and {blue} blues: {sum}",
+            ANSWER_LABELS[0].text
+        ))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let sum = minimum_powers_sum(input)?;
+        Ok(ANSWER_LABELS[1].answer(sum))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_lines::<Game>(input).map(drop)
     }
 
-    fn part_two(&self) -> String {
-        format!(
-            "Sum of minimum powers of all games: {}",
-            sum_of_minimum_powers(games()),
-        )
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Sum of IDs of possible games for 12 reds, 13 greens, and 14 blues: 8",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Sum of minimum powers of all games: 2286",
+            },
+        ]
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        Ok(random_games(size, rng))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        let games = parse_lines::<Game>(input).unwrap_or_default();
+        match part {
+            1 => games
+                .iter()
+                .map(|game| game.explain_possibility(self.red, self.green, self.blue))
+                .collect(),
+            2 => cube_statistics(&games).explain(),
+            _ => Vec::new(),
+        }
     }
 }
 
-fn sum_of_possible_game_ids(games: &[Game], red: u32, green: u32, blue: u32) -> u32 {
+/// Games of one to six draws, each with one to three colors of up to 20 cubes.
+fn random_games(games: usize, rng: &mut Rng) -> String {
+    let mut colors = ["red", "green", "blue"];
+    let mut lines = String::new();
+    for number in 1..=games {
+        let draws = (0..rng.range(1..=6))
+            .map(|_| {
+                rng.shuffle(&mut colors);
+                colors[..rng.range(1..=3) as usize]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .join(", ")
+            })
+            .join("; ");
+        lines.push_str(&format!("Game {number}: {draws}\n"));
+    }
+    lines
+}
+
+pub fn possible_games_sum(input: &str) -> Result<u32, String> {
+    let Day2 { red, green, blue } = Day2::default();
+    possible_games_sum_with(input, red, green, blue)
+}
+
+fn possible_games_sum_with(input: &str, red: u32, green: u32, blue: u32) -> Result<u32, String> {
+    sum_of_possible_game_ids(&parse_lines(input)?, red, green, blue)
+        .ok_or_else(|| "Sum of possible game IDs overflows".to_string())
+}
+
+pub fn minimum_powers_sum(input: &str) -> Result<u64, String> {
+    sum_of_minimum_powers(&parse_lines(input)?)
+        .ok_or_else(|| "Sum of minimum powers overflows".to_string())
+}
+
+pub fn sum_of_possible_game_ids(games: &[Game], red: u32, green: u32, blue: u32) -> Option<u32> {
     games
         .iter()
         .filter(|game| game.is_possible(red, green, blue))
         .map(|game| game.number)
-        .sum()
+        .checked_sum()
+}
+
+pub fn sum_of_minimum_powers(games: &[Game]) -> Option<u64> {
+    games
+        .iter()
+        .map(Game::minimum_power)
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .checked_sum()
 }
 
-fn sum_of_minimum_powers(games: &[Game]) -> u32 {
-    games.iter().map(Game::minimum_power).sum()
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MinimumBag {
+    pub game: u32,
+    pub bag: Draw,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Game {
+pub struct CubeStatistics {
+    pub minimum_bags: Vec<MinimumBag>,
+    pub max_red: u32,
+    pub max_green: u32,
+    pub max_blue: u32,
+    /// The number of games for each power of their minimum bag.
+    pub powers: BTreeMap<u64, usize>,
+}
+
+impl CubeStatistics {
+    fn explain(&self) -> Vec<String> {
+        let mut lines = self
+            .minimum_bags
+            .iter()
+            .map(|MinimumBag { game, bag }| {
+                let power = bag
+                    .power()
+                    .map_or_else(|| "overflowing".to_string(), |power| power.to_string());
+                format!(
+                    "Game {game} needs {} red, {} green and {} blue, power {power}",
+                    bag.red, bag.green, bag.blue,
+                )
+            })
+            .collect::<Vec<_>>();
+        lines.push(format!(
+            "Most cubes in a draw: {} red, {} green and {} blue",
+            self.max_red, self.max_green, self.max_blue,
+        ));
+        let powers = self
+            .powers
+            .iter()
+            .flat_map(|(power, count)| std::iter::repeat_n(*power, *count))
+            .collect::<Vec<_>>();
+        if let (Some(min), Some(max)) = (powers.first(), powers.last()) {
+            lines.push(format!(
+                "Powers: min {min}, median {}, max {max}",
+                powers[powers.len() / 2],
+            ));
+        }
+        lines
+    }
+}
+
+pub fn cube_statistics(games: &[Game]) -> CubeStatistics {
+    let minimum_bags = games
+        .iter()
+        .map(|game| MinimumBag {
+            game: game.number,
+            bag: game.minimum_bag(),
+        })
+        .collect::<Vec<_>>();
+    let mut powers = BTreeMap::new();
+    for power in minimum_bags
+        .iter()
+        .filter_map(|minimum| minimum.bag.power())
+    {
+        *powers.entry(power).or_default() += 1;
+    }
+    CubeStatistics {
+        max_red: minimum_bags
+            .iter()
+            .map(|minimum| minimum.bag.red)
+            .max()
+            .unwrap_or(0),
+        max_green: minimum_bags
+            .iter()
+            .map(|minimum| minimum.bag.green)
+            .max()
+            .unwrap_or(0),
+        max_blue: minimum_bags
+            .iter()
+            .map(|minimum| minimum.bag.blue)
+            .max()
+            .unwrap_or(0),
+        minimum_bags,
+        powers,
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game {
     number: u32,
     draws: Vec<Draw>,
 }
 
 impl Game {
-    fn is_possible(&self, red: u32, green: u32, blue: u32) -> bool {
-        red >= self.draws.iter().map(|draw| draw.red).max().unwrap_or(0)
-            && green >= self.draws.iter().map(|draw| draw.green).max().unwrap_or(0)
-            && blue >= self.draws.iter().map(|draw| draw.blue).max().unwrap_or(0)
+    pub fn number(&self) -> u32 {
+        self.number
     }
 
-    fn minimum_power(&self) -> u32 {
-        self.draws.iter().map(|draw| draw.red).max().unwrap_or(0)
-            * self.draws.iter().map(|draw| draw.green).max().unwrap_or(0)
-            * self.draws.iter().map(|draw| draw.blue).max().unwrap_or(0)
+    pub fn is_possible(&self, red: u32, green: u32, blue: u32) -> bool {
+        let bag = self.minimum_bag();
+        red >= bag.red && green >= bag.green && blue >= bag.blue
+    }
+
+    /// The fewest cubes of each color that make every draw of the game possible.
+    pub fn minimum_bag(&self) -> Draw {
+        let max = |color: fn(&Draw) -> u32| self.draws.iter().map(color).max().unwrap_or(0);
+        Draw {
+            red: max(|draw| draw.red),
+            green: max(|draw| draw.green),
+            blue: max(|draw| draw.blue),
+        }
+    }
+
+    pub fn minimum_power(&self) -> Option<u64> {
+        self.minimum_bag().power()
+    }
+
+    /// The first draw with more cubes of a color than the limit, as the index of the draw (from 0), the color, the
+    /// number of cubes seen and the limit. `None` if the game is possible.
+    pub fn first_violation(
+        &self,
+        red: u32,
+        green: u32,
+        blue: u32,
+    ) -> Option<(usize, &'static str, u32, u32)> {
+        self.draws.iter().enumerate().find_map(|(index, draw)| {
+            [
+                ("red", draw.red, red),
+                ("green", draw.green, green),
+                ("blue", draw.blue, blue),
+            ]
+            .into_iter()
+            .find(|(_, seen, limit)| seen > limit)
+            .map(|(color, seen, limit)| (index, color, seen, limit))
+        })
+    }
+
+    fn explain_possibility(&self, red: u32, green: u32, blue: u32) -> String {
+        match self.first_violation(red, green, blue) {
+            Some((index, color, seen, limit)) => format!(
+                "Game {} is impossible: draw {} has {seen} {color}, more than {limit}",
+                self.number,
+                index + 1,
+            ),
+            None => format!("Game {} is possible", self.number),
+        }
     }
 }
 
@@ -88,70 +340,127 @@ impl FromStr for Game {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Draw {
-    red: u32,
-    green: u32,
-    blue: u32,
+/// A game whose draws may have cubes of any color, not only red, green and blue.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColoredGame {
+    number: u32,
+    draws: Vec<HashMap<String, u32>>,
 }
 
-enum CubeColors {
-    Red,
-    Green,
-    Blue,
+impl ColoredGame {
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn draws(&self) -> &[HashMap<String, u32>] {
+        &self.draws
+    }
+
+    /// Whether every draw fits in a bag with the given number of cubes of each color. A color that is not in the limits
+    /// has no cube in the bag.
+    pub fn is_possible(&self, limits: &HashMap<String, u32>) -> bool {
+        self.minimum_bag()
+            .iter()
+            .all(|(color, n)| limits.get(color).is_some_and(|limit| limit >= n))
+    }
+
+    /// The fewest cubes of each color seen in the game that make every draw possible.
+    pub fn minimum_bag(&self) -> HashMap<String, u32> {
+        let mut bag = HashMap::<String, u32>::new();
+        for (color, n) in self.draws.iter().flatten() {
+            let max = bag.entry(color.clone()).or_default();
+            *max = (*max).max(*n);
+        }
+        bag
+    }
 }
 
-impl FromStr for CubeColors {
+impl FromStr for ColoredGame {
     type Err = String;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "red" => Ok(Self::Red),
-            "green" => Ok(Self::Green),
-            "blue" => Ok(Self::Blue),
-            _ => Err(format!("Unknown color: {s}")),
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Ok(("", game)) = parse_colored_game(line) {
+            Ok(game)
+        } else {
+            Err(format!("Invalid game: '{line}'"))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Draw {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Draw {
+    pub fn power(&self) -> Option<u64> {
+        [self.red, self.green, self.blue]
+            .into_iter()
+            .map(u64::from)
+            .checked_product()
+    }
+
+    fn from_colors(colors: &HashMap<String, u32>) -> Option<Self> {
+        let mut draw = Self {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+        for (color, n) in colors {
+            match color.as_str() {
+                "red" => draw.red = *n,
+                "green" => draw.green = *n,
+                "blue" => draw.blue = *n,
+                _ => return None,
+            }
         }
+        Some(draw)
     }
 }
 
 fn parse_game(input: &str) -> IResult<&str, Game> {
     tuple((
         tag("Game "),
-        digit1,
+        map_res(digit1, str::parse),
         tag(": "),
         separated_list0(tag("; "), parse_draw),
     ))(input)
-    .map(|(input, (_, number, _, draws))| {
-        (
-            input,
-            Game {
-                number: number.parse().unwrap(),
-                draws,
-            },
-        )
-    })
+    .map(|(input, (_, number, _, draws))| (input, Game { number, draws }))
 }
 
 fn parse_draw(input: &str) -> IResult<&str, Draw> {
-    separated_list0(tag(", "), parse_cube_draw)(input).map(|(input, colors)| {
-        let mut red = 0;
-        let mut green = 0;
-        let mut blue = 0;
-        for (n, color) in colors {
-            match color {
-                CubeColors::Red => red += n,
-                CubeColors::Green => green += n,
-                CubeColors::Blue => blue += n,
-            }
-        }
-        (input, Draw { red, green, blue })
-    })
+    map_opt(parse_colored_draw, |colors| Draw::from_colors(&colors))(input)
 }
 
-fn parse_cube_draw(input: &str) -> IResult<&str, (u32, CubeColors)> {
-    tuple((digit1, tag(" "), alpha1))(input).map(|(input, (number, _, color))| {
-        (input, (number.parse().unwrap(), color.parse().unwrap()))
-    })
+fn parse_colored_game(input: &str) -> IResult<&str, ColoredGame> {
+    tuple((
+        tag("Game "),
+        map_res(digit1, str::parse),
+        tag(": "),
+        separated_list0(tag("; "), parse_colored_draw),
+    ))(input)
+    .map(|(input, (_, number, _, draws))| (input, ColoredGame { number, draws }))
+}
+
+fn parse_colored_draw(input: &str) -> IResult<&str, HashMap<String, u32>> {
+    map_opt(separated_list0(tag(", "), parse_cube_draw), |cubes| {
+        cubes
+            .into_iter()
+            .try_fold(HashMap::new(), |mut draw, (n, color)| {
+                let count = draw.entry(color.to_string()).or_insert(0u32);
+                *count = count.checked_add(n)?;
+                Some(draw)
+            })
+    })(input)
+}
+
+fn parse_cube_draw(input: &str) -> IResult<&str, (u32, &str)> {
+    tuple((map_res(digit1, str::parse), tag(" "), alpha1))(input)
+        .map(|(input, (number, _, color))| (input, (number, color)))
 }
 
 #[cfg(test)]
@@ -159,16 +468,7 @@ mod test {
     use super::*;
 
     fn example() -> Vec<Game> {
-        read_lines(
-            b"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
-                .as_slice(),
-        )
-        .parse()
-        .collect()
+        parse_lines(EXAMPLE).unwrap()
     }
 
     #[test]
@@ -215,9 +515,91 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
         assert!(!game3.is_possible(12, 13, 14));
     }
 
+    #[test]
+    fn first_violation_should_report_the_first_draw_and_color_over_the_limits() {
+        let games = example();
+
+        assert_eq!(games[0].first_violation(12, 13, 14), None);
+        assert_eq!(
+            games[2].first_violation(12, 13, 14),
+            Some((0, "red", 20, 12))
+        );
+        assert_eq!(
+            games[3].first_violation(14, 13, 14),
+            Some((2, "blue", 15, 14))
+        );
+    }
+
     #[test]
     fn part1_example() {
-        assert_eq!(sum_of_possible_game_ids(&example(), 12, 13, 14), 8);
+        assert_eq!(sum_of_possible_game_ids(&example(), 12, 13, 14), Some(8));
+    }
+
+    #[test]
+    fn parse_game_with_unknown_color_should_fail() {
+        assert!("Game 1: 3 yellow".parse::<Game>().is_err());
+    }
+
+    #[test]
+    fn parse_game_with_overflowing_number_should_fail() {
+        assert!("Game 4294967296: 3 red".parse::<Game>().is_err());
+        assert!("Game 1: 4294967296 red".parse::<Game>().is_err());
+    }
+
+    #[test]
+    fn parse_game_with_overflowing_draw_should_fail() {
+        assert!("Game 1: 4294967295 red, 1 red".parse::<Game>().is_err());
+    }
+
+    #[test]
+    fn parse_colored_game_should_accept_any_color() {
+        let game = "Game 7: 3 yellow, 1 red; 2 yellow, 2 yellow"
+            .parse::<ColoredGame>()
+            .unwrap();
+
+        assert_eq!(game.number(), 7);
+        assert_eq!(
+            game.draws(),
+            [
+                HashMap::from([("yellow".to_string(), 3), ("red".to_string(), 1)]),
+                HashMap::from([("yellow".to_string(), 4)]),
+            ],
+        );
+        assert_eq!(
+            game.minimum_bag(),
+            HashMap::from([("yellow".to_string(), 4), ("red".to_string(), 1)]),
+        );
+    }
+
+    #[test]
+    fn colored_game_should_be_impossible_with_a_color_missing_from_the_limits() {
+        let game = "Game 1: 3 yellow, 1 red".parse::<ColoredGame>().unwrap();
+        let limits = |yellow: Option<u32>| {
+            HashMap::from([
+                ("red".to_string(), 12),
+                ("green".to_string(), 13),
+                ("blue".to_string(), 14),
+            ])
+            .into_iter()
+            .chain(yellow.map(|n| ("yellow".to_string(), n)))
+            .collect::<HashMap<_, _>>()
+        };
+
+        assert!(game.is_possible(&limits(Some(3))));
+        assert!(!game.is_possible(&limits(Some(2))));
+        assert!(!game.is_possible(&limits(None)));
+    }
+
+    #[test]
+    fn minimum_bag_should_take_the_largest_draw_of_each_color() {
+        assert_eq!(
+            example()[3].minimum_bag(),
+            Draw {
+                red: 14,
+                green: 3,
+                blue: 15,
+            },
+        );
     }
 
     #[test]
@@ -225,11 +607,125 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
         let game1: Game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
             .parse()
             .unwrap();
-        assert_eq!(game1.minimum_power(), 48);
+        assert_eq!(game1.minimum_power(), Some(48));
+    }
+
+    #[test]
+    fn minimum_power_should_not_overflow_for_large_draws() {
+        let game: Game = "Game 1: 100000 red, 100000 green, 100000 blue"
+            .parse()
+            .unwrap();
+        assert_eq!(game.minimum_power(), Some(1_000_000_000_000_000));
+    }
+
+    #[test]
+    fn minimum_power_should_return_none_on_overflow() {
+        let game: Game = "Game 1: 4294967295 red, 4294967295 green, 4294967295 blue"
+            .parse()
+            .unwrap();
+        assert_eq!(game.minimum_power(), None);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(sum_of_minimum_powers(&example()), 2286);
+        assert_eq!(sum_of_minimum_powers(&example()), Some(2286));
+    }
+
+    #[test]
+    fn part_one_should_use_the_configured_limits() {
+        let day = Day2 {
+            red: 20,
+            ..Day2::default()
+        };
+
+        assert_eq!(
+            day.part_one(
+                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+            ),
+            Ok("Sum of IDs of possible games for 20 reds, 13 greens, and 14 blues: 4".to_string()),
+        );
+    }
+
+    #[test]
+    fn answers_should_only_be_known_for_the_limits_of_the_puzzle() {
+        let day = Day2 {
+            red: 20,
+            ..Day2::default()
+        };
+
+        assert_eq!(Day2::default().known_answers(), ["2541", "66016"]);
+        assert!(day.known_answers().is_empty());
+    }
+
+    #[test]
+    fn explain_part_one_should_report_the_first_draw_over_the_limits() {
+        let explanation = Day2::default().explain(
+            1,
+            "Game 1: 3 blue, 4 red
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green",
+        );
+
+        assert_eq!(
+            explanation,
+            vec![
+                "Game 1 is possible",
+                "Game 3 is impossible: draw 1 has 20 red, more than 12",
+            ],
+        );
+    }
+
+    #[test]
+    fn explain_part_two_should_report_the_minimum_bag() {
+        assert_eq!(
+            Day2::default().explain(2, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            vec![
+                "Game 1 needs 4 red, 2 green and 6 blue, power 48",
+                "Most cubes in a draw: 4 red, 2 green and 6 blue",
+                "Powers: min 48, median 48, max 48",
+            ],
+        );
+    }
+
+    #[test]
+    fn cube_statistics_of_example() {
+        let statistics = cube_statistics(&example());
+
+        assert_eq!(
+            statistics.minimum_bags[2],
+            MinimumBag {
+                game: 3,
+                bag: Draw {
+                    red: 20,
+                    green: 13,
+                    blue: 6,
+                },
+            },
+        );
+        assert_eq!(
+            (
+                statistics.max_red,
+                statistics.max_green,
+                statistics.max_blue
+            ),
+            (20, 13, 15),
+        );
+        assert_eq!(
+            statistics.powers,
+            BTreeMap::from([(12, 1), (36, 1), (48, 1), (630, 1), (1560, 1)]),
+        );
+    }
+
+    #[test]
+    fn generated_games_should_be_valid() {
+        let input = random_games(100, &mut Rng::new(2));
+
+        assert_eq!(
+            parse_lines::<Game>(&input).map(|games| games.len()),
+            Ok(100)
+        );
+        assert!(possible_games_sum(&input).is_ok_and(|sum| sum > 0));
+        assert!(minimum_powers_sum(&input).is_ok_and(|sum| sum > 0));
     }
 }
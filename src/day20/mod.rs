@@ -0,0 +1,494 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_utils::divisors::lcm;
+
+use crate::{Example, Label, Solution};
+
+mod input;
+
+pub struct Day20;
+
+const EXAMPLE1: &str = "
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+const EXAMPLE2: &str = "
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+/// The number of button presses of part one.
+const PRESSES: usize = 1000;
+
+/// The number of button presses after which a conjunction feeding the final one is considered to never send a high
+/// pulse.
+const MAX_CYCLE: u64 = 1 << 20;
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "pulses-product",
+        text: "Product of low and high pulses",
+    },
+    Label {
+        key: "machine-start-presses",
+        text: "Button presses to start the machine",
+    },
+];
+
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Pulse Propagation")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let product = pulses_product(input)?;
+        Ok(ANSWER_LABELS[0].answer(product))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let presses = presses_to_start_machine(input)?;
+        Ok(ANSWER_LABELS[1].answer(presses))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_configuration(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE1,
+                answer: "Product of low and high pulses: 32000000",
+            },
+            Example {
+                part: 1,
+                input: EXAMPLE2,
+                answer: "Product of low and high pulses: 11687500",
+            },
+        ]
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        let configuration = match parse_configuration(input) {
+            Ok(configuration) => configuration,
+            Err(error) => return vec![error],
+        };
+        match part {
+            2 => match configuration.cycles("rx") {
+                Ok(cycles) => cycles
+                    .iter()
+                    .map(|(name, presses)| {
+                        format!("{name} sends a high pulse every {presses} presses")
+                    })
+                    .collect(),
+                Err(error) => vec![error],
+            },
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The product of the numbers of low and high pulses sent during the first thousand button presses.
+pub fn pulses_product(input: &str) -> Result<u64, String> {
+    let configuration = parse_configuration(input)?;
+    let mut machine = Machine::new(&configuration);
+    let (mut low, mut high) = (0u64, 0u64);
+    for _ in 0..PRESSES {
+        machine.push_button(|pulse| match pulse.high {
+            true => high += 1,
+            false => low += 1,
+        });
+    }
+    low.checked_mul(high)
+        .ok_or_else(|| "Product of pulses overflows".to_string())
+}
+
+/// The number of button presses until a single low pulse reaches `rx`, which is fed by a conjunction whose inputs each
+/// send a high pulse at a regular interval: the answer is the least common multiple of the intervals.
+pub fn presses_to_start_machine(input: &str) -> Result<u64, String> {
+    parse_configuration(input)?
+        .cycles("rx")?
+        .into_iter()
+        .try_fold(1, |presses, (_, cycle)| lcm(presses, cycle))
+        .ok_or_else(|| "Button presses overflow".to_string())
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// A module that is only a destination, such as `output` or `rx`.
+    Untyped,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Module {
+    pub kind: Kind,
+    /// The destination modules, each with the index of this module among the inputs of the destination.
+    pub destinations: Vec<(usize, usize)>,
+    pub inputs: Vec<usize>,
+}
+
+/// The modules and their connections, the modules being identified by their index in `names`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Configuration {
+    pub names: Vec<String>,
+    pub modules: Vec<Module>,
+    broadcaster: usize,
+}
+
+impl Configuration {
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|module| module == name)
+    }
+
+    /// The number of button presses after which each input of the conjunction feeding the given module first sends
+    /// it a high pulse, with the name of the input.
+    pub fn cycles(&self, target: &str) -> Result<Vec<(String, u64)>, String> {
+        let target = self
+            .index(target)
+            .ok_or_else(|| format!("No module named {target}"))?;
+        let feeder = match self.modules[target].inputs[..] {
+            [feeder] if self.modules[feeder].kind == Kind::Conjunction => feeder,
+            [feeder] => {
+                return Err(format!(
+                    "{} is fed by {}, which is not a conjunction",
+                    self.names[target], self.names[feeder]
+                ))
+            }
+            _ => {
+                return Err(format!(
+                    "{} is not fed by a single conjunction",
+                    self.names[target]
+                ))
+            }
+        };
+        let inputs = &self.modules[feeder].inputs;
+        let mut cycles = vec![None; inputs.len()];
+        let mut machine = Machine::new(self);
+        for presses in 1..=MAX_CYCLE {
+            machine.push_button(|pulse| {
+                if pulse.high && pulse.to == feeder {
+                    if let Some(cycle) = inputs
+                        .iter()
+                        .position(|input| *input == pulse.from)
+                        .and_then(|index| cycles.get_mut(index))
+                    {
+                        cycle.get_or_insert(presses);
+                    }
+                }
+            });
+            if cycles.iter().all(Option::is_some) {
+                break;
+            }
+        }
+        inputs
+            .iter()
+            .zip(cycles)
+            .map(|(input, cycle)| {
+                let name = self.names[*input].clone();
+                match cycle {
+                    Some(cycle) => Ok((name, cycle)),
+                    None => Err(format!("{name} sends no high pulse in {MAX_CYCLE} presses")),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parses the modules, one per line. Errors give the line number.
+#[tracing::instrument(skip_all)]
+pub fn parse_configuration(input: &str) -> Result<Configuration, String> {
+    let mut names = Vec::<String>::new();
+    let mut indices = HashMap::<String, usize>::new();
+    let mut index = |name: &str, names: &mut Vec<String>| {
+        *indices.entry(name.to_string()).or_insert_with(|| {
+            names.push(name.to_string());
+            names.len() - 1
+        })
+    };
+    let mut declared = Vec::new();
+    for (number, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (module, destinations) = line
+            .split_once(" -> ")
+            .ok_or_else(|| format!("Line {}: Missing destinations", number + 1))?;
+        let module = module.trim();
+        let (kind, name) = match module.as_bytes().first() {
+            Some(b'%') => (Kind::FlipFlop, &module[1..]),
+            Some(b'&') => (Kind::Conjunction, &module[1..]),
+            _ if module == "broadcaster" => (Kind::Broadcaster, module),
+            _ => return Err(format!("Line {}: Invalid module: {module}", number + 1)),
+        };
+        if name.is_empty() {
+            return Err(format!("Line {}: Missing module name", number + 1));
+        }
+        let module = index(name, &mut names);
+        if declared.iter().any(|(declared, _, _)| *declared == module) {
+            return Err(format!("Line {}: Duplicate module {name}", number + 1));
+        }
+        let destinations = destinations
+            .split(',')
+            .map(|destination| index(destination.trim(), &mut names))
+            .collect::<Vec<_>>();
+        declared.push((module, kind, destinations));
+    }
+    let mut modules = vec![
+        Module {
+            kind: Kind::Untyped,
+            destinations: Vec::new(),
+            inputs: Vec::new(),
+        };
+        names.len()
+    ];
+    for (module, kind, destinations) in declared {
+        modules[module].kind = kind;
+        for destination in destinations {
+            let slot = modules[destination].inputs.len();
+            modules[destination].inputs.push(module);
+            modules[module].destinations.push((destination, slot));
+        }
+    }
+    let broadcaster = indices
+        .get("broadcaster")
+        .copied()
+        .filter(|broadcaster| modules[*broadcaster].kind == Kind::Broadcaster)
+        .ok_or_else(|| "No broadcaster".to_string())?;
+    Ok(Configuration {
+        names,
+        modules,
+        broadcaster,
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    /// The index of `from` among the inputs of `to`.
+    slot: usize,
+    pub high: bool,
+}
+
+/// The state of the modules: whether each flip-flop is on, and the last pulse each conjunction received from each of
+/// its inputs.
+pub struct Machine<'a> {
+    configuration: &'a Configuration,
+    on: Vec<bool>,
+    memory: Vec<Vec<bool>>,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(configuration: &'a Configuration) -> Self {
+        Self {
+            configuration,
+            on: vec![false; configuration.modules.len()],
+            memory: configuration
+                .modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+        }
+    }
+
+    /// Sends a low pulse to the broadcaster, and processes the pulses in the order they are sent, including the one of
+    /// the button, until there are no more.
+    pub fn push_button<F: FnMut(&Pulse)>(&mut self, mut observe: F) {
+        let broadcaster = self.configuration.broadcaster;
+        let mut pulses = VecDeque::from([Pulse {
+            from: broadcaster,
+            to: broadcaster,
+            slot: 0,
+            high: false,
+        }]);
+        while let Some(pulse) = pulses.pop_front() {
+            observe(&pulse);
+            let module = &self.configuration.modules[pulse.to];
+            let high = match module.kind {
+                Kind::Broadcaster => pulse.high,
+                Kind::FlipFlop if pulse.high => continue,
+                Kind::FlipFlop => {
+                    self.on[pulse.to] = !self.on[pulse.to];
+                    self.on[pulse.to]
+                }
+                Kind::Conjunction => {
+                    let memory = &mut self.memory[pulse.to];
+                    memory[pulse.slot] = pulse.high;
+                    !memory.iter().all(|high| *high)
+                }
+                Kind::Untyped => continue,
+            };
+            pulses.extend(module.destinations.iter().map(|(to, slot)| Pulse {
+                from: pulse.to,
+                to: *to,
+                slot: *slot,
+                high,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Two counters that reset when they reach 3 and 7, each followed by an inverter feeding `hub`.
+    const COUNTERS: &str = "
+broadcaster -> a1, b1
+%a1 -> a2, ca
+%a2 -> ca
+&ca -> a1, na
+&na -> hub
+%b1 -> b2, cb
+%b2 -> b3, cb
+%b3 -> cb
+&cb -> b1, nb
+&nb -> hub
+&hub -> rx
+";
+
+    #[test]
+    fn parse_configuration_should_connect_the_modules() {
+        let configuration = parse_configuration(EXAMPLE2).unwrap();
+        let con = configuration.index("con").unwrap();
+        let output = configuration.index("output").unwrap();
+
+        assert_eq!(configuration.names.len(), 6);
+        assert_eq!(configuration.modules[con].kind, Kind::Conjunction);
+        assert_eq!(
+            configuration.modules[con].inputs,
+            vec![
+                configuration.index("a").unwrap(),
+                configuration.index("b").unwrap()
+            ]
+        );
+        assert_eq!(configuration.modules[output].kind, Kind::Untyped);
+    }
+
+    #[test]
+    fn explain_should_give_the_error_of_an_invalid_configuration() {
+        assert_eq!(
+            Day20.explain(2, "broadcaster -> a\n%a"),
+            vec!["Line 2: Missing destinations"],
+        );
+    }
+
+    #[test]
+    fn parse_configuration_should_reject_invalid_configurations() {
+        assert_eq!(
+            parse_configuration("broadcaster -> a\n%a"),
+            Err("Line 2: Missing destinations".to_string())
+        );
+        assert_eq!(
+            parse_configuration("broadcaster -> a\n%a -> b\n&a -> b"),
+            Err("Line 3: Duplicate module a".to_string())
+        );
+        assert_eq!(
+            parse_configuration("%a -> b"),
+            Err("No broadcaster".to_string())
+        );
+    }
+
+    #[test]
+    fn push_button_should_send_the_pulses_of_example1_in_order() {
+        let configuration = parse_configuration(EXAMPLE1).unwrap();
+        let mut pulses = Vec::new();
+
+        Machine::new(&configuration).push_button(|pulse| {
+            pulses.push(format!(
+                "{} -{}-> {}",
+                configuration.names[pulse.from],
+                if pulse.high { "high" } else { "low" },
+                configuration.names[pulse.to]
+            ))
+        });
+
+        assert_eq!(
+            pulses,
+            vec![
+                "broadcaster -low-> broadcaster",
+                "broadcaster -low-> a",
+                "broadcaster -low-> b",
+                "broadcaster -low-> c",
+                "a -high-> b",
+                "b -high-> c",
+                "c -high-> inv",
+                "inv -low-> a",
+                "a -low-> b",
+                "b -low-> c",
+                "c -low-> inv",
+                "inv -high-> a",
+            ]
+        );
+    }
+
+    #[test]
+    fn pulses_product_should_return_32000000_for_example1() {
+        assert_eq!(pulses_product(EXAMPLE1), Ok(32000000));
+    }
+
+    #[test]
+    fn pulses_product_should_return_11687500_for_example2() {
+        assert_eq!(pulses_product(EXAMPLE2), Ok(11687500));
+    }
+
+    #[test]
+    fn cycles_should_find_the_period_of_each_counter() {
+        let configuration = parse_configuration(COUNTERS).unwrap();
+
+        assert_eq!(
+            configuration.cycles("rx"),
+            Ok(vec![("na".to_string(), 3), ("nb".to_string(), 7)])
+        );
+    }
+
+    #[test]
+    fn presses_to_start_machine_should_match_the_simulation() {
+        let configuration = parse_configuration(COUNTERS).unwrap();
+        let rx = configuration.index("rx").unwrap();
+        let mut machine = Machine::new(&configuration);
+        let presses = (1..100).find(|_| {
+            let mut started = false;
+            machine.push_button(|pulse| started |= pulse.to == rx && !pulse.high);
+            started
+        });
+
+        assert_eq!(presses, Some(21));
+        assert_eq!(presses_to_start_machine(COUNTERS), Ok(21));
+    }
+
+    #[test]
+    fn presses_to_start_machine_should_require_a_conjunction_before_rx() {
+        assert_eq!(
+            presses_to_start_machine("broadcaster -> a\n%a -> rx"),
+            Err("rx is fed by a, which is not a conjunction".to_string())
+        );
+        assert_eq!(
+            presses_to_start_machine(EXAMPLE1),
+            Err("No module named rx".to_string())
+        );
+    }
+}
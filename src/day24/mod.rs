@@ -0,0 +1,504 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_utils::divisors::gcd;
+use aoc_utils::input::non_empty_lines;
+
+use crate::{Example, Label, Solution};
+
+mod input;
+
+pub struct Day24;
+
+const EXAMPLE: &str = "
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+/// The example moved into the test area of the puzzle, each position `p` becoming `10^13 p + 1.3 10^14`. The paths
+/// keep their shape, so that the two crossings inside the area from 7 to 27 of the puzzle statement are the two inside
+/// the test area.
+const EXAMPLE_IN_TEST_AREA: &str = "
+320000000000000, 260000000000000, 430000000000000 @ -2,  1, -2
+310000000000000, 320000000000000, 350000000000000 @ -1, -1, -2
+330000000000000, 380000000000000, 470000000000000 @ -2, -2, -4
+250000000000000, 440000000000000, 410000000000000 @ -1, -2, -1
+330000000000000, 320000000000000, 280000000000000 @  1, -5, -3
+";
+
+/// The area in which the crossings of part one are counted, on both axes.
+pub const TEST_AREA: RangeInclusive<i64> = 200_000_000_000_000..=400_000_000_000_000;
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "test-area-crossings",
+        text: "Crossings inside the test area",
+    },
+    Label {
+        key: "initial-position-sum",
+        text: "Sum of the initial position coordinates",
+    },
+];
+
+impl Solution for Day24 {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Never Tell Me The Odds")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let crossings = crossings_in_test_area(input, TEST_AREA)?;
+        Ok(ANSWER_LABELS[0].answer(crossings))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let sum = throw_coordinates_sum(input)?;
+        Ok(ANSWER_LABELS[1].answer(sum))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_hailstones(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE_IN_TEST_AREA,
+                answer: "Crossings inside the test area: 2",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Sum of the initial position coordinates: 47",
+            },
+        ]
+    }
+}
+
+/// Parses the hailstones, one per line. Errors give the line number.
+#[tracing::instrument(skip_all)]
+pub fn parse_hailstones(input: &str) -> Result<Vec<Hailstone>, String> {
+    non_empty_lines(input)
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error| format!("Hailstone {}: {error}", index + 1))
+        })
+        .collect()
+}
+
+/// The number of pairs of hailstones whose paths, ignoring the Z axis, cross in the future inside the area.
+pub fn crossings_in_test_area(input: &str, area: RangeInclusive<i64>) -> Result<usize, String> {
+    let hailstones = parse_hailstones(input)?;
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(index, a)| hailstones[index + 1..].iter().map(move |b| (a, b)))
+        .try_fold(0, |crossings, (a, b)| {
+            Ok(crossings + usize::from(a.crosses_inside(b, &area)?))
+        })
+}
+
+/// The sum of the coordinates of the position the rock must be thrown from to hit every hailstone.
+pub fn throw_coordinates_sum(input: &str) -> Result<i128, String> {
+    let throw = find_throw(&parse_hailstones(input)?)?;
+    Ok(throw.position.iter().sum())
+}
+
+type Vector = [i128; 3];
+
+fn sub(a: Vector, b: Vector) -> Option<Vector> {
+    Some([
+        a[0].checked_sub(b[0])?,
+        a[1].checked_sub(b[1])?,
+        a[2].checked_sub(b[2])?,
+    ])
+}
+
+fn cross(a: Vector, b: Vector) -> Option<Vector> {
+    let component =
+        |i: usize, j: usize| a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?);
+    Some([component(1, 2)?, component(2, 0)?, component(0, 1)?])
+}
+
+fn scaled(a: Vector, factor: i128) -> Option<Vector> {
+    Some([
+        a[0].checked_mul(factor)?,
+        a[1].checked_mul(factor)?,
+        a[2].checked_mul(factor)?,
+    ])
+}
+
+/// Divides the vector by the GCD of its components, keeping its direction.
+fn reduced(a: Vector) -> Vector {
+    let divisor = a
+        .iter()
+        .fold(0, |divisor, component| gcd(divisor, *component));
+    match divisor {
+        0 => a,
+        _ => a.map(|component| component / divisor),
+    }
+}
+
+/// The exact quotient of the vectors, if the first one is an integer multiple of the second one.
+fn quotient(a: Vector, b: Vector) -> Option<i128> {
+    let index = b.iter().position(|component| *component != 0)?;
+    let factor = a[index].checked_div(b[index])?;
+    (scaled(b, factor)? == a).then_some(factor)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Hailstone {
+    pub position: [i64; 3],
+    pub velocity: [i64; 3],
+}
+
+impl Hailstone {
+    fn position(&self) -> Vector {
+        self.position.map(i128::from)
+    }
+
+    fn velocity(&self) -> Vector {
+        self.velocity.map(i128::from)
+    }
+
+    /// Whether the paths of both hailstones on the XY plane cross inside the area, at times that are not in the past
+    /// for either of them. The intersection is computed with integer fractions, so it is exact, and fails if they
+    /// overflow.
+    pub fn crosses_inside(
+        &self,
+        other: &Hailstone,
+        area: &RangeInclusive<i64>,
+    ) -> Result<bool, String> {
+        self.checked_crosses_inside(other, area)
+            .ok_or_else(|| "Crossing of hailstones overflows".to_string())
+    }
+
+    fn checked_crosses_inside(
+        &self,
+        other: &Hailstone,
+        area: &RangeInclusive<i64>,
+    ) -> Option<bool> {
+        let [px, py, _] = self.position();
+        let [vx, vy, _] = self.velocity();
+        let [qx, qy, _] = other.position();
+        let [wx, wy, _] = other.velocity();
+        let cross =
+            |a: i128, b: i128, c: i128, d: i128| a.checked_mul(b)?.checked_sub(c.checked_mul(d)?);
+        let denominator = cross(vx, wy, vy, wx)?;
+        if denominator == 0 {
+            return Some(false);
+        }
+        let (dx, dy) = (qx.checked_sub(px)?, qy.checked_sub(py)?);
+        let t = cross(dx, wy, dy, wx)?;
+        let s = cross(dx, vy, dy, vx)?;
+        // Multiplying by the sign of the denominator keeps the comparisons in the right direction.
+        let sign = denominator.signum();
+        if t.signum() * sign < 0 || s.signum() * sign < 0 {
+            return Some(false);
+        }
+        let (min, max) = (i128::from(*area.start()), i128::from(*area.end()));
+        let denominator = denominator.abs();
+        for (p, v) in [(px, vx), (py, vy)] {
+            let numerator = p
+                .checked_mul(denominator)?
+                .checked_add(t.checked_mul(v)?.checked_mul(sign)?)?;
+            if numerator < min.checked_mul(denominator)?
+                || numerator > max.checked_mul(denominator)?
+            {
+                return Some(false);
+            }
+        }
+        Some(true)
+    }
+}
+
+impl FromStr for Hailstone {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = line
+            .split_once('@')
+            .ok_or_else(|| "Missing velocity".to_string())?;
+        let vector = |text: &str| -> Result<[i64; 3], String> {
+            let components = text
+                .split(',')
+                .map(|component| {
+                    component
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("Invalid number: {}", component.trim()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            components.try_into().map_err(|components: Vec<_>| {
+                format!("{} coordinates instead of 3", components.len())
+            })
+        };
+        Ok(Self {
+            position: vector(position)?,
+            velocity: vector(velocity)?,
+        })
+    }
+}
+
+/// The position and velocity of the rock.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Throw {
+    pub position: Vector,
+    pub velocity: Vector,
+}
+
+impl Throw {
+    /// Whether the rock and the hailstone are at the same position at some time that is not in the past.
+    pub fn hits(&self, hailstone: &Hailstone) -> bool {
+        let (Some(offset), Some(closing)) = (
+            sub(hailstone.position(), self.position),
+            sub(self.velocity, hailstone.velocity()),
+        ) else {
+            return false;
+        };
+        match quotient(offset, closing) {
+            Some(time) => time >= 0,
+            None => offset == [0; 3],
+        }
+    }
+}
+
+/// The throw that hits every hailstone, with only integer arithmetic.
+///
+/// Seen from the first hailstone, which is then still at the origin, the path of the rock goes through the origin and
+/// crosses the path of every other hailstone, so it lies on the plane containing the origin and that path. Two such
+/// planes give the direction of the rock, and the times it meets their hailstones then give its velocity and initial
+/// position.
+pub fn find_throw(hailstones: &[Hailstone]) -> Result<Throw, String> {
+    let (first, others) = hailstones
+        .split_first()
+        .ok_or_else(|| "No hailstones".to_string())?;
+    let overflow = || "The coordinates overflow".to_string();
+    let relative = others
+        .iter()
+        .map(|hailstone| {
+            Some((
+                sub(hailstone.position(), first.position())?,
+                sub(hailstone.velocity(), first.velocity())?,
+            ))
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(overflow)?;
+    let throw = relative
+        .iter()
+        .enumerate()
+        .flat_map(|(index, a)| relative[index + 1..].iter().map(move |b| (a, b)))
+        .find_map(|(&(p1, v1), &(p2, v2))| {
+            let direction = reduced(cross(cross(p1, v1)?, cross(p2, v2)?)?);
+            if direction == [0; 3] {
+                return None;
+            }
+            // The hailstone meets the path of the rock when its position is collinear with the direction.
+            let meeting = |p: Vector, v: Vector| {
+                let time = quotient(scaled(cross(p, direction)?, -1)?, cross(v, direction)?)?;
+                Some((time, sub(p, scaled(v, -time)?)?))
+            };
+            let (t1, c1) = meeting(p1, v1)?;
+            let (t2, c2) = meeting(p2, v2)?;
+            if t1 == t2 {
+                return None;
+            }
+            let velocity = sub(c2, c1)?.map(|component| component / (t2 - t1));
+            let position = sub(c1, scaled(velocity, t1)?)?;
+            Some(Throw {
+                position: sub(position, scaled(first.position(), -1)?)?,
+                velocity: sub(velocity, scaled(first.velocity(), -1)?)?,
+            })
+        })
+        .ok_or_else(|| "The hailstones do not determine a single throw".to_string())?;
+    match hailstones
+        .iter()
+        .position(|hailstone| !throw.hits(hailstone))
+    {
+        Some(index) => Err(format!("The throw misses hailstone {}", index + 1)),
+        None => Ok(throw),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Hailstone> {
+        parse_hailstones(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn parse_hailstones_should_parse_example() {
+        assert_eq!(
+            example()[4],
+            Hailstone {
+                position: [20, 19, 15],
+                velocity: [1, -5, -3],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_hailstones_should_reject_invalid_hailstones() {
+        assert_eq!(
+            parse_hailstones("1, 2, 3 @ 1, 1, 1\n1, 2 @ 1, 1, 1"),
+            Err("Hailstone 2: 2 coordinates instead of 3".to_string())
+        );
+        assert_eq!(
+            parse_hailstones("1, 2, 3"),
+            Err("Hailstone 1: Missing velocity".to_string())
+        );
+        assert_eq!(
+            parse_hailstones("1, 2, x @ 1, 1, 1"),
+            Err("Hailstone 1: Invalid number: x".to_string())
+        );
+    }
+
+    #[test]
+    fn crosses_inside_should_follow_the_pairs_of_example() {
+        let hailstones = example();
+        let area = 7..=27;
+
+        assert_eq!(
+            hailstones[0].crosses_inside(&hailstones[1], &area),
+            Ok(true)
+        );
+        assert_eq!(
+            hailstones[0].crosses_inside(&hailstones[2], &area),
+            Ok(true)
+        );
+        // Outside of the area.
+        assert_eq!(
+            hailstones[0].crosses_inside(&hailstones[3], &area),
+            Ok(false)
+        );
+        // In the past for the second hailstone.
+        assert_eq!(
+            hailstones[0].crosses_inside(&hailstones[4], &area),
+            Ok(false)
+        );
+        // Parallel.
+        assert_eq!(
+            hailstones[1].crosses_inside(&hailstones[2], &area),
+            Ok(false)
+        );
+        // In the past for both hailstones.
+        assert_eq!(
+            hailstones[3].crosses_inside(&hailstones[4], &area),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn crossings_in_test_area_should_fail_on_overflow() {
+        let input = "
+0, 0, 0 @ -9223372036854775808, -9223372036854775808, 0
+0, -9223372036854775808, 0 @ -9223372036854775808, -1, 0
+";
+
+        assert_eq!(
+            crossings_in_test_area(input, TEST_AREA),
+            Err("Crossing of hailstones overflows".to_string())
+        );
+    }
+
+    #[test]
+    fn crossings_in_test_area_should_return_2_for_example() {
+        assert_eq!(crossings_in_test_area(EXAMPLE, 7..=27), Ok(2));
+    }
+
+    #[test]
+    fn example_in_test_area_should_have_the_crossings_of_example() {
+        assert_eq!(
+            crossings_in_test_area(EXAMPLE_IN_TEST_AREA, TEST_AREA),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn crossings_in_test_area_should_not_overflow_with_puzzle_coordinates() {
+        let input = "
+200000000000000, 400000000000000, 0 @ 999, -999, 0
+400000000000000, 400000000000000, 0 @ -998, -999, 0
+";
+
+        assert_eq!(crossings_in_test_area(input, TEST_AREA), Ok(1));
+    }
+
+    #[test]
+    fn find_throw_should_return_the_throw_of_example() {
+        assert_eq!(
+            find_throw(&example()),
+            Ok(Throw {
+                position: [24, 13, 10],
+                velocity: [-3, 1, 2],
+            })
+        );
+    }
+
+    #[test]
+    fn throw_coordinates_sum_should_return_47_for_example() {
+        assert_eq!(throw_coordinates_sum(EXAMPLE), Ok(47));
+    }
+
+    #[test]
+    fn find_throw_should_handle_puzzle_coordinates() {
+        let throw = Throw {
+            position: [
+                123_456_789_012_345,
+                234_567_890_123_456,
+                345_678_901_234_567,
+            ],
+            velocity: [-123, 45, 67],
+        };
+        let hailstones = [
+            (5, [12, -34, 5]),
+            (17, [-8, 21, 3]),
+            (42, [33, 7, -19]),
+            (100, [0, 2, 1]),
+        ]
+        .map(|(time, velocity): (i128, [i64; 3])| {
+            let velocity_128 = velocity.map(i128::from);
+            let position: [i128; 3] = std::array::from_fn(|i| {
+                throw.position[i] + time * (throw.velocity[i] - velocity_128[i])
+            });
+            Hailstone {
+                position: position.map(|component| component as i64),
+                velocity,
+            }
+        });
+
+        assert_eq!(find_throw(&hailstones), Ok(throw));
+    }
+
+    #[test]
+    fn find_throw_should_report_missed_hailstones() {
+        let mut hailstones = example();
+        hailstones.push(Hailstone {
+            position: [0, 0, 0],
+            velocity: [0, 0, 0],
+        });
+
+        assert_eq!(
+            find_throw(&hailstones),
+            Err("The throw misses hailstone 6".to_string())
+        );
+    }
+}
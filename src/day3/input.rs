@@ -1,4 +1,4 @@
-pub const INPUT: &[u8] = b"
+pub const INPUT: &str = "
 416.........................559...............417...............785.......900.......284...........503...796....992..........................
 .........702*....772............378..569.........&.49..606...14*..............$.453*.........307....*......$.....-.................995......
 .....................458...856......+.........+....&..............680.......104.............%....516.................................*......
@@ -1,95 +1,219 @@
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::sync::OnceLock;
 
-use crate::input::{read_lines, FilterNotEmpty};
-use crate::Solution;
+use aoc_utils::checked::CheckedIterator;
+use aoc_utils::input::non_empty_lines;
+use aoc_utils::random::Rng;
 
-mod input;
+use crate::{Example, Label, Solution};
 
-fn schematic() -> &'static EngineSchematic {
-    static INPUT: OnceLock<EngineSchematic> = OnceLock::new();
-    INPUT.get_or_init(|| EngineSchematic::from_lines(read_lines(input::INPUT).filter_not_empty()))
-}
+mod input;
+mod svg;
 
 pub struct Day3;
 
+const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "part-numbers-sum",
+        text: "Sum of all part numbers",
+    },
+    Label {
+        key: "gear-ratios-sum",
+        text: "Sum of all gear ratios",
+    },
+];
+
 impl Solution for Day3 {
     fn day(&self) -> u8 {
         3
     }
 
-    fn part_one(&self) -> String {
-        format!(
-            "Sum of all part numbers: {}",
-            schematic().part_numbers().iter().sum::<u32>(),
-        )
+    fn title(&self) -> Option<&'static str> {
+        Some("Gear Ratios")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
     }
 
-    fn part_two(&self) -> String {
-        format!(
-            "Sum of all gear ratios: {}",
-            schematic()
-                .gears()
-                .into_iter()
-                .map(|(n1, n2)| n1 * n2)
-                .sum::<u32>(),
-        )
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["533775", "78236071"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let sum = part_numbers_sum(input)?;
+        Ok(ANSWER_LABELS[0].answer(sum))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let sum = gear_ratios_sum(input)?;
+        Ok(ANSWER_LABELS[1].answer(sum))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        EngineSchematic::from_lines(non_empty_lines(input)).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Sum of all part numbers: 4361",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Sum of all gear ratios: 467835",
+            },
+        ]
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        Ok(random_schematic(size, rng))
+    }
+
+    fn visualize(&self, input: &str) -> Result<String, String> {
+        Ok(EngineSchematic::from_lines(non_empty_lines(input))?.to_svg())
+    }
+}
+
+/// The width of the schematics of the puzzle inputs.
+const GENERATED_WIDTH: usize = 140;
+
+/// Lines as wide as the puzzle inputs, with numbers of up to three digits and symbols scattered among the dots. A number
+/// is never directly followed by another, so that each stays a number of its own.
+fn random_schematic(lines: usize, rng: &mut Rng) -> String {
+    let symbols = ['*', '#', '+', '$', '/', '@', '%', '=', '-', '&'];
+    let mut schematic = String::new();
+    for _ in 0..lines {
+        let mut line = String::new();
+        while line.len() < GENERATED_WIDTH {
+            let number = rng.range(1..=999).to_string();
+            if rng.chance(15) && line.len() + number.len() < GENERATED_WIDTH {
+                line.push_str(&number);
+                line.push(if rng.chance(30) {
+                    *rng.choose(&symbols)
+                } else {
+                    '.'
+                });
+            } else if rng.chance(8) {
+                line.push(*rng.choose(&symbols));
+            } else {
+                line.push('.');
+            }
+        }
+        schematic.push_str(&line);
+        schematic.push('\n');
     }
+    schematic
+}
+
+pub fn part_numbers_sum(input: &str) -> Result<u32, String> {
+    EngineSchematic::from_lines(non_empty_lines(input))?
+        .part_numbers()
+        .into_iter()
+        .checked_sum()
+        .ok_or_else(|| "Sum of part numbers overflows".to_string())
+}
+
+pub fn gear_ratios_sum(input: &str) -> Result<u64, String> {
+    let schematic = EngineSchematic::from_lines(non_empty_lines(input))?;
+    sum_of_gear_ratios(&schematic.gears()).ok_or_else(|| "Sum of gear ratios overflows".to_string())
 }
 
-struct EngineSchematic {
+pub fn sum_of_gear_ratios(gears: &[(u32, u32)]) -> Option<u64> {
+    gears
+        .iter()
+        .map(|(n1, n2)| u64::from(*n1) * u64::from(*n2))
+        .checked_sum()
+}
+
+#[derive(Default)]
+pub struct EngineSchematic {
     symbols: HashMap<Position, char>,
     numbers: HashMap<usize, Vec<SchematicNumber>>,
 }
 
 impl EngineSchematic {
-    fn from_lines<L: IntoIterator<Item = String>>(lines: L) -> Self {
-        let mut symbols = HashMap::new();
-        let mut numbers = HashMap::new();
-
-        for (line, content) in lines.into_iter().enumerate() {
-            let mut line_numbers = Vec::new();
-            let mut current_number = Vec::new();
-            for (column, c) in content.chars().enumerate() {
-                if c.is_ascii_digit() {
-                    current_number.push(c);
-                } else {
-                    if !current_number.is_empty() {
-                        let value = current_number.iter().collect::<String>().parse().unwrap();
-                        line_numbers.push(SchematicNumber {
-                            value,
-                            line,
-                            start: column - current_number.len(),
-                            end: column - 1,
-                        });
-                        current_number.clear()
-                    }
-                    if c != '.' {
-                        symbols.insert(Position(column, line), c);
-                    }
-                }
-            }
-            if !current_number.is_empty() {
-                let value = current_number.iter().collect::<String>().parse().unwrap();
-                line_numbers.push(SchematicNumber {
-                    value,
+    /// An empty schematic, to be built with [`EngineSchematic::push_line`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn from_lines<L, S>(lines: L) -> Result<Self, String>
+    where
+        L: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut schematic = Self::new();
+        for line in lines {
+            schematic.push_line(line.as_ref())?;
+        }
+        Ok(schematic)
+    }
+
+    /// Adds a line below the existing ones. The schematic is left unchanged if the line is invalid.
+    pub fn push_line(&mut self, content: &str) -> Result<(), String> {
+        let line = self.numbers.len();
+        let mut line_symbols = Vec::new();
+        let mut line_numbers = Vec::new();
+        let mut current_number: Option<SchematicNumber> = None;
+        for (column, c) in content.chars().enumerate() {
+            if let Some(digit) = c.to_digit(10) {
+                let number = current_number.get_or_insert(SchematicNumber {
+                    value: 0,
                     line,
-                    start: content.len() - current_number.len(),
-                    end: content.len() - 1,
+                    start: column,
+                    end: column,
                 });
+                number.value = number
+                    .value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or_else(|| {
+                        format!(
+                            "Number too large at line {}, column {}",
+                            line + 1,
+                            number.start + 1,
+                        )
+                    })?;
+                number.end = column;
+            } else {
+                line_numbers.extend(current_number.take());
+                if c != '.' {
+                    line_symbols.push((Position(column, line), c));
+                }
             }
-            numbers.insert(line, line_numbers);
         }
-
-        Self { symbols, numbers }
+        line_numbers.extend(current_number);
+        self.symbols.extend(line_symbols);
+        self.numbers.insert(line, line_numbers);
+        Ok(())
     }
 
-    fn part_numbers(&self) -> Vec<u32> {
+    pub fn part_numbers(&self) -> Vec<u32> {
         self.symbols
             .keys()
             .flat_map(|position| self.adjacent_numbers(position))
-            .unique()
+            .unique_by(SchematicNumber::id)
             .map(|number| number.value)
             .collect()
     }
@@ -105,7 +229,36 @@ impl EngineSchematic {
             .collect()
     }
 
-    fn gears(&self) -> Vec<(u32, u32)> {
+    /// Every symbol with the numbers around it, from top to bottom and left to right.
+    pub fn symbols_with_adjacent_numbers(&self) -> Vec<(Position, char, Vec<u32>)> {
+        self.symbols
+            .iter()
+            .sorted_by_key(|(position, _)| (position.1, position.0))
+            .map(|(position, symbol)| {
+                let numbers = self
+                    .adjacent_numbers(position)
+                    .iter()
+                    .map(|number| number.value)
+                    .collect();
+                (*position, *symbol, numbers)
+            })
+            .collect()
+    }
+
+    /// The number with a digit at the given position, if any.
+    pub fn number_at(&self, position: Position) -> Option<SchematicNumber> {
+        self.numbers
+            .get(&position.1)?
+            .iter()
+            .find(|number| (number.start..=number.end).contains(&position.0))
+            .copied()
+    }
+
+    pub fn symbol_at(&self, position: Position) -> Option<char> {
+        self.symbols.get(&position).copied()
+    }
+
+    pub fn gears(&self) -> Vec<(u32, u32)> {
         self.symbols
             .iter()
             .filter(|(_, c)| **c == '*')
@@ -121,18 +274,46 @@ impl EngineSchematic {
     }
 }
 
+/// The column and the line of a cell of the schematic, from 0.
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
-struct Position(usize, usize);
+pub struct Position(pub usize, pub usize);
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
-struct SchematicNumber {
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct SchematicNumber {
     value: u32,
     line: usize,
     start: usize,
     end: usize,
 }
 
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+struct NumberId {
+    line: usize,
+    start: usize,
+}
+
 impl SchematicNumber {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The position of the first digit.
+    pub fn start(&self) -> Position {
+        Position(self.start, self.line)
+    }
+
+    /// The position of the last digit.
+    pub fn end(&self) -> Position {
+        Position(self.end, self.line)
+    }
+
+    fn id(&self) -> NumberId {
+        NumberId {
+            line: self.line,
+            start: self.start,
+        }
+    }
+
     fn is_adjacent(&self, position: &Position) -> bool {
         let start = self.start.saturating_sub(1);
         let end = self.end + 1;
@@ -144,28 +325,8 @@ impl SchematicNumber {
 mod test {
     use super::*;
 
-    fn example1() -> &'static EngineSchematic {
-        static EXAMPLE: OnceLock<EngineSchematic> = OnceLock::new();
-        EXAMPLE.get_or_init(|| {
-            EngineSchematic::from_lines(
-                read_lines(
-                    b"
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
+    fn example1() -> EngineSchematic {
+        EngineSchematic::from_lines(non_empty_lines(EXAMPLE)).unwrap()
     }
 
     #[test]
@@ -174,14 +335,117 @@ mod test {
     }
 
     #[test]
-    fn part2_example() {
+    fn part_numbers_should_keep_equal_values_adjacent_to_different_symbols() {
+        let schematic = EngineSchematic::from_lines(non_empty_lines(
+            "
+.12.....12.
+*..#...$...
+",
+        ))
+        .unwrap();
+
+        assert_eq!(schematic.part_numbers(), vec![12, 12]);
+    }
+
+    #[test]
+    fn from_lines_should_locate_numbers_after_multibyte_characters() {
+        let schematic = EngineSchematic::from_lines(non_empty_lines(
+            "
+é.12
+.*..
+",
+        ))
+        .unwrap();
+
+        assert_eq!(schematic.part_numbers(), vec![12]);
+    }
+
+    #[test]
+    fn push_line_should_build_the_schematic_line_by_line() {
+        let mut schematic = EngineSchematic::new();
+        schematic.push_line("467..114..").unwrap();
+        schematic.push_line("...*......").unwrap();
+        assert_eq!(schematic.gears(), vec![]);
+
+        schematic.push_line("..35..633.").unwrap();
+        assert_eq!(schematic.gears(), vec![(467, 35)]);
+    }
+
+    #[test]
+    fn push_line_should_leave_the_schematic_unchanged_on_error() {
+        let mut schematic = EngineSchematic::new();
+        schematic.push_line("12.").unwrap();
+
         assert_eq!(
-            example1()
-                .gears()
-                .into_iter()
-                .map(|(n1, n2)| n1 * n2)
-                .sum::<u32>(),
-            467835,
+            schematic.push_line("*.99999999999"),
+            Err("Number too large at line 2, column 3".to_string()),
         );
+        assert_eq!(schematic.part_numbers(), Vec::<u32>::new());
+        schematic.push_line(".*.").unwrap();
+        assert_eq!(schematic.part_numbers(), vec![12]);
+    }
+
+    #[test]
+    fn number_at_should_find_the_number_under_any_of_its_digits() {
+        let schematic = example1();
+
+        let number = schematic.number_at(Position(6, 2)).unwrap();
+        assert_eq!(
+            (number.value(), number.start(), number.end()),
+            (633, Position(6, 2), Position(8, 2)),
+        );
+        assert_eq!(schematic.number_at(Position(8, 2)), Some(number));
+        assert_eq!(schematic.number_at(Position(9, 2)), None);
+        assert_eq!(schematic.number_at(Position(3, 1)), None);
+    }
+
+    #[test]
+    fn symbol_at_should_only_find_symbols() {
+        let schematic = example1();
+
+        assert_eq!(schematic.symbol_at(Position(3, 1)), Some('*'));
+        assert_eq!(schematic.symbol_at(Position(6, 3)), Some('#'));
+        assert_eq!(schematic.symbol_at(Position(0, 0)), None);
+        assert_eq!(schematic.symbol_at(Position(0, 1)), None);
+    }
+
+    #[test]
+    fn from_lines_should_reject_numbers_that_overflow() {
+        let schematic = EngineSchematic::from_lines(non_empty_lines(
+            "
+..99999999999
+.*...........
+",
+        ));
+
+        assert_eq!(
+            schematic.err(),
+            Some("Number too large at line 1, column 3".to_string()),
+        );
+    }
+
+    #[test]
+    fn symbols_with_adjacent_numbers_of_example() {
+        let symbols = example1().symbols_with_adjacent_numbers();
+
+        assert_eq!(symbols.len(), 6);
+        assert_eq!(symbols[0], (Position(3, 1), '*', vec![467, 35]));
+        assert_eq!(symbols[1], (Position(6, 3), '#', vec![633]));
+        assert_eq!(symbols[4], (Position(3, 8), '$', vec![664]));
+        assert_eq!(symbols[5], (Position(5, 8), '*', vec![755, 598]));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(sum_of_gear_ratios(&example1().gears()), Some(467835));
+    }
+
+    #[test]
+    fn generated_schematic_should_be_valid() {
+        let schematic = random_schematic(50, &mut Rng::new(3));
+
+        assert!(non_empty_lines(&schematic).all(|line| line.len() == GENERATED_WIDTH));
+        assert!(part_numbers_sum(&schematic).is_ok_and(|sum| sum > 0));
+        assert!(gear_ratios_sum(&schematic).is_ok());
     }
 }
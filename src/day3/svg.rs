@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::{EngineSchematic, Position};
+
+const CELL_WIDTH: usize = 10;
+const CELL_HEIGHT: usize = 18;
+
+impl EngineSchematic {
+    /// Renders the schematic as an SVG image where part numbers, other numbers, symbols and gears each have their own
+    /// color.
+    pub fn to_svg(&self) -> String {
+        let part_numbers = self
+            .symbols
+            .keys()
+            .flat_map(|position| self.adjacent_numbers(position))
+            .map(|number| number.id())
+            .collect::<HashSet<_>>();
+        let mut numbers = self.numbers.values().flatten().collect::<Vec<_>>();
+        numbers.sort_by_key(|number| (number.line, number.start));
+        let mut symbols = self.symbols.iter().collect::<Vec<_>>();
+        symbols.sort_by_key(|(Position(column, line), _)| (*line, *column));
+
+        let width = numbers
+            .iter()
+            .map(|number| number.end + 1)
+            .chain(symbols.iter().map(|(position, _)| position.0 + 1))
+            .max()
+            .unwrap_or(0);
+        let height = self.numbers.len();
+
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}" font-family="monospace" font-size="16">
+<style>.part{{fill:#2e7d32}}.number{{fill:#9e9e9e}}.symbol{{fill:#1565c0}}.gear{{fill:#c62828;font-weight:bold}}</style>
+"#,
+            width * CELL_WIDTH,
+            height * CELL_HEIGHT,
+        );
+        for number in numbers {
+            let class = if part_numbers.contains(&number.id()) {
+                "part"
+            } else {
+                "number"
+            };
+            text(
+                &mut svg,
+                class,
+                number.start,
+                number.line,
+                number.end - number.start + 1,
+                &number.value.to_string(),
+            );
+        }
+        for (position, symbol) in symbols {
+            let class = if *symbol == '*' && self.adjacent_numbers(position).len() == 2 {
+                "gear"
+            } else {
+                "symbol"
+            };
+            text(&mut svg, class, position.0, position.1, 1, &escape(*symbol));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn text(svg: &mut String, class: &str, column: usize, line: usize, length: usize, content: &str) {
+    let _ = writeln!(
+        svg,
+        r#"<text class="{class}" x="{}" y="{}" textLength="{}">{content}</text>"#,
+        column * CELL_WIDTH,
+        (line + 1) * CELL_HEIGHT - 4,
+        length * CELL_WIDTH,
+    );
+}
+
+fn escape(symbol: char) -> String {
+    match symbol {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        _ => symbol.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn to_svg_should_color_code_the_schematic() {
+        let schematic =
+            EngineSchematic::from_lines(["467..114..", "...*......", "..35...&..", "......*..7"])
+                .unwrap();
+
+        assert_snapshot!(schematic.to_svg(), @r#"
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 72" font-family="monospace" font-size="16">
+        <style>.part{fill:#2e7d32}.number{fill:#9e9e9e}.symbol{fill:#1565c0}.gear{fill:#c62828;font-weight:bold}</style>
+        <text class="part" x="0" y="14" textLength="30">467</text>
+        <text class="number" x="50" y="14" textLength="30">114</text>
+        <text class="part" x="20" y="50" textLength="20">35</text>
+        <text class="number" x="90" y="68" textLength="10">7</text>
+        <text class="gear" x="30" y="32" textLength="10">*</text>
+        <text class="symbol" x="70" y="50" textLength="10">&amp;</text>
+        <text class="symbol" x="60" y="68" textLength="10">*</text>
+        </svg>
+        "#);
+    }
+}
@@ -1,4 +1,4 @@
-pub const INPUT: &[u8] = b"
+pub const INPUT: &str = "
 Card   1: 66 92  4 54 39 76 49 27 61 56 | 66 59 85 54 61 86 37 49  6 18 81 39  4 56  2 48 76 72 71 25 27 67 10 92 13
 Card   2:  8  9 21 99 15 84 39 47 63 18 | 10 50 55 75 78 28 47 19 63 81  8 61  5 66  3  7 12 27 33  9 16 39 17  4 64
 Card   3: 85 17 43 97 49 48 24 72 64 42 | 91 94 18 23 45 95 77  8  5 13 65 66 96 47 86 93 90 98 67 50 20 73 99 32 15
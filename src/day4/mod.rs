@@ -1,85 +1,331 @@
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
 use std::str::FromStr;
-use std::sync::OnceLock;
 
 use nom::bytes::complete::tag;
 use nom::character::complete::{digit1, multispace1};
+use nom::combinator::map_res;
 use nom::multi::separated_list0;
 use nom::sequence::tuple;
 use nom::IResult;
 
-use crate::input::{read_lines, FilterNotEmpty, ParseExt};
-use crate::Solution;
+use aoc_utils::input::parse_lines;
+use aoc_utils::random::Rng;
 
-mod input;
+use crate::{Example, Label, Solution, Variant};
 
-fn scratchcards() -> &'static Vec<Scratchcard> {
-    static SCRATCHCARDS: OnceLock<Vec<Scratchcard>> = OnceLock::new();
-    SCRATCHCARDS.get_or_init(|| {
-        read_lines(input::INPUT)
-            .filter_not_empty()
-            .parse()
-            .collect()
-    })
-}
+mod input;
 
 pub struct Day4;
 
+const EXAMPLE: &str = "
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "scratchcard-points-sum",
+        text: "Sum of all scratchcards points",
+    },
+    Label {
+        key: "scratchcard-count",
+        text: "Total number of scratchcards",
+    },
+];
+
 impl Solution for Day4 {
     fn day(&self) -> u8 {
         4
     }
 
-    fn part_one(&self) -> String {
-        format!(
-            "Sum of all scratchcards points: {}",
-            scratchcards().iter().map(Scratchcard::points).sum::<u32>(),
-        )
+    fn title(&self) -> Option<&'static str> {
+        Some("Scratchcards")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["18653", "5921508"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        Ok(ANSWER_LABELS[0].answer(total_points(input)?))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        Ok(ANSWER_LABELS[1].answer(total_scratchcards(input)?))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_lines::<Scratchcard>(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Sum of all scratchcards points: 13",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Total number of scratchcards: 30",
+            },
+        ]
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![
+            Variant {
+                name: "streaming",
+                part: 1,
+                solve: |input| {
+                    let scorer = score_reader(input.as_bytes())?;
+                    Ok(ANSWER_LABELS[0].answer(scorer.points()))
+                },
+            },
+            Variant {
+                name: "streaming",
+                part: 2,
+                solve: |input| {
+                    let scorer = score_reader(input.as_bytes())?;
+                    Ok(ANSWER_LABELS[1].answer(scorer.scratchcards()))
+                },
+            },
+        ]
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        Ok(random_scratchcards(size, rng))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        let scratchcards = match parse_lines::<Scratchcard>(input) {
+            Ok(scratchcards) => scratchcards,
+            Err(error) => return vec![error],
+        };
+        match part {
+            1 => scratchcards
+                .iter()
+                .map(|scratchcard| {
+                    format!(
+                        "Card {}: {} matching numbers, {} points",
+                        scratchcard.number,
+                        scratchcard.matching_numbers_count(),
+                        scratchcard.points(),
+                    )
+                })
+                .collect(),
+            2 => cascade_table(&scratchcards),
+            _ => Vec::new(),
+        }
+    }
+}
+
+fn cascade_table(scratchcards: &[Scratchcard]) -> Vec<String> {
+    let copies = copy_counts(scratchcards);
+    let header = format!("{:>5}  {:>8}  {:>7}  Spawns", "Card", "Copies", "Matches");
+    let rows =
+        scratchcards
+            .iter()
+            .zip(&copies)
+            .enumerate()
+            .map(|(index, (scratchcard, copies))| {
+                let spawned = won_cards(scratchcards, index)
+                    .map(|card| card.number.to_string())
+                    .collect::<Vec<_>>();
+                format!(
+                    "{:>5}  {copies:>8}  {:>7}  {}",
+                    scratchcard.number,
+                    scratchcard.matching_numbers_count(),
+                    if spawned.is_empty() {
+                        "-".to_string()
+                    } else {
+                        spawned.join(", ")
+                    },
+                )
+            });
+    let total = copies
+        .iter()
+        .fold(0, |total: usize, copies| total.saturating_add(*copies));
+    std::iter::once(header)
+        .chain(rows)
+        .chain(std::iter::once(format!("Total: {total}")))
+        .collect()
+}
+
+/// The number of copies of each card once all the cards have been won, original included.
+pub fn copy_counts(scratchcards: &[Scratchcard]) -> Vec<usize> {
+    let mut copies = vec![1usize; scratchcards.len()];
+    for (index, scratchcard) in scratchcards.iter().enumerate() {
+        for won in index + 1..(index + 1 + scratchcard.matching_numbers_count()).min(copies.len()) {
+            copies[won] = copies[won].saturating_add(copies[index]);
+        }
     }
+    copies
+}
+
+/// The cards won by the card at `index`, which are the next ones in the list whatever their numbers, as for
+/// `copy_counts`.
+fn won_cards(scratchcards: &[Scratchcard], index: usize) -> impl Iterator<Item = &Scratchcard> {
+    (0..scratchcards[index].matching_numbers_count())
+        .filter_map(move |n| scratchcards.get(index + 1 + n))
+}
 
-    fn part_two(&self) -> String {
-        format!(
-            "Total number of scratchcards: {}",
-            compute_nb_scratchcards(scratchcards()),
-        )
+/// Cards with 10 winning numbers and 25 numbers, laid out like the puzzle input. Most cards have no matching numbers
+/// and the others up to three, so that the number of copies grows slowly rather than exponentially with the number of
+/// cards.
+fn random_scratchcards(cards: usize, rng: &mut Rng) -> String {
+    let width = cards.to_string().len();
+    let mut numbers = (1..=99).collect::<Vec<u32>>();
+    let mut lines = String::new();
+    for number in 1..=cards {
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(10);
+        let matches = if rng.chance(60) {
+            0
+        } else {
+            rng.range(1..=3) as usize
+        };
+        let mut owned = [&winning[..matches], &others[..25 - matches]].concat();
+        rng.shuffle(&mut owned);
+        let format = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        lines.push_str(&format!(
+            "Card {number:>width$}: {} | {}\n",
+            format(winning),
+            format(&owned),
+        ));
     }
+    lines
 }
 
-fn compute_nb_scratchcards(scratchcards: &[Scratchcard]) -> usize {
-    let mut cards_to_process = scratchcards.iter().collect::<Vec<_>>();
-    let mut scratchcards_count = scratchcards.len();
+pub fn total_points(input: &str) -> Result<u64, String> {
+    Ok(parse_lines::<Scratchcard>(input)?
+        .iter()
+        .map(Scratchcard::points)
+        .fold(0, u64::saturating_add))
+}
+
+pub fn total_scratchcards(input: &str) -> Result<usize, String> {
+    Ok(compute_nb_scratchcards(&parse_lines(input)?))
+}
+
+pub fn compute_nb_scratchcards(scratchcards: &[Scratchcard]) -> usize {
+    copy_counts(scratchcards)
+        .into_iter()
+        .fold(0, usize::saturating_add)
+}
+
+/// Scores the cards one at a time, only keeping the copies won for the cards to come, so that the memory used depends on
+/// the number of matches of a card rather than on the number of cards.
+#[derive(Debug, Default, Clone)]
+pub struct StreamingScorer {
+    pending_copies: VecDeque<usize>,
+    points: u64,
+    scratchcards: usize,
+}
+
+impl StreamingScorer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, scratchcard: &Scratchcard) {
+        let copies = self
+            .pending_copies
+            .pop_front()
+            .unwrap_or(0)
+            .saturating_add(1);
+        let matches = scratchcard.matching_numbers_count();
+        if self.pending_copies.len() < matches {
+            self.pending_copies.resize(matches, 0);
+        }
+        for pending in self.pending_copies.iter_mut().take(matches) {
+            *pending = pending.saturating_add(copies);
+        }
+        self.points = self.points.saturating_add(scratchcard.points());
+        self.scratchcards = self.scratchcards.saturating_add(copies);
+    }
 
-    while let Some(scratchcard) = cards_to_process.pop() {
-        let mut won_cards = (0..scratchcard.matching_numbers_count())
-            .filter_map(|n| scratchcards.get(scratchcard.number + n))
-            .collect::<Vec<_>>();
-        scratchcards_count += won_cards.len();
-        cards_to_process.append(&mut won_cards);
+    /// The sum of the points of the cards added so far.
+    pub fn points(&self) -> u64 {
+        self.points
     }
 
-    scratchcards_count
+    /// The number of scratchcards added so far, copies included. Copies won for cards that have not been added yet do
+    /// not count.
+    pub fn scratchcards(&self) -> usize {
+        self.scratchcards
+    }
+}
+
+/// Reads and scores the cards line by line, skipping blank lines.
+pub fn score_reader<R: BufRead>(reader: R) -> Result<StreamingScorer, String> {
+    let mut scorer = StreamingScorer::new();
+    for line in reader.lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        if !line.trim().is_empty() {
+            scorer.add(&line.parse()?);
+        }
+    }
+    Ok(scorer)
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Scratchcard {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scratchcard {
     number: usize,
     winning_numbers: HashSet<u32>,
     card_numbers: HashSet<u32>,
 }
 
 impl Scratchcard {
-    fn matching_numbers_count(&self) -> usize {
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn matching_numbers_count(&self) -> usize {
         self.winning_numbers
             .intersection(&self.card_numbers)
             .count()
     }
 
-    fn points(&self) -> u32 {
+    pub fn points(&self) -> u64 {
+        self.points_with_base(2)
+    }
+
+    /// One point for the first match, multiplied by `base` for each match after it.
+    pub fn points_with_base(&self, base: u32) -> u64 {
         match self.matching_numbers_count() {
             0 => 0,
-            n => 2u32.pow(n as u32 - 1),
+            n => u64::from(base).saturating_pow(u32::try_from(n - 1).unwrap_or(u32::MAX)),
         }
     }
+
+    /// The same number of points for every match.
+    pub fn linear_points(&self, points_per_match: u32) -> u64 {
+        u64::try_from(self.matching_numbers_count())
+            .unwrap_or(u64::MAX)
+            .saturating_mul(u64::from(points_per_match))
+    }
 }
 
 impl FromStr for Scratchcard {
@@ -99,22 +345,22 @@ fn parse_scratchcard(input: &str) -> IResult<&str, Scratchcard> {
     tuple((
         tag("Card"),
         multispace1,
-        digit1,
+        map_res(digit1, str::parse),
         tag(":"),
         multispace1,
-        separated_list0(multispace1, digit1),
+        separated_list0(multispace1, map_res(digit1, str::parse)),
         tag(" |"),
         multispace1,
-        separated_list0(multispace1, digit1),
+        separated_list0(multispace1, map_res(digit1, str::parse)),
     ))(input)
     .map(
         |(input, (_, _, number, _, _, winning_numbers, _, _, card_numbers))| {
             (
                 input,
                 Scratchcard {
-                    number: number.parse().unwrap(),
-                    winning_numbers: winning_numbers.iter().map(|i| i.parse().unwrap()).collect(),
-                    card_numbers: card_numbers.iter().map(|i| i.parse().unwrap()).collect(),
+                    number,
+                    winning_numbers: winning_numbers.into_iter().collect(),
+                    card_numbers: card_numbers.into_iter().collect(),
                 },
             )
         },
@@ -125,24 +371,8 @@ fn parse_scratchcard(input: &str) -> IResult<&str, Scratchcard> {
 mod test {
     use super::*;
 
-    fn example() -> &'static Vec<Scratchcard> {
-        static SCRATCHCARDS: OnceLock<Vec<Scratchcard>> = OnceLock::new();
-        SCRATCHCARDS.get_or_init(|| {
-            read_lines(
-                b"
-Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
-"
-                .as_slice(),
-            )
-            .filter_not_empty()
-            .parse()
-            .collect()
-        })
+    fn example() -> Vec<Scratchcard> {
+        parse_lines(EXAMPLE).unwrap()
     }
 
     #[test]
@@ -169,6 +399,11 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
         );
     }
 
+    #[test]
+    fn parse_card_with_overflowing_number_should_fail() {
+        assert!("Card 1: 4294967296 | 1".parse::<Scratchcard>().is_err());
+    }
+
     #[test]
     fn points_should_be_8_for_card_1() {
         let card1 = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
@@ -196,13 +431,151 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
         assert_eq!(card5.points(), 0);
     }
 
+    #[test]
+    fn points_with_base_3_should_be_27_for_card_1() {
+        assert_eq!(example()[0].points_with_base(3), 27);
+    }
+
+    #[test]
+    fn points_with_base_should_be_0_without_matches() {
+        assert_eq!(example()[4].points_with_base(10), 0);
+    }
+
+    #[test]
+    fn linear_points_should_count_every_match() {
+        assert_eq!(
+            example()
+                .iter()
+                .map(|card| card.linear_points(5))
+                .collect::<Vec<_>>(),
+            vec![20, 10, 10, 5, 0, 0],
+        );
+    }
+
     #[test]
     fn part1_example() {
-        assert_eq!(example().iter().map(Scratchcard::points).sum::<u32>(), 13)
+        assert_eq!(example().iter().map(Scratchcard::points).sum::<u64>(), 13)
+    }
+
+    fn card_with_matches(matches: u32) -> Scratchcard {
+        let numbers = (1..=matches).map(|n| n.to_string()).collect::<Vec<_>>();
+        format!("Card 1: {} | {}", numbers.join(" "), numbers.join(" "))
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn points_should_not_overflow_for_a_card_with_40_matches() {
+        assert_eq!(card_with_matches(40).points(), 1 << 39);
+    }
+
+    #[test]
+    fn points_should_saturate_for_a_card_with_70_matches() {
+        assert_eq!(card_with_matches(70).points(), u64::MAX);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(compute_nb_scratchcards(example()), 30)
+        assert_eq!(compute_nb_scratchcards(&example()), 30)
+    }
+
+    #[test]
+    fn copy_counts_of_example() {
+        assert_eq!(copy_counts(&example()), vec![1, 2, 4, 8, 14, 1]);
+    }
+
+    #[test]
+    fn score_reader_should_match_both_parts_of_example() {
+        let scorer = score_reader(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!((scorer.points(), scorer.scratchcards()), (13, 30));
+    }
+
+    #[test]
+    fn streaming_scorer_should_ignore_copies_won_past_the_last_card() {
+        let mut scorer = StreamingScorer::new();
+        scorer.add(&card_with_matches(3));
+        scorer.add(&"Card 2: 1 | 2".parse().unwrap());
+
+        assert_eq!(scorer.scratchcards(), 3);
+    }
+
+    #[test]
+    fn score_reader_should_report_invalid_cards() {
+        assert_eq!(
+            score_reader("Card 1: 1 | 1\nCard 2: x | 1\n".as_bytes()).map(|scorer| scorer.points()),
+            Err("Invalid card: Card 2: x | 1".to_string()),
+        );
+    }
+
+    #[test]
+    fn explain_should_report_matches_per_card() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3: 87 83 26 28 32 | 88 30 70 12 93 22 82 36";
+
+        assert_eq!(
+            Day4.explain(1, input),
+            vec![
+                "Card 1: 4 matching numbers, 8 points",
+                "Card 2: 2 matching numbers, 2 points",
+                "Card 3: 0 matching numbers, 0 points",
+            ],
+        );
+    }
+
+    #[test]
+    fn explain_should_give_the_error_of_an_invalid_input() {
+        assert_eq!(
+            Day4.explain(2, "Card 1: 1 | 1\nCard 2: x | 1"),
+            vec!["Invalid card: Card 2: x | 1"],
+        );
+    }
+
+    #[test]
+    fn explain_part_two_should_show_the_cascade_of_copies() {
+        assert_eq!(
+            Day4.explain(2, EXAMPLE),
+            vec![
+                " Card    Copies  Matches  Spawns",
+                "    1         1        4  2, 3, 4, 5",
+                "    2         2        2  3, 4",
+                "    3         4        2  4, 5",
+                "    4         8        1  5",
+                "    5        14        0  -",
+                "    6         1        0  -",
+                "Total: 30",
+            ],
+        );
+    }
+
+    #[test]
+    fn explain_part_two_should_spawn_the_next_cards_whatever_their_numbers() {
+        let input = "Card 7: 1 2 | 1 2
+Card 3: 1 | 1
+Card 9: 1 | 2";
+
+        assert_eq!(
+            Day4.explain(2, input),
+            vec![
+                " Card    Copies  Matches  Spawns",
+                "    7         1        2  3, 9",
+                "    3         2        1  9",
+                "    9         4        0  -",
+                "Total: 7",
+            ],
+        );
+    }
+
+    #[test]
+    fn generated_scratchcards_should_be_valid() {
+        let scratchcards =
+            parse_lines::<Scratchcard>(&random_scratchcards(200, &mut Rng::new(4))).unwrap();
+
+        assert_eq!(scratchcards.len(), 200);
+        assert!(scratchcards
+            .iter()
+            .all(|scratchcard| scratchcard.matching_numbers_count() <= 3));
+        assert!(compute_nb_scratchcards(&scratchcards) < 200 * 200);
     }
 }
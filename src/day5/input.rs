@@ -1,10 +1,6 @@
-pub const SEEDS: &[u32] = &[
-    3127166940, 109160474, 3265086325, 86449584, 1581539098, 205205726, 3646327835, 184743451,
-    2671979893, 17148151, 305618297, 40401857, 2462071712, 203075200, 358806266, 131147346,
-    1802185716, 538526744, 635790399, 705979250,
-];
+pub const INPUT: &str = "seeds: 3127166940 109160474 3265086325 86449584 1581539098 205205726 3646327835 184743451 2671979893 17148151 305618297 40401857 2462071712 203075200 358806266 131147346 1802185716 538526744 635790399 705979250
 
-pub const SEED_TO_SOIL_MAP: &[u8] = b"
+seed-to-soil map:
 931304316 1786548802 232453384
 3500539319 2322065235 6421609
 496396007 147739714 266329192
@@ -37,9 +33,8 @@ pub const SEED_TO_SOIL_MAP: &[u8] = b"
 269226185 629314747 133410452
 2943085089 3871156865 23804311
 1731984867 2512159762 204963884
-";
 
-pub const SOIL_TO_FERTILIZER_MAP: &[u8] = b"
+soil-to-fertilizer map:
 3368312743 826425240 243745914
 1045038113 3682756471 174490549
 3931158487 1530223690 363808809
@@ -69,9 +64,8 @@ pub const SOIL_TO_FERTILIZER_MAP: &[u8] = b"
 1423662510 3991222595 60049702
 3808588784 4111911476 102622226
 1518142024 2591321500 13033199
-";
 
-pub const FERTILIZER_TO_WATER_MAP: &[u8] = b"
+fertilizer-to-water map:
 206818393 1973789958 18543481
 2641351404 1992333439 41420268
 58400970 2574944960 107826712
@@ -107,9 +101,8 @@ pub const FERTILIZER_TO_WATER_MAP: &[u8] = b"
 166227682 269683848 40590711
 0 2331802713 58400970
 225361874 420137315 226066064
-";
 
-pub const WATER_TO_LIGHT_MAP: &[u8] = b"
+water-to-light map:
 1833244152 0 764535859
 212138399 2132863085 224047237
 445686952 1600446740 163005122
@@ -135,9 +128,8 @@ pub const WATER_TO_LIGHT_MAP: &[u8] = b"
 794563824 1237176557 244191789
 621886817 1190805596 46370961
 3994565455 3403272109 186068531
-";
 
-pub const LIGHT_TO_TEMPERATURE_MAP: &[u8] = b"
+light-to-temperature map:
 432141642 1268486741 19474646
 3617581823 3276436954 357008111
 3505110084 3786131308 49942802
@@ -152,9 +144,8 @@ pub const LIGHT_TO_TEMPERATURE_MAP: &[u8] = b"
 909738155 3836074110 138515824
 1048253979 2837492192 299739845
 2866648907 3633445065 152686243
-";
 
-pub const TEMPERATURE_TO_HUMIDITY_MAP: &[u8] = b"
+temperature-to-humidity map:
 646729740 1519504972 559297346
 1894539176 2990410634 44298872
 232257988 972432123 414471752
@@ -187,9 +178,8 @@ pub const TEMPERATURE_TO_HUMIDITY_MAP: &[u8] = b"
 1983095187 4129082008 165885288
 3698642563 251047688 21462207
 2718009081 581475723 314983749
-";
 
-pub const HUMIDITY_TO_LOCATION_MAP: &[u8] = b"
+humidity-to-location map:
 971626884 4275486551 19480745
 1218249913 2090555906 502249162
 2914848039 2902831882 224865747
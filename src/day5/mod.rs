@@ -2,115 +2,545 @@ use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::BTreeSet;
 use std::ops::Range;
-use std::sync::OnceLock;
+use std::str::FromStr;
 
-use crate::input::{read_lines, FilterNotEmpty};
-use crate::Solution;
+use aoc_utils::input::non_empty_lines;
+use aoc_utils::random::Rng;
+
+use crate::{Example, Label, Solution, Variant};
 
 mod input;
 
-fn seed_to_soil_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::SEED_TO_SOIL_MAP).filter_not_empty()))
+/// The seeds and the maps of an almanac, each map converting from a category to another.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<CategoryMap>,
 }
 
-fn soil_to_fertilizer_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::SOIL_TO_FERTILIZER_MAP).filter_not_empty()))
+#[derive(Debug, Eq, PartialEq)]
+struct CategoryMap {
+    source: String,
+    destination: String,
+    map: Map,
 }
 
-fn fertilizer_to_water_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::FERTILIZER_TO_WATER_MAP).filter_not_empty()))
-}
+impl Almanac {
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
 
-fn water_to_light_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::WATER_TO_LIGHT_MAP).filter_not_empty()))
-}
+    /// The maps to follow to convert a `from` value into a `to` value, by category names.
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<Map>, String> {
+        let mut category = from;
+        let mut maps = Vec::new();
+        while category != to {
+            if maps.len() == self.maps.len() {
+                return Err(format!("No conversion from {from} to {to}"));
+            }
+            let next = self
+                .maps
+                .iter()
+                .find(|map| map.source == category)
+                .ok_or_else(|| format!("No {category}-to-* map"))?;
+            maps.push(next.map.clone());
+            category = &next.destination;
+        }
+        Ok(maps)
+    }
 
-fn light_to_temperature_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::LIGHT_TO_TEMPERATURE_MAP).filter_not_empty()))
+    pub fn convert(&self, from: &str, to: &str, value: u64) -> Result<u64, String> {
+        Ok(map_all(&self.chain(from, to)?, value))
+    }
+
+    /// The value of a seed in every category, following the maps from the seed until there is none left.
+    pub fn trace(&self, seed: u64) -> Vec<(&str, u64)> {
+        let mut trace = vec![("seed", seed)];
+        let mut category = "seed";
+        let mut value = seed;
+        while let Some(next) = self
+            .maps
+            .iter()
+            .find(|map| map.source == category)
+            .filter(|_| trace.len() <= self.maps.len())
+        {
+            category = &next.destination;
+            value = next.map.map(value);
+            trace.push((category, value));
+        }
+        trace
+    }
 }
 
-fn temperature_to_humidity_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::TEMPERATURE_TO_HUMIDITY_MAP).filter_not_empty()))
+impl FromStr for Almanac {
+    type Err = String;
+
+    #[tracing::instrument(skip_all)]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = non_empty_lines(input);
+        let seeds = lines
+            .next()
+            .and_then(|line| line.strip_prefix("seeds:"))
+            .ok_or("Missing seeds")?
+            .split_whitespace()
+            .map(|seed| seed.parse().map_err(|_| format!("Invalid seed: {seed}")))
+            .collect::<Result<_, _>>()?;
+
+        let mut sections: Vec<(&str, &str, Vec<MapEntry>)> = Vec::new();
+        for line in lines {
+            if let Some(categories) = line.strip_suffix(" map:") {
+                let (source, destination) = categories
+                    .split_once("-to-")
+                    .ok_or_else(|| format!("Invalid map header: {line}"))?;
+                sections.push((source, destination, Vec::new()));
+            } else {
+                sections
+                    .last_mut()
+                    .ok_or_else(|| format!("Map entry outside of a map: {line}"))?
+                    .2
+                    .push(line.parse()?);
+            }
+        }
+
+        Ok(Almanac {
+            seeds,
+            maps: sections
+                .into_iter()
+                .map(|(source, destination, entries)| CategoryMap {
+                    source: source.to_string(),
+                    destination: destination.to_string(),
+                    map: entries.into_iter().collect(),
+                })
+                .collect(),
+        })
+    }
 }
 
-fn humidity_to_location_map() -> &'static Map {
-    static MAP: OnceLock<Map> = OnceLock::new();
-    MAP.get_or_init(|| Map::from(read_lines(input::HUMIDITY_TO_LOCATION_MAP).filter_not_empty()))
+fn seeds_and_location_maps(input: &str) -> Result<(Vec<u64>, Vec<Map>), String> {
+    let almanac = input.parse::<Almanac>()?;
+    let maps = almanac.chain("seed", "location")?;
+    Ok((almanac.seeds, maps))
 }
 
 pub struct Day5;
 
+const EXAMPLE: &str = "
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "minimal-location",
+        text: "Minimal location",
+    },
+    Label {
+        key: "minimal-location-with-ranges",
+        text: "Minimal location with ranges",
+    },
+];
+
 impl Solution for Day5 {
     fn day(&self) -> u8 {
         5
     }
 
-    fn part_one(&self) -> String {
-        let maps = &[
-            seed_to_soil_map(),
-            soil_to_fertilizer_map(),
-            fertilizer_to_water_map(),
-            water_to_light_map(),
-            light_to_temperature_map(),
-            temperature_to_humidity_map(),
-            humidity_to_location_map(),
-        ];
-        let min_location = input::SEEDS
-            .iter()
-            .map(|seed| map_all(maps, *seed))
+    fn title(&self) -> Option<&'static str> {
+        Some("If You Give A Seed A Fertilizer")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["218513636", "81956384"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        Ok(ANSWER_LABELS[0].answer(lowest_location(input)?))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        Ok(ANSWER_LABELS[1].answer(lowest_location_with_ranges(input)?))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        seeds_and_location_maps(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Minimal location: 35",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Minimal location with ranges: 46",
+            },
+        ]
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![Variant {
+            name: "ascending locations",
+            part: 2,
+            solve: |input| {
+                Ok(ANSWER_LABELS[1]
+                    .answer(lowest_location_with(input, Algorithm::AscendingLocations)?))
+            },
+        }]
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        Ok(random_almanac(size, rng))
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        match (part, input.parse::<Almanac>()) {
+            (1, Ok(almanac)) => almanac
+                .seeds()
+                .iter()
+                .map(|seed| {
+                    almanac
+                        .trace(*seed)
+                        .into_iter()
+                        .map(|(category, value)| format!("{category} {value}"))
+                        .join(" → ")
+                })
+                .collect(),
+            (2, _) => match lowest_location_seed(input) {
+                Ok(SeedLocation { seed, location }) => {
+                    vec![format!(
+                        "Seed {seed} reaches the lowest location {location}"
+                    )]
+                }
+                Err(error) => vec![error],
+            },
+            (_, Err(error)) => vec![error],
+            _ => Vec::new(),
+        }
+    }
+
+    fn visualize(&self, input: &str) -> Result<String, String> {
+        let (seeds, maps) = seeds_and_location_maps(input)?;
+        let ranges = seed_ranges(&seeds)?;
+        let mut output = format!("Seeds: {}\n", format_ranges(&normalize(ranges.clone())));
+        let stages = map_range_stages(&maps, ranges);
+        for (index, stage) in stages.iter().enumerate() {
+            output.push_str(&format!("Map {}\n", index + 1));
+            for mapping in stage {
+                output.push_str(&format!(
+                    "  {:?} → {}\n",
+                    mapping.source,
+                    format_ranges(&mapping.targets),
+                ));
+            }
+        }
+        if let Some(location) = stages
+            .last()
+            .into_iter()
+            .flatten()
+            .flat_map(|mapping| &mapping.targets)
+            .map(|range| range.start)
             .min()
-            .unwrap();
-        format!("Minimal location: {}", min_location)
-    }
-
-    fn part_two(&self) -> String {
-        let maps = &[
-            seed_to_soil_map(),
-            soil_to_fertilizer_map(),
-            fertilizer_to_water_map(),
-            water_to_light_map(),
-            light_to_temperature_map(),
-            temperature_to_humidity_map(),
-            humidity_to_location_map(),
-        ];
-        let ranges = input::SEEDS
-            .iter()
-            .tuples()
-            .map(|(start, length)| *start..(start + length))
+        {
+            output.push_str(&format!("Lowest location: {location}\n"));
+        }
+        Ok(output)
+    }
+}
+
+fn format_ranges(ranges: &[Range<u64>]) -> String {
+    ranges.iter().map(|range| format!("{range:?}")).join(", ")
+}
+
+/// The categories of the puzzle input, in the order of the maps.
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// The bound of the numbers of the generated almanacs, which fit in 32 bits as in the puzzle input.
+const GENERATED_LIMIT: u64 = 1 << 32;
+
+/// An almanac with `size` seed ranges, which cover at most a sixteenth of the numbers so that the lowest location is
+/// rarely 0, and maps of `size` entries. As in the puzzle input, the entries of a map cover every number below the
+/// limit, and so do their targets, so that each map is a bijection.
+fn random_almanac(size: usize, rng: &mut Rng) -> String {
+    let mut almanac = String::from("seeds:");
+    for _ in 0..size {
+        let start = rng.range(0..=GENERATED_LIMIT - 1);
+        let longest = (GENERATED_LIMIT / 16 / size as u64).max(1);
+        let length = rng.range(1..=(GENERATED_LIMIT - start).min(longest));
+        almanac.push_str(&format!(" {start} {length}"));
+    }
+    almanac.push('\n');
+    let entries = size.clamp(1, GENERATED_LIMIT as usize);
+    for (source, destination) in CATEGORIES.iter().tuple_windows() {
+        let mut cuts = BTreeSet::from([0, GENERATED_LIMIT]);
+        while cuts.len() <= entries {
+            cuts.insert(rng.range(1..=GENERATED_LIMIT - 1));
+        }
+        let sources = cuts.iter().tuple_windows().collect::<Vec<_>>();
+        let mut order = (0..sources.len()).collect::<Vec<_>>();
+        rng.shuffle(&mut order);
+        let mut target_start = 0;
+        let mut lines = order
+            .into_iter()
+            .map(|index| {
+                let (start, end) = sources[index];
+                let line = format!("{target_start} {start} {}", end - start);
+                target_start += end - start;
+                line
+            })
             .collect::<Vec<_>>();
-        let min_location = map_range_all(maps, ranges)
+        rng.shuffle(&mut lines);
+        almanac.push_str(&format!(
+            "\n{source}-to-{destination} map:\n{}\n",
+            lines.join("\n")
+        ));
+    }
+    almanac
+}
+
+pub fn lowest_location(input: &str) -> Result<u64, String> {
+    let (seeds, maps) = seeds_and_location_maps(input)?;
+    seeds
+        .into_iter()
+        .map(|seed| map_all(&maps, seed))
+        .min()
+        .ok_or_else(|| "No seeds".to_string())
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SeedLocation {
+    pub seed: u64,
+    pub location: u64,
+}
+
+/// The seed of the seed ranges that ends up at the lowest location.
+pub fn lowest_location_seed(input: &str) -> Result<SeedLocation, String> {
+    let (seeds, maps) = seeds_and_location_maps(input)?;
+    map_tracked_range_all(&maps, seed_ranges(&seeds)?)
+        .into_iter()
+        .filter(|tracked| !tracked.mapped.is_empty())
+        .min_by_key(|tracked| tracked.mapped.start)
+        .map(|tracked| SeedLocation {
+            seed: tracked.source.start,
+            location: tracked.mapped.start,
+        })
+        .ok_or_else(|| "No seeds".to_string())
+}
+
+/// How to find the lowest location of the seed ranges.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Algorithm {
+    /// Splits the seed ranges through every map.
+    RangeSplitting,
+    /// Walks the locations from 0 upward through the inverted maps until one comes from a seed.
+    AscendingLocations,
+}
+
+pub fn lowest_location_with_ranges(input: &str) -> Result<u64, String> {
+    lowest_location_with(input, Algorithm::RangeSplitting)
+}
+
+pub fn lowest_location_with(input: &str, algorithm: Algorithm) -> Result<u64, String> {
+    let (seeds, maps) = seeds_and_location_maps(input)?;
+    let ranges = seed_ranges(&seeds)?;
+    match algorithm {
+        Algorithm::RangeSplitting => map_range_all(&maps, ranges)
             .iter()
             .map(|range| range.start)
-            .min()
-            .unwrap();
-        format!("Minimal location with ranges: {}", min_location)
+            .min(),
+        Algorithm::AscendingLocations => scan_locations(&maps, &ranges),
     }
+    .ok_or_else(|| "No seeds".to_string())
 }
 
-fn map_all(maps: &[&Map], source: u32) -> u32 {
+fn scan_locations(maps: &[Map], seed_ranges: &[Range<u64>]) -> Option<u64> {
+    if seed_ranges.iter().all(Range::is_empty) {
+        return None;
+    }
+    let inverted = maps.iter().rev().map(Map::invert).collect::<Vec<_>>();
+    (0..=u64::MAX).find(|location| {
+        let seed = map_all(&inverted, *location);
+        seed_ranges.iter().any(|range| range.contains(&seed))
+    })
+}
+
+fn seed_ranges(seeds: &[u64]) -> Result<Vec<Range<u64>>, String> {
+    seeds
+        .iter()
+        .tuples()
+        .map(|(start, length)| {
+            start
+                .checked_add(*length)
+                .map(|end| *start..end)
+                .ok_or_else(|| format!("Seed range {start} {length} overflows"))
+        })
+        .collect()
+}
+
+pub fn map_all(maps: &[Map], source: u64) -> u64 {
     maps.iter().fold(source, |value, map| map.map(value))
 }
 
-fn map_range_all(maps: &[&Map], ranges: Vec<Range<u32>>) -> Vec<Range<u32>> {
-    maps.iter().fold(ranges, |ranges, map| {
-        ranges
-            .into_iter()
-            .flat_map(|range| map.map_range(range))
-            .collect()
+/// Maps a location back to the seed it comes from, assuming every map is a bijection.
+pub fn seed_for_location(maps: &[Map], location: u64) -> u64 {
+    maps.iter()
+        .rev()
+        .map(Map::invert)
+        .fold(location, |value, map| map.map(value))
+}
+
+pub fn map_range_all(maps: &[Map], ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    maps.iter().fold(normalize(ranges), |ranges, map| {
+        normalize(
+            ranges
+                .into_iter()
+                .flat_map(|range| map.map_range(range))
+                .collect(),
+        )
     })
 }
 
-#[derive(Debug, Eq, PartialEq, Hash)]
-struct MapEntry {
-    source_start: u32,
-    target_start: u32,
-    range_length: u32,
+/// A range mapped through some maps, along with the range it comes from, number for number.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TrackedRange {
+    pub source: Range<u64>,
+    pub mapped: Range<u64>,
+}
+
+/// Same as [`map_range_all`], but keeps track of the source of every mapped range. The ranges are
+/// not merged, so that each one still comes from a single source range.
+pub fn map_tracked_range_all(maps: &[Map], ranges: Vec<Range<u64>>) -> Vec<TrackedRange> {
+    let tracked = ranges
+        .into_iter()
+        .map(|range| TrackedRange {
+            source: range.clone(),
+            mapped: range,
+        })
+        .collect();
+    maps.iter()
+        .fold(tracked, |tracked: Vec<TrackedRange>, map| {
+            tracked
+                .into_iter()
+                .flat_map(|tracked| {
+                    let mut source_start = tracked.source.start;
+                    map.map_range(tracked.mapped)
+                        .into_iter()
+                        .map(move |mapped| {
+                            let source_end = source_start + (mapped.end - mapped.start);
+                            let source = source_start..source_end;
+                            source_start = source_end;
+                            TrackedRange { source, mapped }
+                        })
+                })
+                .collect()
+        })
+}
+
+/// How a range is split and shifted by a map.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RangeMapping {
+    pub source: Range<u64>,
+    pub targets: Vec<Range<u64>>,
+}
+
+/// Same as [`map_range_all`], but keeps the mapping of every range through every map.
+pub fn map_range_stages(maps: &[Map], ranges: Vec<Range<u64>>) -> Vec<Vec<RangeMapping>> {
+    let mut ranges = normalize(ranges);
+    let mut stages = Vec::new();
+    for map in maps {
+        let stage = ranges
+            .into_iter()
+            .map(|range| RangeMapping {
+                targets: map.map_range(range.clone()),
+                source: range,
+            })
+            .collect::<Vec<_>>();
+        ranges = normalize(
+            stage
+                .iter()
+                .flat_map(|mapping| mapping.targets.clone())
+                .collect(),
+        );
+        stages.push(stage);
+    }
+    stages
+}
+
+fn normalize(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+    ranges
+        .into_iter()
+        .coalesce(|previous, current| {
+            if current.start <= previous.end {
+                Ok(previous.start..previous.end.max(current.end))
+            } else {
+                Err((previous, current))
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "[u64; 3]", into = "[u64; 3]")
+)]
+pub struct MapEntry {
+    source_start: u64,
+    target_start: u64,
+    range_length: u64,
 }
 
 impl Ord for MapEntry {
@@ -126,11 +556,11 @@ impl PartialOrd for MapEntry {
 }
 
 impl MapEntry {
-    fn source_end(&self) -> u32 {
-        self.source_start.saturating_add(self.range_length)
+    fn source_end(&self) -> u64 {
+        self.source_start + self.range_length
     }
 
-    fn try_match(&self, source: u32) -> Option<u32> {
+    fn try_match(&self, source: u64) -> Option<u64> {
         if self.matches(source) {
             Some(self.map(source))
         } else {
@@ -138,27 +568,46 @@ impl MapEntry {
         }
     }
 
-    fn matches(&self, source: u32) -> bool {
+    fn matches(&self, source: u64) -> bool {
         source >= self.source_start && source - self.source_start < self.range_length
     }
 
-    fn map(&self, source: u32) -> u32 {
+    fn map(&self, source: u64) -> u64 {
         source - self.source_start + self.target_start
     }
+
+    fn invert(&self) -> Self {
+        MapEntry {
+            source_start: self.target_start,
+            target_start: self.source_start,
+            range_length: self.range_length,
+        }
+    }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct Map(BTreeSet<MapEntry>);
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<MapEntry>")
+)]
+pub struct Map(BTreeSet<MapEntry>);
 
 impl Map {
-    fn map(&self, source: u32) -> u32 {
+    pub fn map(&self, source: u64) -> u64 {
         self.0
             .iter()
             .find_map(|entry| entry.try_match(source))
             .unwrap_or(source)
     }
 
-    fn map_range(&self, range: Range<u32>) -> Vec<Range<u32>> {
+    /// The map sending every target back to its source. It is only the inverse of this map if the
+    /// targets of the entries cover exactly the same numbers as their sources, as in the puzzle input.
+    pub fn invert(&self) -> Self {
+        self.0.iter().map(MapEntry::invert).collect()
+    }
+
+    pub fn map_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
         let mut result = vec![];
 
         let mut current = range.start;
@@ -177,9 +626,8 @@ impl Map {
                     let start = entry.map(current);
                     let length = (entry.range_length - (current - entry.source_start))
                         .min(range.end - current);
-                    let end = start.saturating_add(length);
-                    result.push(start..end);
-                    current = current.saturating_add(length);
+                    result.push(start..start + length);
+                    current += length;
                 }
                 _ => {
                     result.push(current..range.end);
@@ -192,150 +640,94 @@ impl Map {
     }
 }
 
-impl<I: IntoIterator<Item = String>> From<I> for Map {
-    fn from(value: I) -> Self {
-        Map(value
+impl FromIterator<MapEntry> for Map {
+    fn from_iter<T: IntoIterator<Item = MapEntry>>(entries: T) -> Self {
+        Map(entries
             .into_iter()
-            .map(|entry: String| {
-                let numbers = entry
-                    .split(' ')
-                    .map(|s| s.parse().unwrap())
-                    .collect::<Vec<u32>>();
-                MapEntry {
-                    source_start: numbers[1],
-                    target_start: numbers[0],
-                    range_length: numbers[2],
-                }
-            })
+            .filter(|entry| entry.range_length > 0)
             .collect())
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    fn example_seed_to_soil_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-50 98 2
-52 50 48
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
+impl From<Vec<MapEntry>> for Map {
+    fn from(entries: Vec<MapEntry>) -> Self {
+        entries.into_iter().collect()
     }
+}
 
-    fn example_soil_to_fertilizer_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-0 15 37
-37 52 2
-39 0 15
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
-    }
+impl FromStr for Map {
+    type Err = String;
 
-    fn example_fertilizer_to_water_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        non_empty_lines(input).map(str::parse).collect()
     }
+}
 
-    fn example_water_to_light_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-88 18 7
-18 25 70
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
+impl FromStr for MapEntry {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let numbers = line
+            .split(' ')
+            .map(str::parse)
+            .collect::<Result<Vec<u64>, _>>()
+            .map_err(|_| format!("Invalid map entry: {line}"))?;
+        let numbers: [u64; 3] = numbers
+            .try_into()
+            .map_err(|_| format!("Invalid map entry: {line}"))?;
+        numbers
+            .try_into()
+            .map_err(|_: String| format!("Map entry overflows: {line}"))
     }
+}
 
-    fn example_light_to_temperature_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-45 77 23
-81 45 19
-68 64 13
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
+impl TryFrom<[u64; 3]> for MapEntry {
+    type Error = String;
+
+    fn try_from([target_start, source_start, range_length]: [u64; 3]) -> Result<Self, Self::Error> {
+        if source_start.checked_add(range_length).is_none()
+            || target_start.checked_add(range_length).is_none()
+        {
+            return Err(format!(
+                "Map entry overflows: {target_start} {source_start} {range_length}"
+            ));
+        }
+        Ok(MapEntry {
+            source_start,
+            target_start,
+            range_length,
         })
     }
+}
 
-    fn example_temperature_to_humidity_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-0 69 1
-1 0 69
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
+impl From<MapEntry> for [u64; 3] {
+    fn from(entry: MapEntry) -> Self {
+        [entry.target_start, entry.source_start, entry.range_length]
     }
+}
 
-    fn example_humidity_to_location_map() -> &'static Map {
-        static MAP: OnceLock<Map> = OnceLock::new();
-        MAP.get_or_init(|| {
-            Map::from(
-                read_lines(
-                    b"
-60 56 37
-56 93 4
-"
-                    .as_slice(),
-                )
-                .filter_not_empty(),
-            )
-        })
+#[cfg(test)]
+mod test {
+    use aoc_utils::random::Rng;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn example_maps() -> Vec<Map> {
+        seeds_and_location_maps(EXAMPLE).unwrap().1
     }
 
     #[test]
     fn parse_example() {
+        let almanac = EXAMPLE.parse::<Almanac>().unwrap();
+
+        assert_eq!(almanac.seeds(), [79, 14, 55, 13]);
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(almanac.maps[0].source, "seed");
+        assert_eq!(almanac.maps[0].destination, "soil");
         assert_eq!(
-            example_seed_to_soil_map(),
-            &Map(BTreeSet::from([
+            almanac.maps[0].map,
+            Map(BTreeSet::from([
                 MapEntry {
                     source_start: 50,
                     target_start: 52,
@@ -350,57 +742,310 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_almanac_should_reject_entries_outside_of_a_map() {
+        assert_eq!(
+            "seeds: 79 14\n50 98 2".parse::<Almanac>(),
+            Err("Map entry outside of a map: 50 98 2".to_string()),
+        );
+    }
+
+    #[test]
+    fn parse_almanac_should_reject_unnamed_maps() {
+        assert_eq!(
+            "seeds: 79 14\nsoil map:\n50 98 2".parse::<Almanac>(),
+            Err("Invalid map header: soil map:".to_string()),
+        );
+    }
+
+    #[test]
+    fn convert_should_follow_the_category_names() {
+        let almanac = EXAMPLE.parse::<Almanac>().unwrap();
+
+        assert_eq!(almanac.convert("seed", "soil", 79), Ok(81));
+        assert_eq!(almanac.convert("seed", "temperature", 79), Ok(78));
+        assert_eq!(almanac.convert("light", "location", 74), Ok(82));
+        assert_eq!(almanac.convert("seed", "seed", 79), Ok(79));
+    }
+
+    #[test]
+    fn convert_should_not_depend_on_the_order_of_the_maps() {
+        let almanac = "seeds: 1\n\nsoil-to-location map:\n10 0 5\n\nseed-to-soil map:\n0 1 1"
+            .parse::<Almanac>()
+            .unwrap();
+
+        assert_eq!(almanac.convert("seed", "location", 1), Ok(10));
+    }
+
+    #[test]
+    fn convert_should_fail_without_a_path_between_the_categories() {
+        let almanac = EXAMPLE.parse::<Almanac>().unwrap();
+
+        assert_eq!(
+            almanac.convert("soil", "seed", 81),
+            Err("No location-to-* map".to_string()),
+        );
+        let cycle = "seeds: 1\na-to-b map:\nb-to-a map:"
+            .parse::<Almanac>()
+            .unwrap();
+        assert_eq!(
+            cycle.convert("a", "c", 1),
+            Err("No conversion from a to c".to_string()),
+        );
+    }
+
+    #[test]
+    fn parse_map_with_invalid_entry_should_fail() {
+        assert!("50 98".parse::<Map>().is_err());
+        assert!("50 98 2 1".parse::<Map>().is_err());
+        assert!("50 98 18446744073709551616".parse::<Map>().is_err());
+    }
+
+    #[test]
+    fn parse_map_with_overflowing_entry_should_fail() {
+        assert_eq!(
+            "50 18446744073709551610 10".parse::<Map>(),
+            Err("Map entry overflows: 50 18446744073709551610 10".to_string()),
+        );
+        assert_eq!(
+            "18446744073709551610 50 10".parse::<Map>(),
+            Err("Map entry overflows: 18446744073709551610 50 10".to_string()),
+        );
+    }
+
+    #[test]
+    fn map_should_handle_entries_ending_at_the_32_bits_boundary() {
+        let map: Map = "4180398060 2019002186 114569236".parse().unwrap();
+
+        assert_eq!(map.map(2133571421), 4294967295);
+        assert_eq!(
+            map.map_range(2019002186..2133571422),
+            vec![4180398060..4294967296],
+        );
+    }
+
     #[test]
     fn mapping_seed_79_to_soil_should_return_81() {
-        assert_eq!(example_seed_to_soil_map().map(79), 81);
+        assert_eq!(example_maps()[0].map(79), 81);
+    }
+
+    #[test]
+    fn invert_should_swap_sources_and_targets() {
+        let map: Map = "50 98 2\n52 50 48".parse().unwrap();
+
+        assert_eq!(map.invert(), "98 50 2\n50 52 48".parse().unwrap());
+        assert_eq!(map.invert().map(81), 79);
+    }
+
+    #[test]
+    fn seed_for_location_should_reverse_all_the_maps() {
+        let maps = example_maps();
+
+        for seed in [79, 14, 55, 13] {
+            assert_eq!(seed_for_location(&maps, map_all(&maps, seed)), seed);
+        }
+    }
+
+    #[test]
+    fn map_tracked_range_all_should_keep_the_source_of_each_range() {
+        let map: Map = "200 50 10".parse().unwrap();
+
+        assert_eq!(
+            map_tracked_range_all(&[map], vec![40..70, 80..90]),
+            vec![
+                TrackedRange {
+                    source: 40..50,
+                    mapped: 40..50,
+                },
+                TrackedRange {
+                    source: 50..60,
+                    mapped: 200..210,
+                },
+                TrackedRange {
+                    source: 60..70,
+                    mapped: 60..70,
+                },
+                TrackedRange {
+                    source: 80..90,
+                    mapped: 80..90,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn lowest_location_seed_of_example_should_be_82() {
+        assert_eq!(
+            lowest_location_seed(EXAMPLE),
+            Ok(SeedLocation {
+                seed: 82,
+                location: 46,
+            }),
+        );
+        assert_eq!(map_all(&example_maps(), 82), 46);
+    }
+
+    #[test]
+    fn trace_should_give_the_value_at_every_stage() {
+        let almanac = EXAMPLE.parse::<Almanac>().unwrap();
+
+        assert_eq!(
+            almanac.trace(79),
+            vec![
+                ("seed", 79),
+                ("soil", 81),
+                ("fertilizer", 81),
+                ("water", 81),
+                ("light", 74),
+                ("temperature", 78),
+                ("humidity", 78),
+                ("location", 82),
+            ],
+        );
+    }
+
+    #[test]
+    fn trace_should_stop_on_cycles() {
+        let almanac = "seeds: 1\nseed-to-soil map:\nsoil-to-seed map:"
+            .parse::<Almanac>()
+            .unwrap();
+
+        assert_eq!(
+            almanac.trace(1),
+            vec![("seed", 1), ("soil", 1), ("seed", 1)]
+        );
+    }
+
+    #[test]
+    fn explain_part_one_should_trace_every_seed() {
+        let explanation = Day5.explain(1, EXAMPLE);
+
+        assert_eq!(explanation.len(), 4);
+        assert_eq!(
+            explanation[3],
+            "seed 13 → soil 13 → fertilizer 52 → water 41 → light 34 → temperature 34 → humidity 35 → location 35",
+        );
+    }
+
+    #[test]
+    fn explain_part_two_should_report_the_winning_seed() {
+        assert_eq!(
+            Day5.explain(2, EXAMPLE),
+            vec!["Seed 82 reaches the lowest location 46"],
+        );
+    }
+
+    #[test]
+    fn both_algorithms_should_agree_on_example() {
+        assert_eq!(
+            lowest_location_with(EXAMPLE, Algorithm::RangeSplitting),
+            Ok(46)
+        );
+        assert_eq!(
+            lowest_location_with(EXAMPLE, Algorithm::AscendingLocations),
+            Ok(46),
+        );
+    }
+
+    #[test]
+    fn both_algorithms_should_agree_on_swapped_blocks() {
+        let input = "seeds: 0 5 25 10\n\nseed-to-location map:\n20 0 10\n0 20 10";
+
+        assert_eq!(
+            lowest_location_with(input, Algorithm::RangeSplitting),
+            Ok(5)
+        );
+        assert_eq!(
+            lowest_location_with(input, Algorithm::AscendingLocations),
+            Ok(5),
+        );
+    }
+
+    #[test]
+    fn ascending_locations_should_fail_without_seeds() {
+        assert_eq!(
+            lowest_location_with(
+                "seeds: 5 0\nseed-to-location map:",
+                Algorithm::AscendingLocations
+            ),
+            Err("No seeds".to_string()),
+        );
+    }
+
+    #[test]
+    fn map_range_stages_should_split_and_shift_ranges() {
+        let stages = map_range_stages(&example_maps(), vec![79..93, 55..68]);
+
+        assert_eq!(stages.len(), 7);
+        assert_eq!(stages[0].len(), 2);
+        assert_eq!(stages[0][0].source, 55..68);
+        assert_eq!(stages[0][0].targets, vec![57..70]);
+        assert_eq!(stages[0][1].source, 79..93);
+        assert_eq!(stages[0][1].targets, vec![81..95]);
+        assert_eq!(
+            normalize(
+                stages[6]
+                    .iter()
+                    .flat_map(|mapping| mapping.targets.clone())
+                    .collect()
+            ),
+            map_range_all(&example_maps(), vec![79..93, 55..68]),
+        );
+    }
+
+    #[test]
+    fn visualize_should_end_with_the_lowest_location() {
+        let output = Day5.visualize(EXAMPLE).unwrap();
+
+        assert!(output.starts_with("Seeds: 55..68, 79..93\nMap 1\n  55..68 → 57..70\n"));
+        assert!(output.ends_with("Lowest location: 46\n"));
     }
 
     #[test]
     fn map_single_range_before() {
-        let map = Map::from(["200 50 10".into()]);
+        let map: Map = "200 50 10".parse().unwrap();
 
         assert_eq!(map.map_range(60..80), vec![60..80]);
     }
 
     #[test]
     fn map_single_range_after() {
-        let map = Map::from(["200 50 10".into()]);
+        let map: Map = "200 50 10".parse().unwrap();
 
         assert_eq!(map.map_range(40..50), vec![40..50]);
     }
 
     #[test]
     fn map_single_range_around() {
-        let map = Map::from(["200 50 10".into()]);
+        let map: Map = "200 50 10".parse().unwrap();
 
         assert_eq!(map.map_range(50..60), vec![200..210]);
     }
 
     #[test]
     fn map_single_range_inside() {
-        let map = Map::from(["200 50 10".into()]);
+        let map: Map = "200 50 10".parse().unwrap();
 
         assert_eq!(map.map_range(40..70), vec![40..50, 200..210, 60..70]);
     }
 
     #[test]
     fn map_single_range_intersecting() {
-        let map = Map::from(["200 50 10".into()]);
+        let map: Map = "200 50 10".parse().unwrap();
 
         assert_eq!(map.map_range(55..500), vec![205..210, 60..500]);
     }
 
+    #[test]
+    fn map_range_should_ignore_empty_entries() {
+        let map: Map = "300 50 0\n200 50 10".parse().unwrap();
+
+        assert_eq!(map.map_range(40..70), vec![40..50, 200..210, 60..70]);
+    }
+
     #[test]
     fn part2_example() {
-        let maps = &[
-            example_seed_to_soil_map(),
-            example_soil_to_fertilizer_map(),
-            example_fertilizer_to_water_map(),
-            example_water_to_light_map(),
-            example_light_to_temperature_map(),
-            example_temperature_to_humidity_map(),
-            example_humidity_to_location_map(),
-        ];
+        let maps = &example_maps();
         let seed_ranges = vec![79..(79 + 14), 55..(55 + 13)];
 
         let min_location = map_range_all(maps, seed_ranges)
@@ -411,4 +1056,132 @@ mod test {
 
         assert_eq!(min_location, 46);
     }
+
+    #[test]
+    fn normalize_should_merge_overlapping_and_adjacent_ranges() {
+        assert_eq!(
+            normalize(vec![20..30, 5..10, 0..5, 25..40, 50..60, 55..58, 70..70]),
+            vec![0..10, 20..40, 50..60],
+        );
+    }
+
+    #[test]
+    fn normalize_should_keep_disjoint_ranges() {
+        assert_eq!(normalize(vec![10..20, 0..5]), vec![0..5, 10..20]);
+    }
+
+    fn map_range_all_without_normalization(
+        maps: &[Map],
+        ranges: Vec<Range<u64>>,
+    ) -> Vec<Range<u64>> {
+        maps.iter().fold(ranges, |ranges, map| {
+            ranges
+                .into_iter()
+                .flat_map(|range| map.map_range(range))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn map_range_all_should_match_unnormalized_mapping_for_example() {
+        let maps = &example_maps();
+        let seed_ranges = vec![79..(79 + 14), 55..(55 + 13)];
+
+        assert_eq!(
+            map_range_all(maps, seed_ranges.clone()),
+            normalize(map_range_all_without_normalization(maps, seed_ranges)),
+        );
+    }
+
+    fn arbitrary_map() -> impl Strategy<Value = Map> {
+        prop::collection::vec(
+            (
+                0u64..1 << 24,
+                prop_oneof![0u64..4, 1u64..1 << 24],
+                0u64..1 << 30,
+            ),
+            0..8,
+        )
+        .prop_map(|entries| {
+            let mut source_start = 0;
+            entries
+                .into_iter()
+                .map(|(gap, range_length, target_start)| {
+                    source_start += gap;
+                    let entry = MapEntry {
+                        source_start,
+                        target_start,
+                        range_length,
+                    };
+                    source_start += range_length;
+                    entry
+                })
+                .collect()
+        })
+    }
+
+    fn arbitrary_range() -> impl Strategy<Value = Range<u64>> {
+        (0u64..1 << 28, 0u64..1 << 26).prop_map(|(start, length)| start..start + length)
+    }
+
+    fn entry_containing(map: &Map, source: u64) -> Option<&MapEntry> {
+        map.0.iter().find(|entry| entry.matches(source))
+    }
+
+    proptest! {
+        #[test]
+        fn map_range_should_return_non_empty_ranges(map in arbitrary_map(), range in arbitrary_range()) {
+            for mapped in map.map_range(range) {
+                prop_assert!(!mapped.is_empty(), "{mapped:?} is empty");
+            }
+        }
+
+        #[test]
+        fn map_range_should_preserve_total_length(map in arbitrary_map(), range in arbitrary_range()) {
+            let length = range.end - range.start;
+
+            let mapped_length = map.map_range(range).iter().map(|mapped| mapped.end - mapped.start).sum::<u64>();
+
+            prop_assert_eq!(mapped_length, length);
+        }
+
+        #[test]
+        fn map_range_domains_should_cover_range_exactly(map in arbitrary_map(), range in arbitrary_range()) {
+            let mut current = range.start;
+
+            for mapped in map.map_range(range.clone()) {
+                let last = mapped.end - mapped.start + current - 1;
+                prop_assert_eq!(entry_containing(&map, current), entry_containing(&map, last));
+                prop_assert_eq!(map.map(current), mapped.start);
+                prop_assert_eq!(map.map(last), mapped.end - 1);
+                current = last + 1;
+            }
+
+            prop_assert_eq!(current, range.end);
+        }
+
+        #[test]
+        fn map_range_all_should_match_unnormalized_mapping(
+            maps in prop::collection::vec(arbitrary_map(), 1..4),
+            ranges in prop::collection::vec(arbitrary_range(), 0..6),
+        ) {
+            prop_assert_eq!(
+                map_range_all(&maps, ranges.clone()),
+                normalize(map_range_all_without_normalization(&maps, ranges)),
+            );
+        }
+    }
+
+    #[test]
+    fn generated_almanac_should_have_bijective_maps() {
+        let almanac = random_almanac(20, &mut Rng::new(5));
+        let (seeds, maps) = seeds_and_location_maps(&almanac).unwrap();
+
+        assert_eq!(seeds.len(), 40);
+        assert!(seeds
+            .iter()
+            .all(|seed| seed_for_location(&maps, map_all(&maps, *seed)) == *seed));
+        assert!(lowest_location_with_ranges(&almanac)
+            .is_ok_and(|location| location <= lowest_location(&almanac).unwrap()));
+    }
 }
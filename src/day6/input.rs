@@ -0,0 +1,3 @@
+pub const INPUT: &str = "Time:        50     74     86     85
+Distance:   242   1017   1691   1252
+";
@@ -1,81 +1,289 @@
-use crate::Solution;
+use itertools::Itertools;
 
-static RACES: [Race; 4] = [
-    Race {
-        time: 50,
-        record: 242,
-    },
-    Race {
-        time: 74,
-        record: 1017,
-    },
-    Race {
-        time: 86,
-        record: 1691,
-    },
-    Race {
-        time: 85,
-        record: 1252,
-    },
-];
+use aoc_utils::checked::CheckedIterator;
+use aoc_utils::Progress;
+
+use crate::{Example, Label, Solution};
 
-static RACE: Race = Race {
-    time: 50748685,
-    record: 242101716911252,
-};
+mod input;
 
 pub struct Day6;
 
+/// How many hold times are tried between two reports of progress.
+const REPORT_EVERY: u64 = 1 << 20;
+
+const EXAMPLE: &str = "
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "ways-to-win-product",
+        text: "Product of all ways to win races",
+    },
+    Label {
+        key: "ways-to-win",
+        text: "Ways to win the race",
+    },
+];
+
 impl Solution for Day6 {
     fn day(&self) -> u8 {
         6
     }
 
-    fn part_one(&self) -> String {
-        format!(
-            "Product of all ways to win races: {}",
-            ways_to_win_product(&RACES),
-        )
+    fn title(&self) -> Option<&'static str> {
+        Some("Wait For It")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["1731600", "40087680"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let product = product_of_ways_to_win(input)?;
+        Ok(ANSWER_LABELS[0].answer(product))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        self.solve_part_reporting(2, input, &Progress::default())
+    }
+
+    fn solve_part_reporting(
+        &self,
+        part: u8,
+        input: &str,
+        progress: &Progress,
+    ) -> Result<String, String> {
+        match part {
+            2 => Ok(ANSWER_LABELS[1]
+                .answer(parse_race_ignoring_spaces(input)?.ways_to_win_count_reporting(progress))),
+            _ => self.solve_part(part, input),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Product of all ways to win races: 288",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Ways to win the race: 71503",
+            },
+        ]
     }
 
-    fn part_two(&self) -> String {
-        format!("Ways to win the race: {}", RACE.ways_to_win_count())
+    fn visualize(&self, input: &str) -> Result<String, String> {
+        let mut races = parse_races(input)?;
+        races.push(parse_race_ignoring_spaces(input)?);
+        Ok(races.iter().map(Race::plot).join("\n"))
     }
 }
 
-fn ways_to_win_product(races: &[Race]) -> u64 {
-    races.iter().map(Race::ways_to_win_count).product()
+pub fn product_of_ways_to_win(input: &str) -> Result<u64, String> {
+    ways_to_win_product(&parse_races(input)?)
+        .ok_or_else(|| "Product of ways to win overflows".to_string())
 }
 
-struct Race {
+pub fn ways_to_win_long_race(input: &str) -> Result<u64, String> {
+    Ok(parse_race_ignoring_spaces(input)?.ways_to_win_count())
+}
+
+/// How to read the spaces between the numbers of the race sheet.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Kerning {
+    /// Spaces separate the races, as in part one.
+    Spaces,
+    /// Spaces are just bad kerning and the sheet describes a single race, as in part two.
+    Ignored,
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse_race_sheet(input: &str, kerning: Kerning) -> Result<Vec<Race>, String> {
+    let times = race_sheet_line(input, "Time:", kerning)?;
+    let records = race_sheet_line(input, "Distance:", kerning)?;
+    if times.len() != records.len() {
+        return Err("Times and distances do not match".to_string());
+    }
+    times
+        .into_iter()
+        .zip(records)
+        .map(|(time, record)| {
+            Ok(Race {
+                time: parse_number(&time)?,
+                record: parse_number(&record)?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_races(input: &str) -> Result<Vec<Race>, String> {
+    parse_race_sheet(input, Kerning::Spaces)
+}
+
+pub fn parse_race_ignoring_spaces(input: &str) -> Result<Race, String> {
+    Ok(parse_race_sheet(input, Kerning::Ignored)?[0])
+}
+
+fn race_sheet_line(input: &str, label: &str, kerning: Kerning) -> Result<Vec<String>, String> {
+    let numbers = input
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(label))
+        .ok_or_else(|| format!("Missing {label} line"))?
+        .split_whitespace();
+    Ok(match kerning {
+        Kerning::Spaces => numbers.map(str::to_string).collect(),
+        Kerning::Ignored => vec![numbers.collect()],
+    })
+}
+
+fn parse_number(number: &str) -> Result<u64, String> {
+    number
+        .parse()
+        .map_err(|_| format!("Invalid number: {number}"))
+}
+
+pub fn ways_to_win_product(races: &[Race]) -> Option<u64> {
+    races.iter().map(Race::ways_to_win_count).checked_product()
+}
+
+/// The product of the ways to win each race, saturating at `u64::MAX`.
+pub fn margin_of_error(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(Race::ways_to_win_count)
+        .fold(1, u64::saturating_mul)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Race {
     time: u64,
     record: u64,
 }
 
 impl Race {
-    fn hold(&self, hold_time: u64) -> u64 {
-        hold_time * (self.time - hold_time)
+    pub fn new(time: u64, record: u64) -> Self {
+        Self { time, record }
+    }
+
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn record(&self) -> u64 {
+        self.record
+    }
+
+    fn hold(&self, hold_time: u64) -> u128 {
+        hold_time as u128 * (self.time - hold_time) as u128
+    }
+
+    fn beats_record(&self, hold_time: u64) -> bool {
+        self.hold(hold_time) > self.record as u128
     }
 
     fn min_hold_time(&self) -> Option<u64> {
-        (1..self.time).find(|h| self.hold(*h) > self.record)
+        self.first_winning_hold(1..self.time, 0, &Progress::default())
     }
 
     fn max_hold_time(&self) -> Option<u64> {
-        (1..self.time).rev().find(|h| self.hold(*h) > self.record)
+        self.first_winning_hold((1..self.time).rev(), 0, &Progress::default())
+    }
+
+    /// The first of the hold times that beats the record. The hold times tried are reported out of the duration of the
+    /// race, after the `tried` ones of a previous search.
+    fn first_winning_hold(
+        &self,
+        holds: impl Iterator<Item = u64>,
+        tried: u64,
+        progress: &Progress,
+    ) -> Option<u64> {
+        holds
+            .zip(tried + 1..)
+            .find(|(hold, count)| {
+                if count % REPORT_EVERY == 0 {
+                    progress.report(*count, self.time);
+                }
+                self.beats_record(*hold)
+            })
+            .map(|(hold, _)| hold)
     }
 
-    fn ways_to_win_count(&self) -> u64 {
-        if let Some((min, max)) = self.min_hold_time().zip(self.max_hold_time()) {
-            max - min + 1
-        } else {
-            0
+    /// Plots the distance against the hold time, with the record marked, a row per sampled hold time.
+    pub fn plot(&self) -> String {
+        const WIDTH: usize = 50;
+        const MAX_ROWS: u64 = 30;
+
+        let longest = self.hold(self.time / 2).max(1);
+        let column = |distance: u128| {
+            ((distance as f64 / longest as f64 * WIDTH as f64) as usize).min(WIDTH)
+        };
+        let record = column(self.record as u128);
+        let step = self.time / MAX_ROWS + 1;
+        let hold_width = self.time.to_string().len();
+        let distance_width = longest.to_string().len();
+
+        let mut plot = format!("Race of {}ms, record {}mm\n", self.time, self.record);
+        for hold_time in (0..=self.time).step_by(step as usize) {
+            let distance = self.hold(hold_time);
+            let mut bar = vec![' '; WIDTH + 1];
+            bar[..column(distance)].fill('█');
+            bar[record] = '│';
+            let marker = if self.beats_record(hold_time) {
+                '*'
+            } else {
+                ' '
+            };
+            let line = format!(
+                "{marker} {hold_time:>hold_width$} {distance:>distance_width$} {}",
+                bar.iter().collect::<String>(),
+            );
+            plot.push_str(line.trim_end());
+            plot.push('\n');
+        }
+        match self.min_hold_time().zip(self.max_hold_time()) {
+            Some((min, max)) => plot.push_str(&format!(
+                "Wins when holding from {min}ms to {max}ms: {} ways\n",
+                self.ways_to_win_count(),
+            )),
+            None => plot.push_str("The record cannot be beaten\n"),
         }
+        plot
+    }
+
+    pub fn ways_to_win_count(&self) -> u64 {
+        self.ways_to_win_count_reporting(&Progress::default())
+    }
+
+    /// Same as [`Race::ways_to_win_count`], reporting the hold times tried, from both ends of the race.
+    pub fn ways_to_win_count_reporting(&self, progress: &Progress) -> u64 {
+        let Some(min) = self.first_winning_hold(1..self.time, 0, progress) else {
+            return 0;
+        };
+        let max = self
+            .first_winning_hold((1..self.time).rev(), min, progress)
+            .unwrap_or(min);
+        max - min + 1
     }
 }
 
 #[cfg(test)]
 mod test {
+    use insta::assert_snapshot;
+
     use super::*;
 
     static EXAMPLE_RACES: [Race; 3] = [
@@ -95,6 +303,47 @@ mod test {
         record: 940200,
     };
 
+    #[test]
+    fn parse_races_of_example() {
+        assert_eq!(parse_races(EXAMPLE), Ok(EXAMPLE_RACES.to_vec()));
+    }
+
+    #[test]
+    fn parse_race_ignoring_spaces_of_example() {
+        assert_eq!(parse_race_ignoring_spaces(EXAMPLE), Ok(EXAMPLE_RACE));
+    }
+
+    #[test]
+    fn both_parts_should_be_two_parses_of_the_same_sheet() {
+        assert_eq!(
+            parse_race_sheet(EXAMPLE, Kerning::Spaces),
+            Ok(EXAMPLE_RACES.to_vec())
+        );
+        assert_eq!(
+            parse_race_sheet(EXAMPLE, Kerning::Ignored),
+            Ok(vec![EXAMPLE_RACE])
+        );
+    }
+
+    #[test]
+    fn parse_race_sheet_should_reject_concatenations_that_overflow() {
+        assert_eq!(
+            parse_race_sheet(
+                "Time: 99999999999 99999999999\nDistance: 1 2",
+                Kerning::Ignored
+            ),
+            Err("Invalid number: 9999999999999999999999".to_string()),
+        );
+    }
+
+    #[test]
+    fn parse_races_should_reject_mismatched_lines() {
+        assert_eq!(
+            parse_races("Time: 7 15\nDistance: 9"),
+            Err("Times and distances do not match".to_string()),
+        );
+    }
+
     #[test]
     fn min_hold_time_should_return_2_for_example_1() {
         assert_eq!(EXAMPLE_RACES[0].min_hold_time(), Some(2));
@@ -122,11 +371,97 @@ mod test {
 
     #[test]
     fn ways_to_win_product_should_return_288_for_example() {
-        assert_eq!(ways_to_win_product(&EXAMPLE_RACES), 288);
+        assert_eq!(ways_to_win_product(&EXAMPLE_RACES), Some(288));
+    }
+
+    #[test]
+    fn margin_of_error_should_accept_any_number_of_races() {
+        assert_eq!(margin_of_error(&EXAMPLE_RACES), 288);
+        assert_eq!(margin_of_error(&EXAMPLE_RACES[..1]), 4);
+        assert_eq!(margin_of_error(&[]), 1);
+        assert_eq!(
+            margin_of_error(&[EXAMPLE_RACES[0], EXAMPLE_RACE, EXAMPLE_RACE, EXAMPLE_RACE]),
+            4 * 71503 * 71503 * 71503,
+        );
+    }
+
+    #[test]
+    fn margin_of_error_should_saturate() {
+        let race = Race::new(u64::MAX, 0);
+
+        assert_eq!(margin_of_error(&[race, race]), u64::MAX);
     }
 
     #[test]
     fn ways_to_win_count_should_return_71503_for_example() {
         assert_eq!(EXAMPLE_RACE.ways_to_win_count(), 71503);
     }
+
+    #[test]
+    fn hold_should_not_overflow_for_the_longest_race() {
+        let race = Race {
+            time: u64::MAX,
+            record: 0,
+        };
+
+        assert_eq!(
+            race.hold(u64::MAX / 2),
+            (u64::MAX / 2) as u128 * (u64::MAX / 2 + 1) as u128
+        );
+    }
+
+    #[test]
+    fn ways_to_win_count_should_not_overflow_for_the_longest_race() {
+        let race = Race {
+            time: u64::MAX,
+            record: u64::MAX - 1,
+        };
+
+        assert_eq!(race.min_hold_time(), Some(2));
+        assert_eq!(race.max_hold_time(), Some(u64::MAX - 2));
+        assert_eq!(race.ways_to_win_count(), u64::MAX - 3);
+    }
+
+    #[test]
+    fn ways_to_win_count_should_report_the_hold_times_tried() {
+        let race = Race::new(8 * REPORT_EVERY, 3 * REPORT_EVERY * 5 * REPORT_EVERY);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let progress = Progress::new(move |done, total| sender.send((done, total)).unwrap());
+
+        assert_eq!(
+            race.ways_to_win_count_reporting(&progress),
+            2 * REPORT_EVERY - 1
+        );
+        drop(progress);
+
+        assert_eq!(
+            receiver.iter().collect::<Vec<_>>(),
+            (1..=6)
+                .map(|step| (step * REPORT_EVERY, 8 * REPORT_EVERY))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn ways_to_win_count_should_return_0_when_the_record_cannot_be_beaten() {
+        let race = Race { time: 2, record: 1 };
+
+        assert_eq!(race.ways_to_win_count(), 0);
+    }
+
+    #[test]
+    fn plot_should_mark_the_record_and_the_winning_hold_times() {
+        assert_snapshot!(EXAMPLE_RACES[0].plot(), @r"
+        Race of 7ms, record 9mm
+          0  0                                      │
+          1  6 █████████████████████████            │
+        * 2 10 █████████████████████████████████████│███
+        * 3 12 █████████████████████████████████████│████████████
+        * 4 12 █████████████████████████████████████│████████████
+        * 5 10 █████████████████████████████████████│███
+          6  6 █████████████████████████            │
+          7  0                                      │
+        Wins when holding from 2ms to 5ms: 4 ways
+        ");
+    }
 }
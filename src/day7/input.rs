@@ -1,4 +1,4 @@
-pub const INPUT: &[u8] = b"
+pub const INPUT: &str = "
 Q97J7 740
 65KJ6 889
 6664J 275
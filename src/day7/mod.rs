@@ -1,64 +1,289 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
-use std::sync::OnceLock;
 
-use nom::character::complete::{digit1, one_of, space1};
+use itertools::Itertools;
+use nom::character::complete::{anychar, digit1, space1};
+use nom::combinator::map_res;
 use nom::multi::fill;
 use nom::sequence::tuple;
 use nom::IResult;
 
-use crate::input::{read_lines, FilterNotEmpty, ParseExt};
-use crate::Solution;
+use aoc_utils::checked::CheckedIterator;
+use aoc_utils::input::parse_lines;
+use aoc_utils::random::Rng;
+
+use crate::{Example, Label, Solution, Variant};
 
 mod input;
 
-fn hands() -> &'static Vec<Hand> {
-    static HANDS: OnceLock<Vec<Hand>> = OnceLock::new();
-    HANDS.get_or_init(|| {
-        read_lines(input::INPUT)
-            .filter_not_empty()
-            .parse()
-            .collect()
-    })
-}
+pub struct Day7;
 
-fn hands_with_jokers() -> &'static Vec<Hand> {
-    static HANDS_WITH_JOKERS: OnceLock<Vec<Hand>> = OnceLock::new();
-    HANDS_WITH_JOKERS.get_or_init(|| hands().iter().map(|hand| hand.to_jokers()).collect())
-}
+const EXAMPLE: &str = "
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
 
-pub struct Day7;
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "total-winnings",
+        text: "Total winnings",
+    },
+    Label {
+        key: "total-winnings-with-jokers",
+        text: "Total winnings with jokers",
+    },
+];
 
 impl Solution for Day7 {
     fn day(&self) -> u8 {
         7
     }
 
-    fn part_one(&self) -> String {
-        format!("Total winnings: {}", total_winnings(hands()))
+    fn title(&self) -> Option<&'static str> {
+        Some("Camel Cards")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["250254244", "250087440"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let hands = parse_lines(input)?;
+        let winnings = total_winnings(&hands, &StandardRules).ok_or("Total winnings overflow")?;
+        Ok(ANSWER_LABELS[0].answer(winnings))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let winnings = winnings_with_jokers(input)?;
+        Ok(ANSWER_LABELS[1].answer(winnings))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_lines::<Hand>(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Total winnings: 6440",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Total winnings with jokers: 5905",
+            },
+        ]
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![
+            #[cfg(feature = "parallel")]
+            Variant {
+                name: "parallel",
+                part: 1,
+                solve: |input| {
+                    let hands = parse_lines::<Hand>(input)?;
+                    let winnings = total_winnings_par(&hands, &StandardRules)
+                        .ok_or("Total winnings overflow")?;
+                    Ok(ANSWER_LABELS[0].answer(winnings))
+                },
+            },
+            #[cfg(feature = "parallel")]
+            Variant {
+                name: "parallel",
+                part: 2,
+                solve: |input| {
+                    let hands = parse_lines::<Hand>(input)?;
+                    let winnings =
+                        total_winnings_par(&hands, &JokerRules).ok_or("Total winnings overflow")?;
+                    Ok(ANSWER_LABELS[1].answer(winnings))
+                },
+            },
+        ]
     }
 
-    fn part_two(&self) -> String {
-        format!(
-            "Total winnings with jokers: {}",
-            total_winnings(hands_with_jokers()),
-        )
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        random_hands(size, rng)
     }
+
+    /// The hands by rank, after a warning if some hands appear more than once, as they are then ranked by bid.
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        let hands = parse_lines::<Hand>(input).unwrap_or_default();
+        let table = match part {
+            1 => ranking_table(&hands, &StandardRules),
+            2 => ranking_table(&hands, &JokerRules),
+            _ => return Vec::new(),
+        };
+        let duplicates = duplicate_hands(&hands);
+        let warning = (!duplicates.is_empty()).then(|| {
+            format!(
+                "Warning: {} hands appear more than once, they are ranked by bid",
+                duplicates.len()
+            )
+        });
+        warning.into_iter().chain(table).collect()
+    }
+}
+
+fn ranking_table(hands: &[Hand], rules: &dyn RankingRules) -> Vec<String> {
+    let header = format!(
+        "{:>5}  {:<5}  {:<15}  {:>5}  {:>10}",
+        "Rank", "Hand", "Type", "Bid", "Winnings"
+    );
+    let rows = ranked(hands, rules)
+        .into_iter()
+        .enumerate()
+        .map(|(index, hand)| {
+            let rank = index + 1;
+            let cards = hand.cards.iter().join("");
+            let winnings = rank
+                .checked_mul(hand.bid)
+                .map_or_else(|| "overflow".to_string(), |winnings| winnings.to_string());
+            format!(
+                "{rank:>5}  {cards:<5}  {:<15}  {:>5}  {winnings:>10}",
+                rules.hand_type(&hand.cards),
+                hand.bid,
+            )
+        });
+    std::iter::once(header).chain(rows).collect()
 }
 
-fn total_winnings(hands: &[Hand]) -> usize {
+fn ranked<'a>(hands: &'a [Hand], rules: &dyn RankingRules) -> Vec<&'a Hand> {
     let mut hands: Vec<_> = hands.iter().collect();
-    hands.sort();
+    hands.sort_by_cached_key(|hand| rank_key(rules, hand));
     hands
+}
+
+fn rank_key(rules: &dyn RankingRules, hand: &Hand) -> (HandType, [u8; 5], usize) {
+    (
+        rules.hand_type(&hand.cards),
+        hand.cards.map(|card| rules.card_strength(card)),
+        hand.bid,
+    )
+}
+
+/// The labels of the cards, as they are written in the hands.
+const LABELS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// Distinct hands of random cards with bids of up to 1000, as in the puzzle input.
+fn random_hands(size: usize, rng: &mut Rng) -> Result<String, String> {
+    let distinct_hands = LABELS.len().pow(5);
+    if size > distinct_hands {
+        return Err(format!("There are only {distinct_hands} distinct hands"));
+    }
+    let mut hands = HashSet::new();
+    let mut lines = String::new();
+    while hands.len() < size {
+        let hand = (0..5).map(|_| *rng.choose(&LABELS)).collect::<String>();
+        if hands.insert(hand.clone()) {
+            lines.push_str(&format!("{hand} {}\n", rng.range(1..=1000)));
+        }
+    }
+    Ok(lines)
+}
+
+pub fn winnings(input: &str) -> Result<usize, String> {
+    total_winnings(&parse_lines(input)?, &StandardRules)
+        .ok_or_else(|| "Total winnings overflow".to_string())
+}
+
+pub fn winnings_with_jokers(input: &str) -> Result<usize, String> {
+    total_winnings(&parse_lines(input)?, &JokerRules)
+        .ok_or_else(|| "Total winnings overflow".to_string())
+}
+
+pub fn total_winnings(hands: &[Hand], rules: &dyn RankingRules) -> Option<usize> {
+    ranked(hands, rules)
         .into_iter()
         .enumerate()
-        .map(|(index, hand)| (index + 1) * hand.bid)
-        .sum()
+        .map(|(index, hand)| (index + 1).checked_mul(hand.bid))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .checked_sum()
+}
+
+/// Same as [`total_winnings`], with the hands classified and sorted in parallel.
+#[cfg(feature = "parallel")]
+pub fn total_winnings_par(hands: &[Hand], rules: &(dyn RankingRules + Sync)) -> Option<usize> {
+    use rayon::prelude::*;
+
+    let mut keys = hands
+        .par_iter()
+        .map(|hand| rank_key(rules, hand))
+        .collect::<Vec<_>>();
+    keys.par_sort_unstable();
+    keys.par_iter()
+        .enumerate()
+        .map(|(index, (_, _, bid))| (index + 1).checked_mul(*bid))
+        .try_reduce(|| 0, usize::checked_add)
+}
+
+fn duplicate_hands(hands: &[Hand]) -> Vec<[Card; 5]> {
+    hands.iter().map(|hand| hand.cards).duplicates().collect()
+}
+
+/// How hands are classified, and how their cards break ties between hands of the same type.
+pub trait RankingRules {
+    fn hand_type(&self, cards: &[Card; 5]) -> HandType;
+    fn card_strength(&self, card: Card) -> u8;
+}
+
+/// The rules of part one, where a joker card (see [`Hand::to_jokers`]) is still wild.
+pub struct StandardRules;
+
+impl RankingRules for StandardRules {
+    fn hand_type(&self, cards: &[Card; 5]) -> HandType {
+        get_hand_type(cards)
+    }
+
+    fn card_strength(&self, card: Card) -> u8 {
+        card as u8
+    }
+}
+
+/// The rules of part two, where jacks are jokers: wild, but the weakest card.
+pub struct JokerRules;
+
+impl JokerRules {
+    fn joker(card: Card) -> Card {
+        if card == Card::Jack {
+            Card::Joker
+        } else {
+            card
+        }
+    }
+}
+
+impl RankingRules for JokerRules {
+    fn hand_type(&self, cards: &[Card; 5]) -> HandType {
+        get_hand_type(&cards.map(Self::joker))
+    }
+
+    fn card_strength(&self, card: Card) -> u8 {
+        Self::joker(card) as u8
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum Card {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Card {
     Joker,
     Two,
     Three,
@@ -75,6 +300,27 @@ enum Card {
     Ace,
 }
 
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Self::Joker | Self::Jack => 'J',
+            Self::Two => '2',
+            Self::Three => '3',
+            Self::Four => '4',
+            Self::Five => '5',
+            Self::Six => '6',
+            Self::Seven => '7',
+            Self::Eight => '8',
+            Self::Nine => '9',
+            Self::Ten => 'T',
+            Self::Queen => 'Q',
+            Self::King => 'K',
+            Self::Ace => 'A',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
 impl TryFrom<char> for Card {
     type Error = String;
 
@@ -98,8 +344,9 @@ impl TryFrom<char> for Card {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-enum HandType {
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HandType {
     HighCard,
     OnePair,
     TwoPairs,
@@ -109,40 +356,63 @@ enum HandType {
     FiveOfAKind,
 }
 
+impl Display for HandType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::HighCard => "High Card",
+            Self::OnePair => "One Pair",
+            Self::TwoPairs => "Two Pairs",
+            Self::ThreeOfAKind => "Three of a Kind",
+            Self::FullHouse => "Full House",
+            Self::FourOfAKind => "Four of a Kind",
+            Self::FiveOfAKind => "Five of a Kind",
+        })
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Hand {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hand {
     cards: [Card; 5],
     bid: usize,
-    hand_type: HandType,
 }
 
 impl Hand {
     fn new(cards: [Card; 5], bid: usize) -> Self {
-        let hand_type = get_hand_type(&cards);
-        Self {
-            cards,
-            bid,
-            hand_type,
-        }
+        Self { cards, bid }
     }
 
-    fn to_jokers(self) -> Self {
-        let cards = self.cards.map(|card| {
-            if card == Card::Jack {
-                Card::Joker
-            } else {
-                card
-            }
-        });
-        Self::new(cards, self.bid)
+    pub fn cards(&self) -> [Card; 5] {
+        self.cards
+    }
+
+    pub fn bid(&self) -> usize {
+        self.bid
+    }
+
+    /// The type of the hand under the standard rules.
+    pub fn hand_type(&self) -> HandType {
+        StandardRules.hand_type(&self.cards)
+    }
+
+    pub fn to_jokers(self) -> Self {
+        Self::new(self.cards.map(JokerRules::joker), self.bid)
+    }
+}
+
+/// The cards, followed by the type under the standard rules and the bid: `KTJJT (Two Pairs, bid 220)`.
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for card in self.cards {
+            write!(f, "{card}")?;
+        }
+        write!(f, " ({}, bid {})", self.hand_type(), self.bid)
     }
 }
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.hand_type
-            .cmp(&other.hand_type)
-            .then_with(|| self.cards.cmp(&other.cards))
+        rank_key(&StandardRules, self).cmp(&rank_key(&StandardRules, other))
     }
 }
 
@@ -213,12 +483,16 @@ fn compare_combinations<'a>(
 
 fn parse_hand(input: &str) -> IResult<&str, Hand> {
     let mut cards = [Card::Two; 5];
-    let (input, (_, _, bid)) = tuple((fill(parse_card, &mut cards), space1, digit1))(input)?;
-    Ok((input, Hand::new(cards, bid.parse().unwrap())))
+    let (input, (_, _, bid)) = tuple((
+        fill(parse_card, &mut cards),
+        space1,
+        map_res(digit1, str::parse),
+    ))(input)?;
+    Ok((input, Hand::new(cards, bid)))
 }
 
 fn parse_card(input: &str) -> IResult<&str, Card> {
-    one_of("123456789TJQKA")(input).map(|(input, c)| (input, Card::try_from(c).unwrap()))
+    map_res(anychar, Card::try_from)(input)
 }
 
 #[cfg(test)]
@@ -228,66 +502,93 @@ mod test {
 
     use super::*;
 
-    const EXAMPLE: &[u8] = b"
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-";
-
-    fn example() -> &'static Vec<Hand> {
-        static HANDS: OnceLock<Vec<Hand>> = OnceLock::new();
-        HANDS.get_or_init(|| read_lines(EXAMPLE).filter_not_empty().parse().collect())
-    }
-
-    fn example_with_jokers() -> &'static Vec<Hand> {
-        static HANDS_WITH_JOKERS: OnceLock<Vec<Hand>> = OnceLock::new();
-        HANDS_WITH_JOKERS.get_or_init(|| example().iter().map(|hand| hand.to_jokers()).collect())
+    fn example() -> Vec<Hand> {
+        parse_lines(EXAMPLE).unwrap()
     }
 
     #[test]
     fn parse_example() {
         assert_eq!(
             example(),
-            &[
+            [
                 Hand {
                     cards: [Three, Two, Ten, Three, King],
                     bid: 765,
-                    hand_type: OnePair,
                 },
                 Hand {
                     cards: [Ten, Five, Five, Jack, Five],
                     bid: 684,
-                    hand_type: ThreeOfAKind,
                 },
                 Hand {
                     cards: [King, King, Six, Seven, Seven],
                     bid: 28,
-                    hand_type: TwoPairs,
                 },
                 Hand {
                     cards: [King, Ten, Jack, Jack, Ten],
                     bid: 220,
-                    hand_type: TwoPairs,
                 },
                 Hand {
                     cards: [Queen, Queen, Queen, Jack, Ace],
                     bid: 483,
-                    hand_type: ThreeOfAKind,
                 },
             ],
         );
+        assert_eq!(
+            example().iter().map(Hand::hand_type).collect::<Vec<_>>(),
+            [OnePair, ThreeOfAKind, TwoPairs, TwoPairs, ThreeOfAKind],
+        );
+    }
+
+    #[test]
+    fn parse_hand_with_invalid_card_should_fail() {
+        assert!("1KKK2 100".parse::<Hand>().is_err());
+    }
+
+    #[test]
+    fn parse_hand_with_overflowing_bid_should_fail() {
+        assert!("KKKK2 99999999999999999999".parse::<Hand>().is_err());
     }
 
     #[test]
     fn part1_example() {
-        assert_eq!(total_winnings(example()), 6440);
+        assert_eq!(total_winnings(&example(), &StandardRules), Some(6440));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(total_winnings(example_with_jokers()), 5905);
+        assert_eq!(total_winnings(&example(), &JokerRules), Some(5905));
+    }
+
+    #[test]
+    fn duplicate_hands_should_be_empty_for_example() {
+        assert!(duplicate_hands(&example()).is_empty());
+    }
+
+    #[test]
+    fn duplicate_hands_should_report_identical_hands_with_different_bids() {
+        let hands = parse_lines::<Hand>("KK677 28\n32T3K 765\nKK677 100").unwrap();
+
+        assert_eq!(
+            duplicate_hands(&hands),
+            vec![[King, King, Six, Seven, Seven]]
+        );
+    }
+
+    #[test]
+    fn identical_hands_should_be_ranked_by_bid() {
+        let hands = parse_lines::<Hand>("KK677 100\n32T3K 765\nKK677 28").unwrap();
+
+        assert_eq!(
+            total_winnings(&hands, &StandardRules),
+            Some(765 + 28 * 2 + 100 * 3)
+        );
+    }
+
+    #[test]
+    fn total_winnings_should_return_none_on_overflow() {
+        let hands = parse_lines::<Hand>(&format!("KK677 {}\n32T3K 2", usize::MAX)).unwrap();
+
+        assert_eq!(total_winnings(&hands, &StandardRules), None);
     }
 
     #[test]
@@ -295,56 +596,163 @@ QQQJA 483
         let hand1 = "JKKK2 100".parse::<Hand>().unwrap().to_jokers();
         let hand2 = "QQQQ2 100".parse::<Hand>().unwrap().to_jokers();
 
-        assert_eq!(hand1.hand_type, FourOfAKind);
-        assert_eq!(hand2.hand_type, FourOfAKind);
+        assert_eq!(hand1.hand_type(), FourOfAKind);
+        assert_eq!(hand2.hand_type(), FourOfAKind);
         assert!(hand1 < hand2);
     }
 
     #[test]
     fn test_jokers1() {
-        assert_eq!(
-            "Q97J7 740".parse::<Hand>().unwrap().to_jokers(),
-            Hand {
-                cards: [Queen, Nine, Seven, Joker, Seven],
-                bid: 740,
-                hand_type: ThreeOfAKind,
-            },
-        )
+        let hand = "Q97J7 740".parse::<Hand>().unwrap();
+
+        assert_eq!(hand.to_jokers().cards, [Queen, Nine, Seven, Joker, Seven]);
+        assert_eq!(JokerRules.hand_type(&hand.cards), ThreeOfAKind);
     }
 
     #[test]
     fn test_jokers2() {
-        assert_eq!(
-            "3JKKJ 832".parse::<Hand>().unwrap().to_jokers(),
-            Hand {
-                cards: [Three, Joker, King, King, Joker],
-                bid: 832,
-                hand_type: FourOfAKind,
-            },
-        )
+        let hand = "3JKKJ 832".parse::<Hand>().unwrap();
+
+        assert_eq!(hand.to_jokers().cards, [Three, Joker, King, King, Joker]);
+        assert_eq!(JokerRules.hand_type(&hand.cards), FourOfAKind);
     }
 
     #[test]
     fn test_jokers3() {
-        assert_eq!(
-            "J6AAJ 756".parse::<Hand>().unwrap().to_jokers(),
-            Hand {
-                cards: [Joker, Six, Ace, Ace, Joker],
-                bid: 756,
-                hand_type: FourOfAKind,
-            },
-        )
+        let hand = "J6AAJ 756".parse::<Hand>().unwrap();
+
+        assert_eq!(hand.to_jokers().cards, [Joker, Six, Ace, Ace, Joker]);
+        assert_eq!(JokerRules.hand_type(&hand.cards), FourOfAKind);
     }
 
     #[test]
     fn test_jokers4() {
+        let hand = "JJ22J 252".parse::<Hand>().unwrap();
+
+        assert_eq!(hand.to_jokers().cards, [Joker, Joker, Two, Two, Joker]);
+        assert_eq!(JokerRules.hand_type(&hand.cards), FiveOfAKind);
+    }
+
+    #[test]
+    fn display_should_show_cards_type_and_bid() {
+        let hand = "KTJJT 220".parse::<Hand>().unwrap();
+
+        assert_eq!(hand.to_string(), "KTJJT (Two Pairs, bid 220)");
         assert_eq!(
-            "JJ22J 252".parse::<Hand>().unwrap().to_jokers(),
-            Hand {
-                cards: [Joker, Joker, Two, Two, Joker],
-                bid: 252,
-                hand_type: FiveOfAKind,
-            },
-        )
+            hand.to_jokers().to_string(),
+            "KTJJT (Four of a Kind, bid 220)"
+        );
+    }
+
+    #[test]
+    fn hand_type_display_should_honor_the_width() {
+        assert_eq!(format!("[{:<10}]", OnePair), "[One Pair  ]");
+    }
+
+    #[test]
+    fn standard_rules_should_rank_jacks_above_tens() {
+        let hand = "KTJJT 220".parse::<Hand>().unwrap();
+
+        assert_eq!(StandardRules.hand_type(&hand.cards), TwoPairs);
+        assert!(StandardRules.card_strength(Jack) > StandardRules.card_strength(Ten));
+    }
+
+    #[test]
+    fn joker_rules_should_make_jacks_wild_but_weakest() {
+        let hand = "KTJJT 220".parse::<Hand>().unwrap();
+
+        assert_eq!(JokerRules.hand_type(&hand.cards), FourOfAKind);
+        assert!(JokerRules.card_strength(Jack) < JokerRules.card_strength(Two));
+    }
+
+    struct CardsOnlyRules;
+
+    impl RankingRules for CardsOnlyRules {
+        fn hand_type(&self, _cards: &[Card; 5]) -> HandType {
+            HighCard
+        }
+
+        fn card_strength(&self, card: Card) -> u8 {
+            card as u8
+        }
+    }
+
+    #[test]
+    fn total_winnings_should_accept_any_rules() {
+        assert_eq!(
+            total_winnings(&example(), &CardsOnlyRules),
+            Some(765 + 684 * 2 + 483 * 3 + 220 * 4 + 28 * 5),
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn total_winnings_par_should_match_total_winnings() {
+        let hands = (0..5000)
+            .map(|n: usize| {
+                let cards = (0..5)
+                    .map(|i| "23456789TJQKA".as_bytes()[(n * 7 + i * n / 3) % 13] as char)
+                    .collect::<String>();
+                format!("{cards} {}", n % 1000).parse::<Hand>().unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            total_winnings_par(&hands, &StandardRules),
+            total_winnings(&hands, &StandardRules),
+        );
+        assert_eq!(
+            total_winnings_par(&hands, &JokerRules),
+            total_winnings(&hands, &JokerRules),
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn total_winnings_par_should_return_none_on_overflow() {
+        let hands = parse_lines::<Hand>(&format!("KK677 {}\n32T3K 2", usize::MAX)).unwrap();
+
+        assert_eq!(total_winnings_par(&hands, &StandardRules), None);
+    }
+
+    #[test]
+    fn explain_should_print_hands_by_rank() {
+        assert_eq!(
+            Day7.explain(2, EXAMPLE),
+            vec![
+                " Rank  Hand   Type               Bid    Winnings",
+                "    1  32T3K  One Pair           765         765",
+                "    2  KK677  Two Pairs           28          56",
+                "    3  T55J5  Four of a Kind     684        2052",
+                "    4  QQQJA  Four of a Kind     483        1932",
+                "    5  KTJJT  Four of a Kind     220        1100",
+            ],
+        );
+    }
+
+    #[test]
+    fn explain_should_warn_about_identical_hands() {
+        assert_eq!(
+            Day7.explain(1, "KK677 100\n32T3K 765\nKK677 28"),
+            vec![
+                "Warning: 1 hands appear more than once, they are ranked by bid",
+                " Rank  Hand   Type               Bid    Winnings",
+                "    1  32T3K  One Pair           765         765",
+                "    2  KK677  Two Pairs           28          56",
+                "    3  KK677  Two Pairs          100         300",
+            ],
+        );
+    }
+
+    #[test]
+    fn generated_hands_should_be_distinct() {
+        let hands = parse_lines::<Hand>(&random_hands(500, &mut Rng::new(7)).unwrap()).unwrap();
+
+        assert_eq!(hands.len(), 500);
+        assert!(duplicate_hands(&hands).is_empty());
+        assert_eq!(
+            random_hands(400_000, &mut Rng::new(7)),
+            Err("There are only 371293 distinct hands".to_string())
+        );
     }
 }
@@ -1,135 +1,622 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use std::sync::OnceLock;
 
+use itertools::Itertools;
 use nom::bytes::complete::tag;
 use nom::character::complete::one_of;
 use nom::multi::fill;
 use nom::sequence::tuple;
 use nom::IResult;
 
-use crate::input::{read_lines, FilterNotEmpty, ParseExt};
-use crate::Solution;
+use aoc_utils::divisors::lcm;
+use aoc_utils::random::Rng;
+
+use crate::{Example, Label, Solution};
 
 mod input;
 
-fn instructions() -> &'static Vec<Instruction> {
-    static INSTRUCTIONS: OnceLock<Vec<Instruction>> = OnceLock::new();
-    INSTRUCTIONS.get_or_init(|| parse_instructions(input::INSTRUCTIONS).unwrap())
+pub type Network = (Vec<Instruction>, HashMap<NodeId, Node>);
+
+/// Parses the instructions, which may span several lines, then the nodes. Blank lines are ignored anywhere and errors
+/// on nodes give their line number.
+#[tracing::instrument(skip_all)]
+pub fn parse_network(input: &str) -> Result<Network, String> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .peekable();
+    let mut instructions = String::new();
+    while let Some((_, line)) = lines.next_if(|(_, line)| !line.contains('=')) {
+        instructions.push_str(line);
+    }
+    let instructions = parse_instructions(&instructions)?;
+    let nodes = lines
+        .map(|(number, line)| {
+            line.parse::<Node>()
+                .map(|node| (node.id, node))
+                .map_err(|error| format!("Line {number}: {error}"))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((instructions, nodes))
 }
 
-fn nodes() -> &'static HashMap<NodeId, Node> {
-    static NODES: OnceLock<HashMap<NodeId, Node>> = OnceLock::new();
-    NODES.get_or_init(|| {
-        read_lines(input::NODES)
-            .filter_not_empty()
-            .parse::<Node>()
-            .map(|node| (node.id, node))
-            .collect()
-    })
+pub fn parse_nodes<'a, I: IntoIterator<Item = &'a str>>(
+    lines: I,
+) -> Result<HashMap<NodeId, Node>, String> {
+    lines
+        .into_iter()
+        .map(|line| line.parse::<Node>().map(|node| (node.id, node)))
+        .collect()
 }
 
 pub struct Day8;
 
+const EXAMPLE1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const EXAMPLE2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "wasteland-steps",
+        text: "Steps to traverse wasteland",
+    },
+    Label {
+        key: "wasteland-ghost-steps",
+        text: "Steps to traverse wasteland as ghost",
+    },
+];
+
 impl Solution for Day8 {
     fn day(&self) -> u8 {
         8
     }
 
-    fn part_one(&self) -> String {
-        format!(
-            "Steps to traverse wasteland: {}",
-            traverse_wasteland(instructions(), nodes()),
-        )
+    fn title(&self) -> Option<&'static str> {
+        Some("Haunted Wasteland")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn known_answers(&self) -> &'static [&'static str] {
+        &["16531", "24035773251517"]
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        Ok(ANSWER_LABELS[0].answer(steps(input)?))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let steps = ghost_steps(input)?;
+        Ok(ANSWER_LABELS[1].answer(steps))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_network(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE1,
+                answer: "Steps to traverse wasteland: 2",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE2,
+                answer: "Steps to traverse wasteland as ghost: 6",
+            },
+        ]
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Result<String, String> {
+        random_network(size, rng)
     }
 
-    fn part_two(&self) -> String {
-        format!(
-            "Steps to traverse wasteland as ghost: {}",
-            traverse_wasteland_as_ghost(instructions(), nodes()),
-        )
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        if part != 2 {
+            return Vec::new();
+        }
+        match parse_network(input)
+            .and_then(|(instructions, nodes)| ghost_cycles(&instructions, &nodes))
+        {
+            Ok(cycles) => explain_ghost_cycles(&cycles),
+            Err(error) => vec![error],
+        }
     }
 }
 
-fn traverse_wasteland(instructions: &[Instruction], nodes: &HashMap<NodeId, Node>) -> usize {
-    traverse_wasteland_from(instructions, nodes, [b'A', b'A', b'A'], |id| {
-        id == &[b'Z', b'Z', b'Z']
+fn explain_ghost_cycles(cycles: &[GhostCycle]) -> Vec<String> {
+    let mut lines = cycles
+        .iter()
+        .map(|cycle| {
+            format!(
+                "{}: cycle of {} steps starting at step {}, end nodes at steps {:?}",
+                String::from_utf8_lossy(&cycle.start),
+                cycle.period,
+                cycle.offset,
+                cycle.end_steps,
+            )
+        })
+        .collect::<Vec<_>>();
+    lines.push(if cycles.iter().all(GhostCycle::fits_lcm) {
+        "The LCM shortcut holds: every ghost is on an end node at the multiples of its first arrival"
+    } else {
+        "The LCM shortcut does not hold: some ghosts are on end nodes at irregular steps"
+    }
+    .to_string());
+    lines
+}
+
+/// The number of names that neither end with `A` nor with `Z`, which bounds the size of the generated networks.
+const INNER_NAMES: usize = 26 * 26 * 24;
+
+/// A network of up to six ghosts, with about `size` nodes. As in the puzzle input, each ghost walks a loop whose length
+/// is a multiple of the number of instructions, and which ends on its end node, whose edges are those of its start
+/// node, so that the LCM shortcut holds. The first ghost goes from `AAA` to `ZZZ`, and the edges that are never taken
+/// lead to random nodes.
+fn random_network(size: usize, rng: &mut Rng) -> Result<String, String> {
+    if size > INNER_NAMES {
+        return Err(format!(
+            "A network cannot have more than {INNER_NAMES} nodes"
+        ));
+    }
+    let ghosts = (size / 50).clamp(1, 6);
+    let budget = (size / ghosts).max(1);
+    let root = budget.isqrt() as u64;
+    let length = rng.range((root / 2).max(1)..=root) as usize;
+    let instructions = (0..length)
+        .map(|_| if rng.chance(50) { 'L' } else { 'R' })
+        .collect::<Vec<_>>();
+
+    let letters = b'A'..=b'Z';
+    let mut inner_names = letters
+        .clone()
+        .cartesian_product(letters.clone())
+        .cartesian_product(b'B'..=b'Y')
+        .map(|((first, second), third)| [first, second, third])
+        .collect::<Vec<NodeId>>();
+    rng.shuffle(&mut inner_names);
+    let mut prefixes = letters
+        .clone()
+        .cartesian_product(letters)
+        .filter(|prefix| *prefix != (b'A', b'A') && *prefix != (b'Z', b'Z'))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut prefixes);
+    let ends = std::iter::once((*b"AAA", *b"ZZZ")).chain(
+        prefixes
+            .into_iter()
+            .map(|(first, second)| ([first, second, b'A'], [first, second, b'Z'])),
+    );
+
+    let mut loops = Vec::new();
+    for (start, end) in ends.take(ghosts) {
+        let laps = (budget / length).max(1) as u64;
+        let loop_length = rng.range((laps / 2).max(1)..=laps) as usize * length;
+        let mut nodes = inner_names.split_off(inner_names.len() - (loop_length - 1));
+        nodes.push(end);
+        loops.push((start, nodes));
+    }
+    let names = loops
+        .iter()
+        .flat_map(|(start, nodes)| std::iter::once(start).chain(nodes))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut edges = |next: NodeId, index: usize| {
+        let other = *rng.choose(&names);
+        match instructions[index % length] {
+            'L' => (next, other),
+            _ => (other, next),
+        }
+    };
+    let mut lines = Vec::new();
+    for (start, nodes) in &loops {
+        let mut loop_edges = (0..nodes.len())
+            .map(|step| {
+                (
+                    nodes[step],
+                    edges(nodes[(step + 1) % nodes.len()], step + 1),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (_, end_edges) = loop_edges[nodes.len() - 1];
+        loop_edges.push((*start, end_edges));
+        lines.extend(loop_edges.into_iter().map(|(id, (left, right))| {
+            let name = |id: NodeId| String::from_utf8_lossy(&id).into_owned();
+            format!("{} = ({}, {})", name(id), name(left), name(right))
+        }));
+    }
+    rng.shuffle(&mut lines);
+    Ok(format!(
+        "{}\n\n{}\n",
+        instructions.into_iter().collect::<String>(),
+        lines.join("\n")
+    ))
+}
+
+pub fn steps(input: &str) -> Result<usize, String> {
+    let (instructions, nodes) = parse_network(input)?;
+    traverse_wasteland(&instructions, &nodes)
+}
+
+pub fn ghost_steps(input: &str) -> Result<usize, String> {
+    let (instructions, nodes) = parse_network(input)?;
+    traverse_wasteland_as_ghost(&instructions, &nodes)
+}
+
+pub fn traverse_wasteland(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+) -> Result<usize, String> {
+    traverse_wasteland_from(instructions, nodes, [b'A', b'A', b'A'], |id| id == b"ZZZ")
+}
+
+/// The number of steps to go from the start node to the first node that is an end.
+///
+/// Fails if the path goes through an unknown node, or if it loops without reaching an end.
+pub fn traverse_wasteland_from<F: Fn(&NodeId) -> bool>(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+    start_node: NodeId,
+    is_end: F,
+) -> Result<usize, String> {
+    match walk(instructions, nodes, start_node, is_end)? {
+        Walk::End(path) => Ok(path.len() - 1),
+        Walk::Loop(..) => Err(format!(
+            "No end node can be reached from {}",
+            String::from_utf8_lossy(&start_node)
+        )),
+    }
+}
+
+/// The nodes visited from the start node, which is the first one, following the instructions forever. It stops early
+/// on an unknown node.
+pub fn wasteland_path<'a>(
+    instructions: &'a [Instruction],
+    nodes: &'a HashMap<NodeId, Node>,
+    start_node: NodeId,
+) -> impl Iterator<Item = NodeId> + 'a {
+    let mut instructions = instructions.iter().cycle();
+    std::iter::successors(Some(start_node), move |id| {
+        Some(nodes.get(id)?.next_node(instructions.next()?))
     })
 }
 
-fn traverse_wasteland_from<F: Fn(&NodeId) -> bool>(
+/// The nodes visited from the start node up to the first end node, both included, so that the number of steps is one
+/// less than the length of the path. Returns `None` if the path goes through an unknown node.
+pub fn traverse_wasteland_path<F: Fn(&NodeId) -> bool>(
     instructions: &[Instruction],
     nodes: &HashMap<NodeId, Node>,
     start_node: NodeId,
     is_end: F,
+) -> Option<Vec<NodeId>> {
+    let mut path = Vec::new();
+    for id in wasteland_path(instructions, nodes, start_node) {
+        path.push(id);
+        if is_end(&id) {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// The number of steps until cursors moving together from each start node are all on an end node at the same time.
+///
+/// Panics if a path goes through an unknown node, and never returns if the cursors never meet the predicate together.
+pub fn traverse_wasteland_with_cursors<F: Fn(&NodeId) -> bool>(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+    start_nodes: &[NodeId],
+    is_end: F,
 ) -> usize {
     let mut steps = 0;
 
-    let mut current_node_id = start_node;
+    let mut cursors = start_nodes.to_vec();
     for instruction in instructions.iter().cycle() {
-        if is_end(&current_node_id) {
+        if cursors.iter().all(&is_end) {
             break;
         }
-        let current_node = nodes[&current_node_id];
-        current_node_id = current_node.next_node(instruction);
+        for cursor in &mut cursors {
+            *cursor = nodes[cursor].next_node(instruction);
+        }
         steps += 1;
     }
 
     steps
 }
 
-fn traverse_wasteland_as_ghost(
+pub fn traverse_wasteland_as_ghost(
     instructions: &[Instruction],
     nodes: &HashMap<NodeId, Node>,
-) -> usize {
+) -> Result<usize, String> {
     let cycle_lengths = nodes
         .keys()
         .filter(|id| id[2] == b'A')
         .map(|id| traverse_wasteland_from(instructions, nodes, *id, |id| id[2] == b'Z'))
+        .collect::<Result<Vec<_>, _>>()?;
+    cycle_lengths
+        .into_iter()
+        .try_fold(1, lcm)
+        .ok_or_else(|| "Steps to traverse wasteland as ghost overflow".to_string())
+}
+
+/// The path of a ghost, which ends up looping once it is back on a node at the same point of the instructions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GhostCycle {
+    pub start: NodeId,
+    /// The step at which the loop starts.
+    pub offset: usize,
+    /// The length of the loop.
+    pub period: usize,
+    /// The steps on an end node, before the loop comes back to its start.
+    pub end_steps: Vec<usize>,
+}
+
+impl GhostCycle {
+    /// Whether the ghost is on an end node at exactly the multiples of its first arrival, which is what the LCM of the
+    /// first arrivals relies on.
+    pub fn fits_lcm(&self) -> bool {
+        let Some(&first) = self.end_steps.first() else {
+            return false;
+        };
+        self.period.is_multiple_of(first)
+            && self
+                .end_steps
+                .iter()
+                .copied()
+                .eq((first..self.offset + self.period).step_by(first))
+    }
+}
+
+pub fn ghost_cycles(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+) -> Result<Vec<GhostCycle>, String> {
+    let mut starts = nodes
+        .keys()
+        .filter(|id| id[2] == b'A')
+        .copied()
         .collect::<Vec<_>>();
-    dbg!(&cycle_lengths);
-    find_smallest_number_divisible_by(&cycle_lengths)
+    starts.sort();
+    starts
+        .into_iter()
+        .map(|start| ghost_cycle(instructions, nodes, start))
+        .collect()
+}
+
+fn ghost_cycle(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+    start: NodeId,
+) -> Result<GhostCycle, String> {
+    let (path, offset) = ghost_path(instructions, nodes, start)?;
+    let end_steps = path
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, id)| id[2] == b'Z')
+        .map(|(step, _)| step)
+        .collect();
+    Ok(GhostCycle {
+        start,
+        offset,
+        period: path.len() - offset,
+        end_steps,
+    })
 }
 
-fn find_smallest_number_divisible_by(numbers: &[usize]) -> usize {
-    let min = numbers.iter().min().copied().unwrap_or(0);
-    let mut number = min;
-    loop {
-        if numbers.iter().all(|i| number % i == 0) {
-            break number;
+/// The nodes visited from `start` until the ghost loops, with the step at which the loop starts.
+fn ghost_path(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+    start: NodeId,
+) -> Result<(Vec<NodeId>, usize), String> {
+    match walk(instructions, nodes, start, |_| false)? {
+        Walk::Loop(path, offset) => Ok((path, offset)),
+        Walk::End(_) => unreachable!("No node is an end"),
+    }
+}
+
+/// How a walk through the network stops.
+enum Walk {
+    /// On an end node, with the nodes visited from the start, both included.
+    End(Vec<NodeId>),
+    /// Back on a node at the same point of the instructions, with the nodes visited before and the step at which the
+    /// loop starts.
+    Loop(Vec<NodeId>, usize),
+}
+
+/// Follows the instructions from `start` until an end node, or until the path loops.
+fn walk<F: Fn(&NodeId) -> bool>(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+    start: NodeId,
+    is_end: F,
+) -> Result<Walk, String> {
+    let mut visited = HashMap::new();
+    let mut path = Vec::new();
+    let mut current = start;
+    for (step, (index, instruction)) in instructions.iter().enumerate().cycle().enumerate() {
+        if let Some(offset) = visited.insert((current, index), step) {
+            return Ok(Walk::Loop(path, offset));
+        }
+        path.push(current);
+        if is_end(&current) {
+            return Ok(Walk::End(path));
+        }
+        current = nodes
+            .get(&current)
+            .ok_or_else(|| format!("Unknown node: {}", String::from_utf8_lossy(&current)))?
+            .next_node(instruction);
+    }
+    Err("No instructions".to_string())
+}
+
+/// The node each ghost is on after exactly `steps` steps, computed from the loop it ends up in rather than by walking
+/// every step.
+pub fn positions_after(
+    instructions: &[Instruction],
+    nodes: &HashMap<NodeId, Node>,
+    starts: &[NodeId],
+    steps: usize,
+) -> Result<Vec<NodeId>, String> {
+    starts
+        .iter()
+        .map(|start| {
+            let (path, offset) = ghost_path(instructions, nodes, *start)?;
+            let step = if steps < path.len() {
+                steps
+            } else {
+                offset + (steps - offset) % (path.len() - offset)
+            };
+            Ok(path[step])
+        })
+        .collect()
+}
+
+/// The structure of a network, to sanity-check an input before traversing it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GraphReport {
+    /// The number of weakly connected components, ignoring edges to unknown nodes.
+    pub components: usize,
+    /// The nodes that cannot be reached from any start node (ending with `A`), sorted.
+    pub unreachable: Vec<NodeId>,
+    /// The nodes whose both edges lead back to themselves, sorted.
+    pub self_loops: Vec<NodeId>,
+}
+
+pub fn analyze(nodes: &HashMap<NodeId, Node>) -> GraphReport {
+    let mut neighbours = HashMap::<NodeId, Vec<NodeId>>::new();
+    for node in nodes.values() {
+        for next in [node.left, node.right] {
+            if nodes.contains_key(&next) {
+                neighbours.entry(node.id).or_default().push(next);
+                neighbours.entry(next).or_default().push(node.id);
+            }
+        }
+    }
+    let mut components = 0;
+    let mut visited = HashSet::new();
+    for id in nodes.keys() {
+        if visited.insert(*id) {
+            components += 1;
+            let mut pending = vec![*id];
+            while let Some(current) = pending.pop() {
+                for next in neighbours.get(&current).into_iter().flatten() {
+                    if visited.insert(*next) {
+                        pending.push(*next);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut pending = nodes
+        .keys()
+        .filter(|id| id[2] == b'A')
+        .copied()
+        .collect::<Vec<_>>();
+    let mut reachable = pending.iter().copied().collect::<HashSet<_>>();
+    while let Some(current) = pending.pop() {
+        if let Some(node) = nodes.get(&current) {
+            for next in [node.left, node.right] {
+                if reachable.insert(next) {
+                    pending.push(next);
+                }
+            }
         }
-        number += min;
+    }
+    let mut unreachable = nodes
+        .keys()
+        .filter(|id| !reachable.contains(*id))
+        .copied()
+        .collect::<Vec<_>>();
+    unreachable.sort();
+
+    let mut self_loops = nodes
+        .values()
+        .filter(|node| node.left == node.id && node.right == node.id)
+        .map(Node::id)
+        .collect::<Vec<_>>();
+    self_loops.sort();
+
+    GraphReport {
+        components,
+        unreachable,
+        self_loops,
     }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Instruction {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Instruction {
     Left,
     Right,
 }
 
-fn parse_instructions(input: &str) -> Result<Vec<Instruction>, String> {
-    input
-        .chars()
-        .map(|c| match c {
+/// Parses the instructions, ignoring any whitespace between them. The index of an invalid instruction does not count
+/// that whitespace.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, String> {
+    let mut instructions = input.chars().filter(|c| !c.is_whitespace()).peekable();
+    if instructions.peek().is_none() {
+        return Err("No instructions".to_string());
+    }
+    instructions
+        .enumerate()
+        .map(|(index, c)| match c {
             'L' => Ok(Instruction::Left),
             'R' => Ok(Instruction::Right),
-            _ => Err(format!("Invalid instruction {c}")),
+            _ => Err(format!("Invalid instruction '{c}' at index {index}")),
         })
         .collect()
 }
 
-type NodeId = [u8; 3];
+pub type NodeId = [u8; 3];
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Node {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Node {
+    #[cfg_attr(feature = "serde", serde(with = "node_id"))]
     id: NodeId,
+    #[cfg_attr(feature = "serde", serde(with = "node_id"))]
     left: NodeId,
+    #[cfg_attr(feature = "serde", serde(with = "node_id"))]
     right: NodeId,
 }
 
 impl Node {
-    fn next_node(&self, instruction: &Instruction) -> NodeId {
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn next_node(&self, instruction: &Instruction) -> NodeId {
         match instruction {
             Instruction::Left => self.left,
             Instruction::Right => self.right,
@@ -143,6 +630,8 @@ impl FromStr for Node {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         if let Ok(("", node)) = parse_node(input) {
             Ok(node)
+        } else if input.chars().any(|c| c.is_ascii_lowercase()) {
+            Err(format!("Invalid node: {input} (node ids are upper case)"))
         } else {
             Err(format!("Invalid node: {input}"))
         }
@@ -167,55 +656,40 @@ fn parse_id(input: &str) -> IResult<&str, NodeId> {
     Ok((input, chars.map(|c| c as u8)))
 }
 
+#[cfg(feature = "serde")]
+mod node_id {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{parse_id, NodeId};
+
+    pub fn serialize<S: Serializer>(id: &NodeId, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf8_lossy(id))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NodeId, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        match parse_id(&id) {
+            Ok(("", node_id)) => Ok(node_id),
+            _ => Err(D::Error::custom(format!("Invalid node id: {id}"))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use aoc_utils::input::non_empty_lines;
+
     use crate::day8::Instruction::{Left, Right};
 
     use super::*;
 
-    fn example_nodes() -> &'static HashMap<NodeId, Node> {
-        static NODES: OnceLock<HashMap<NodeId, Node>> = OnceLock::new();
-        NODES.get_or_init(|| {
-            read_lines(
-                b"
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)
-"
-                .as_slice(),
-            )
-            .filter_not_empty()
-            .parse::<Node>()
-            .map(|node| (node.id, node))
-            .collect()
-        })
+    fn example_nodes() -> HashMap<NodeId, Node> {
+        parse_network(EXAMPLE1).unwrap().1
     }
 
-    fn example2_nodes() -> &'static HashMap<NodeId, Node> {
-        static NODES: OnceLock<HashMap<NodeId, Node>> = OnceLock::new();
-        NODES.get_or_init(|| {
-            read_lines(
-                b"
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)
-"
-                .as_slice(),
-            )
-            .filter_not_empty()
-            .parse::<Node>()
-            .map(|node| (node.id, node))
-            .collect()
-        })
+    fn example2_nodes() -> HashMap<NodeId, Node> {
+        parse_network(EXAMPLE2).unwrap().1
     }
 
     #[test]
@@ -223,6 +697,65 @@ XXX = (XXX, XXX)
         assert_eq!(parse_instructions("RL"), Ok(vec![Right, Left]));
     }
 
+    #[test]
+    fn parse_instructions_should_ignore_surrounding_whitespace() {
+        assert_eq!(parse_instructions(" LLR\n\n"), Ok(vec![Left, Left, Right]));
+    }
+
+    #[test]
+    fn parse_instructions_should_reject_empty_instructions() {
+        assert_eq!(
+            parse_instructions(" \n"),
+            Err("No instructions".to_string()),
+        );
+    }
+
+    #[test]
+    fn parse_instructions_should_report_the_index_of_an_invalid_instruction() {
+        assert_eq!(
+            parse_instructions("LRXL"),
+            Err("Invalid instruction 'X' at index 2".to_string()),
+        );
+    }
+
+    #[test]
+    fn parse_network_should_read_instructions_then_nodes() {
+        let (instructions, nodes) =
+            parse_network("RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\n").unwrap();
+
+        assert_eq!(instructions, vec![Right, Left]);
+        assert_eq!(nodes.len(), 2);
+    }
+
+    #[test]
+    fn parse_network_should_accept_instructions_over_several_lines() {
+        let (instructions, nodes) =
+            parse_network("\nRL\n  L \n\nAAA = (BBB, CCC)\n\n\n  BBB = (DDD, EEE)\n\n").unwrap();
+
+        assert_eq!(instructions, vec![Right, Left, Left]);
+        assert_eq!(nodes.len(), 2);
+    }
+
+    #[test]
+    fn parse_network_should_give_the_line_of_an_invalid_node() {
+        assert_eq!(
+            parse_network("RL\n\nAAA = (BBB, CCC)\nBbB = (DDD, EEE)"),
+            Err("Line 4: Invalid node: BbB = (DDD, EEE) (node ids are upper case)".to_string()),
+        );
+        assert_eq!(
+            parse_network("RL\n\nAAA = (BBB, CCC)\n\nBBB = DDD"),
+            Err("Line 5: Invalid node: BBB = DDD".to_string()),
+        );
+    }
+
+    #[test]
+    fn parse_instructions_should_not_count_whitespace_in_the_index() {
+        assert_eq!(
+            parse_instructions("LR\nRXL"),
+            Err("Invalid instruction 'X' at index 3".to_string()),
+        );
+    }
+
     #[test]
     fn parse_example_node_line_1() {
         assert_eq!(
@@ -237,14 +770,226 @@ XXX = (XXX, XXX)
 
     #[test]
     fn part1_example() {
-        assert_eq!(traverse_wasteland(&[Right, Left], example_nodes()), 2);
+        assert_eq!(traverse_wasteland(&[Right, Left], &example_nodes()), Ok(2));
     }
 
     #[test]
-    fn part2_example() {
+    fn steps_should_fail_on_unknown_nodes() {
+        assert_eq!(
+            steps("L\n\nAAA = (BBB, BBB)\n"),
+            Err("Unknown node: BBB".to_string()),
+        );
+        assert_eq!(
+            ghost_steps("L\n\nAAA = (BBB, BBB)\n"),
+            Err("Unknown node: BBB".to_string()),
+        );
+    }
+
+    #[test]
+    fn steps_should_fail_when_the_end_cannot_be_reached() {
+        let network = "LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+
+        assert_eq!(
+            steps(network),
+            Err("No end node can be reached from AAA".to_string()),
+        );
+        assert_eq!(
+            ghost_steps(network),
+            Err("No end node can be reached from AAA".to_string()),
+        );
+    }
+
+    #[test]
+    fn traverse_wasteland_from_should_stop_at_the_first_matching_node() {
+        assert_eq!(
+            traverse_wasteland_from(&[Left, Right], &example_nodes(), *b"AAA", |id| id == b"EEE"),
+            Ok(2),
+        );
         assert_eq!(
-            traverse_wasteland_as_ghost(&[Left, Right], example2_nodes()),
+            traverse_wasteland_from(&[Right], &example_nodes(), *b"CCC", |id| id[0] == b'G'),
+            Ok(1),
+        );
+    }
+
+    #[test]
+    fn traverse_wasteland_path_should_list_the_visited_nodes() {
+        assert_eq!(
+            traverse_wasteland_path(&[Right, Left], &example_nodes(), *b"AAA", |id| id == b"ZZZ"),
+            Some(vec![*b"AAA", *b"CCC", *b"ZZZ"]),
+        );
+    }
+
+    #[test]
+    fn traverse_wasteland_path_should_stop_on_unknown_nodes() {
+        let nodes = parse_nodes(["AAA = (BBB, BBB)"]).unwrap();
+
+        assert_eq!(
+            traverse_wasteland_path(&[Left], &nodes, *b"AAA", |id| id == b"ZZZ"),
+            None,
+        );
+    }
+
+    #[test]
+    fn wasteland_path_should_cycle_through_the_instructions() {
+        assert_eq!(
+            wasteland_path(&[Left, Right], &example2_nodes(), *b"22A")
+                .take(5)
+                .collect::<Vec<_>>(),
+            vec![*b"22A", *b"22B", *b"22C", *b"22Z", *b"22B"],
+        );
+    }
+
+    #[test]
+    fn traverse_wasteland_with_cursors_should_wait_for_all_cursors() {
+        let nodes = example2_nodes();
+        let is_end = |id: &NodeId| id[2] == b'Z';
+
+        assert_eq!(
+            traverse_wasteland_with_cursors(&[Left, Right], &nodes, &[*b"11A", *b"22A"], is_end),
             6,
         );
+        assert_eq!(
+            traverse_wasteland_with_cursors(&[Left, Right], &nodes, &[*b"22A"], is_end),
+            3,
+        );
+        assert_eq!(
+            traverse_wasteland_with_cursors(&[Left, Right], &nodes, &[], is_end),
+            0,
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            traverse_wasteland_as_ghost(&[Left, Right], &example2_nodes()),
+            Ok(6),
+        );
+    }
+
+    #[test]
+    fn ghost_cycles_of_example() {
+        assert_eq!(
+            ghost_cycles(&[Left, Right], &example2_nodes()),
+            Ok(vec![
+                GhostCycle {
+                    start: *b"11A",
+                    offset: 1,
+                    period: 2,
+                    end_steps: vec![2],
+                },
+                GhostCycle {
+                    start: *b"22A",
+                    offset: 1,
+                    period: 6,
+                    end_steps: vec![3, 6],
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn positions_after_should_follow_the_loops_of_example2() {
+        let instructions = parse_instructions("LR").unwrap();
+        let nodes = example2_nodes();
+        let starts = [*b"11A", *b"22A"];
+
+        assert_eq!(
+            positions_after(&instructions, &nodes, &starts, 0),
+            Ok(vec![*b"11A", *b"22A"]),
+        );
+        assert_eq!(
+            positions_after(&instructions, &nodes, &starts, 6),
+            Ok(vec![*b"11Z", *b"22Z"]),
+        );
+        assert_eq!(
+            positions_after(&instructions, &nodes, &starts, 1_000_001),
+            Ok(vec![*b"11B", *b"22C"]),
+        );
+    }
+
+    #[test]
+    fn positions_after_should_agree_with_walking_the_path() {
+        let instructions = parse_instructions("LR").unwrap();
+        let nodes = example2_nodes();
+
+        for steps in 0..20 {
+            let walked = wasteland_path(&instructions, &nodes, *b"22A")
+                .nth(steps)
+                .unwrap();
+            assert_eq!(
+                positions_after(&instructions, &nodes, &[*b"22A"], steps),
+                Ok(vec![walked]),
+            );
+        }
+    }
+
+    #[test]
+    fn fits_lcm_should_require_end_nodes_at_multiples_of_the_first_arrival() {
+        let cycle = |offset, period, end_steps| GhostCycle {
+            start: *b"11A",
+            offset,
+            period,
+            end_steps,
+        };
+
+        assert!(cycle(1, 6, vec![3, 6]).fits_lcm());
+        assert!(!cycle(1, 6, vec![2, 5]).fits_lcm());
+        assert!(!cycle(2, 5, vec![2, 4, 6]).fits_lcm());
+        assert!(!cycle(0, 4, vec![]).fits_lcm());
+    }
+
+    #[test]
+    fn analyze_should_report_the_structure_of_example_network() {
+        assert_eq!(
+            analyze(&example_nodes()),
+            GraphReport {
+                components: 1,
+                unreachable: vec![],
+                self_loops: vec![*b"DDD", *b"EEE", *b"GGG", *b"ZZZ"],
+            },
+        );
+        assert_eq!(
+            analyze(&example2_nodes()),
+            GraphReport {
+                components: 1,
+                unreachable: vec![],
+                self_loops: vec![*b"XXX"],
+            },
+        );
+    }
+
+    #[test]
+    fn analyze_should_report_disconnected_and_unreachable_nodes() {
+        let nodes = parse_nodes(non_empty_lines(
+            "
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, QQQ)
+BBB = (CCC, CCC)
+CCC = (BBB, BBB)
+",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            analyze(&nodes),
+            GraphReport {
+                components: 2,
+                unreachable: vec![*b"BBB", *b"CCC"],
+                self_loops: vec![],
+            },
+        );
+    }
+
+    #[test]
+    fn generated_network_should_fit_the_lcm_shortcut() {
+        let network = random_network(1000, &mut Rng::new(8)).unwrap();
+        let (instructions, nodes) = parse_network(&network).unwrap();
+        let cycles = ghost_cycles(&instructions, &nodes).unwrap();
+
+        assert!(nodes.len() <= 1000 + 6);
+        assert_eq!(cycles.len(), 6);
+        assert!(cycles.iter().all(GhostCycle::fits_lcm));
+        assert_eq!(steps(&network), Ok(cycles[0].end_steps[0]));
+        assert!(ghost_steps(&network).is_ok());
     }
 }
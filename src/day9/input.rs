@@ -0,0 +1 @@
+pub const INPUT: &str = "";
@@ -0,0 +1,278 @@
+use std::str::FromStr;
+
+use aoc_utils::checked::CheckedIterator;
+use aoc_utils::input::parse_lines;
+
+use crate::{Example, Label, Solution};
+
+mod input;
+
+pub struct Day9;
+
+const EXAMPLE: &str = "
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+const ANSWER_LABELS: [Label; 2] = [
+    Label {
+        key: "extrapolated-values-sum",
+        text: "Sum of extrapolated values",
+    },
+    Label {
+        key: "backward-extrapolated-values-sum",
+        text: "Sum of backward extrapolated values",
+    },
+];
+
+impl Solution for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Mirage Maintenance")
+    }
+
+    fn labels(&self) -> &'static [Label] {
+        &ANSWER_LABELS
+    }
+
+    fn input(&self) -> &'static str {
+        input::INPUT
+    }
+
+    fn part_one(&self, input: &str) -> Result<String, String> {
+        let sum = next_values_sum(input)?;
+        Ok(ANSWER_LABELS[0].answer(sum))
+    }
+
+    fn part_two(&self, input: &str) -> Result<String, String> {
+        let sum = previous_values_sum(input)?;
+        Ok(ANSWER_LABELS[1].answer(sum))
+    }
+
+    fn validate(&self, input: &str) -> Result<(), String> {
+        parse_report(input).map(drop)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                part: 1,
+                input: EXAMPLE,
+                answer: "Sum of extrapolated values: 114",
+            },
+            Example {
+                part: 2,
+                input: EXAMPLE,
+                answer: "Sum of backward extrapolated values: 2",
+            },
+        ]
+    }
+
+    fn explain(&self, part: u8, input: &str) -> Vec<String> {
+        parse_report(input)
+            .unwrap_or_default()
+            .iter()
+            .map(|history| {
+                let value = match part {
+                    1 => history.next_value(),
+                    _ => history.previous_value(),
+                };
+                format!(
+                    "{history}: {} levels of differences, extrapolated to {}",
+                    history.differences().len(),
+                    value.map_or("an overflow".to_string(), |value| value.to_string()),
+                )
+            })
+            .collect()
+    }
+}
+
+/// The histories of the OASIS report, one per line.
+#[tracing::instrument(skip_all)]
+pub fn parse_report(input: &str) -> Result<Vec<History>, String> {
+    let report = parse_lines::<History>(input)?;
+    if report.is_empty() {
+        return Err("No histories".to_string());
+    }
+    Ok(report)
+}
+
+pub fn next_values_sum(input: &str) -> Result<i64, String> {
+    parse_report(input)?
+        .iter()
+        .map(History::next_value)
+        .collect::<Option<Vec<_>>>()
+        .and_then(|values| values.into_iter().checked_sum())
+        .ok_or_else(|| "Sum of extrapolated values overflows".to_string())
+}
+
+pub fn previous_values_sum(input: &str) -> Result<i64, String> {
+    parse_report(input)?
+        .iter()
+        .map(History::previous_value)
+        .collect::<Option<Vec<_>>>()
+        .and_then(|values| values.into_iter().checked_sum())
+        .ok_or_else(|| "Sum of backward extrapolated values overflows".to_string())
+}
+
+/// The successive values of a sensor, from the oldest.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct History(Vec<i64>);
+
+impl History {
+    pub fn new(values: Vec<i64>) -> Self {
+        Self(values)
+    }
+
+    pub fn values(&self) -> &[i64] {
+        &self.0
+    }
+
+    /// The history followed by the sequences of differences between consecutive values, down to the first one made
+    /// only of zeros, which is not included. Empty if a difference overflows.
+    pub fn differences(&self) -> Vec<Vec<i64>> {
+        let mut levels = vec![self.0.clone()];
+        while let Some(level) = levels.last().filter(|level| level.iter().any(|v| *v != 0)) {
+            let next = level
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<Vec<_>>>();
+            match next {
+                Some(next) => levels.push(next),
+                None => return Vec::new(),
+            }
+        }
+        levels.pop();
+        levels
+    }
+
+    /// The value that comes after the last one, or `None` if it overflows.
+    pub fn next_value(&self) -> Option<i64> {
+        extrapolate(self.0.iter().copied())
+    }
+
+    /// The value that came before the first one, or `None` if it overflows.
+    pub fn previous_value(&self) -> Option<i64> {
+        extrapolate(self.0.iter().rev().copied())
+    }
+}
+
+/// The value after the given ones, which is the sum of the last values of every sequence of differences.
+fn extrapolate<I: Iterator<Item = i64>>(values: I) -> Option<i64> {
+    let mut level = values.collect::<Vec<_>>();
+    let mut next = 0i64;
+    while level.iter().any(|value| *value != 0) {
+        next = next.checked_add(*level.last()?)?;
+        level = level
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<_>>()?;
+    }
+    Some(next)
+}
+
+impl FromStr for History {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let values = line
+            .split_whitespace()
+            .map(|value| value.parse().map_err(|_| format!("Invalid value: {value}")))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(values))
+    }
+}
+
+impl std::fmt::Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = self.0.iter().map(i64::to_string).collect::<Vec<_>>();
+        write!(f, "{}", values.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<History> {
+        parse_report(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn parse_report_should_parse_example() {
+        assert_eq!(example()[0], History::new(vec![0, 3, 6, 9, 12, 15]),);
+        assert_eq!(example().len(), 3);
+    }
+
+    #[test]
+    fn parse_report_should_reject_invalid_values() {
+        assert_eq!(parse_report("1 2 x"), Err("Invalid value: x".to_string()));
+        assert_eq!(parse_report("\n"), Err("No histories".to_string()));
+    }
+
+    #[test]
+    fn next_value_should_extrapolate_example_histories() {
+        assert_eq!(
+            example()
+                .iter()
+                .map(History::next_value)
+                .collect::<Vec<_>>(),
+            vec![Some(18), Some(28), Some(68)],
+        );
+    }
+
+    #[test]
+    fn previous_value_should_extrapolate_example_histories() {
+        assert_eq!(
+            example()
+                .iter()
+                .map(History::previous_value)
+                .collect::<Vec<_>>(),
+            vec![Some(-3), Some(0), Some(5)],
+        );
+    }
+
+    #[test]
+    fn next_values_sum_should_return_114_for_example() {
+        assert_eq!(next_values_sum(EXAMPLE), Ok(114));
+    }
+
+    #[test]
+    fn previous_values_sum_should_return_2_for_example() {
+        assert_eq!(previous_values_sum(EXAMPLE), Ok(2));
+    }
+
+    #[test]
+    fn differences_should_stop_before_the_zeros() {
+        assert_eq!(
+            example()[1].differences(),
+            vec![
+                vec![1, 3, 6, 10, 15, 21],
+                vec![2, 3, 4, 5, 6],
+                vec![1, 1, 1, 1],
+            ],
+        );
+    }
+
+    #[test]
+    fn extrapolation_should_handle_negative_values_and_single_values() {
+        assert_eq!(History::new(vec![-2, -4, -6]).next_value(), Some(-8));
+        assert_eq!(History::new(vec![7]).next_value(), Some(7));
+        assert_eq!(History::new(vec![]).next_value(), Some(0));
+    }
+
+    #[test]
+    fn extrapolation_should_report_overflows() {
+        let history = History::new(vec![i64::MIN, i64::MAX]);
+
+        assert_eq!(history.next_value(), None);
+        assert_eq!(
+            next_values_sum("0 9223372036854775807"),
+            Err("Sum of extrapolated values overflows".to_string())
+        );
+    }
+}
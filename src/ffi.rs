@@ -0,0 +1,135 @@
+use std::panic::catch_unwind;
+use std::slice;
+
+pub const ADVENT2023_OK: i32 = 0;
+pub const ADVENT2023_ERROR: i32 = 1;
+pub const ADVENT2023_INVALID_ARGUMENT: i32 = -1;
+pub const ADVENT2023_BUFFER_TOO_SMALL: i32 = -2;
+pub const ADVENT2023_PANIC: i32 = -3;
+
+/// Solves a part of a day for the given input.
+///
+/// The answer, or the error message, is written to `out` as a NUL-terminated UTF-8 string.
+/// `out_len` holds the capacity of `out` and receives the length of the message, without the
+/// terminating NUL, so that a caller can retry with a larger buffer.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, `out_len` must be a valid pointer and `out`
+/// must point to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn advent2023_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if (input.is_null() && input_len > 0) || out.is_null() || out_len.is_null() {
+        return ADVENT2023_INVALID_ARGUMENT;
+    }
+    let input = if input_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input, input_len)
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return ADVENT2023_INVALID_ARGUMENT;
+    };
+
+    let (status, message) = match catch_unwind(|| crate::solve(day, part, input)) {
+        Ok(Ok(answer)) => (ADVENT2023_OK, answer),
+        Ok(Err(error)) => (ADVENT2023_ERROR, error),
+        Err(_) => (ADVENT2023_PANIC, "Solver panicked".to_string()),
+    };
+
+    let capacity = *out_len;
+    *out_len = message.len();
+    if message.len() >= capacity {
+        return ADVENT2023_BUFFER_TOO_SMALL;
+    }
+    let out = slice::from_raw_parts_mut(out, capacity);
+    out[..message.len()].copy_from_slice(message.as_bytes());
+    out[message.len()] = 0;
+    status
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn solve(day: u8, part: u8, input: &str, capacity: usize) -> (i32, usize, String) {
+        let mut out = vec![0xFF; capacity];
+        let mut out_len = capacity;
+        let status = unsafe {
+            advent2023_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut out_len,
+            )
+        };
+        let message = CStr::from_bytes_until_nul(&out)
+            .map(|message| message.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        (status, out_len, message)
+    }
+
+    #[test]
+    fn solve_should_write_the_answer() {
+        assert_eq!(
+            solve(7, 1, "32T3K 765\nKTJJT 220\n", 64),
+            (ADVENT2023_OK, 20, "Total winnings: 1205".to_string()),
+        );
+    }
+
+    #[test]
+    fn solve_should_write_the_error() {
+        assert_eq!(
+            solve(42, 1, "", 64),
+            (ADVENT2023_ERROR, 15, "Invalid day: 42".to_string()),
+        );
+    }
+
+    #[test]
+    fn solve_should_report_the_required_length_when_the_buffer_is_too_small() {
+        let (status, out_len, _) = solve(42, 1, "", 15);
+
+        assert_eq!((status, out_len), (ADVENT2023_BUFFER_TOO_SMALL, 15));
+    }
+
+    #[test]
+    fn solve_should_reject_invalid_utf8() {
+        let input = [0xC3, 0x28];
+        let mut out = [0; 16];
+        let mut out_len = out.len();
+
+        let status =
+            unsafe { advent2023_solve(1, 1, input.as_ptr(), 2, out.as_mut_ptr(), &mut out_len) };
+
+        assert_eq!(status, ADVENT2023_INVALID_ARGUMENT);
+    }
+
+    #[test]
+    fn solve_should_reject_null_pointers() {
+        let mut out_len = 0;
+
+        let status = unsafe {
+            advent2023_solve(
+                1,
+                1,
+                std::ptr::null(),
+                0,
+                std::ptr::null_mut(),
+                &mut out_len,
+            )
+        };
+
+        assert_eq!(status, ADVENT2023_INVALID_ARGUMENT);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{day2, day4, day5, day6, day7, day8};
+
+pub fn parse_game(input: &str) {
+    let _ = input.parse::<day2::Game>();
+}
+
+pub fn parse_scratchcard(input: &str) {
+    let _ = input.parse::<day4::Scratchcard>();
+}
+
+pub fn parse_map(input: &str) {
+    let _ = input.parse::<day5::Map>();
+}
+
+pub fn parse_races(input: &str) {
+    let _ = day6::parse_race_sheet(input, day6::Kerning::Spaces);
+    let _ = day6::parse_race_sheet(input, day6::Kerning::Ignored);
+}
+
+pub fn parse_hand(input: &str) {
+    let _ = input.parse::<day7::Hand>();
+}
+
+pub fn parse_node(input: &str) {
+    let _ = input.parse::<day8::Node>();
+}
@@ -1,47 +1,93 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod input;
+pub use aoc_utils::{answer_value, Answer, DayResult, Example, Label, Registry, Solution, Variant};
 
-pub trait Solution {
-    fn day(&self) -> u8;
-    fn part_one(&self) -> String;
-    fn part_two(&self) -> String;
+pub const YEAR: u16 = 2023;
 
-    fn execute(&self) {
-        let day = self.day();
-        let start = Instant::now();
-        println!("{day}:1 — {}", self.part_one());
-        let part1_duration = start.elapsed();
-        println!("Part 1 in {}ms", part1_duration.as_millis());
-        println!("{day}:2 — {}", self.part_two());
-        let part2_duration = start.elapsed() - part1_duration;
-        println!("Part 2 in {}ms", part2_duration.as_millis());
-        let total_duration = start.elapsed();
-        println!("Done in {}ms", total_duration.as_millis());
-    }
-}
+#[cfg(feature = "day1")]
+pub mod day1;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day16")]
+pub mod day16;
+#[cfg(feature = "day17")]
+pub mod day17;
+#[cfg(feature = "day2")]
+pub mod day2;
+#[cfg(feature = "day20")]
+pub mod day20;
+#[cfg(feature = "day24")]
+pub mod day24;
+#[cfg(feature = "day3")]
+pub mod day3;
+#[cfg(feature = "day4")]
+pub mod day4;
+#[cfg(feature = "day5")]
+pub mod day5;
+#[cfg(feature = "day6")]
+pub mod day6;
+#[cfg(feature = "day7")]
+pub mod day7;
+#[cfg(feature = "day8")]
+pub mod day8;
+#[cfg(feature = "day9")]
+pub mod day9;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+/// The solutions of the days enabled by the `dayN` features.
 pub fn solutions() -> HashMap<u8, Box<dyn Solution>> {
-    [
-        Box::new(day1::Day1) as Box<dyn Solution>,
-        Box::new(day2::Day2),
+    let solutions: Vec<Box<dyn Solution>> = vec![
+        #[cfg(feature = "day1")]
+        Box::new(day1::Day1),
+        #[cfg(feature = "day2")]
+        Box::new(day2::Day2::default()),
+        #[cfg(feature = "day3")]
         Box::new(day3::Day3),
+        #[cfg(feature = "day4")]
         Box::new(day4::Day4),
+        #[cfg(feature = "day5")]
         Box::new(day5::Day5),
+        #[cfg(feature = "day6")]
         Box::new(day6::Day6),
+        #[cfg(feature = "day7")]
         Box::new(day7::Day7),
+        #[cfg(feature = "day8")]
         Box::new(day8::Day8),
-    ]
-    .into_iter()
-    .map(|solution| (solution.day(), solution))
-    .collect()
+        #[cfg(feature = "day9")]
+        Box::new(day9::Day9),
+        #[cfg(feature = "day10")]
+        Box::new(day10::Day10),
+        #[cfg(feature = "day16")]
+        Box::new(day16::Day16),
+        #[cfg(feature = "day17")]
+        Box::new(day17::Day17),
+        #[cfg(feature = "day20")]
+        Box::new(day20::Day20),
+        #[cfg(feature = "day24")]
+        Box::new(day24::Day24),
+    ];
+    solutions
+        .into_iter()
+        .map(|solution| (solution.day(), solution))
+        .collect()
+}
+
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    solutions()
+        .get(&day)
+        .ok_or_else(|| format!("Invalid day: {day}"))?
+        .solve_part(part, input)
+}
+
+pub fn register(registry: &mut Registry) {
+    for solution in solutions().into_values() {
+        registry.register(YEAR, solution);
+    }
 }
@@ -0,0 +1,43 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::solutions;
+
+#[pyfunction]
+fn solve(day: u8, part: u8, text: &str) -> PyResult<String> {
+    crate::solve(day, part, text).map_err(PyValueError::new_err)
+}
+
+#[pyfunction]
+fn run_all(py: Python<'_>) -> PyResult<Vec<Bound<'_, PyDict>>> {
+    let results = py.detach(|| {
+        let mut solutions = solutions().into_values().collect::<Vec<_>>();
+        solutions.sort_by_key(|solution| solution.day());
+        solutions
+            .iter()
+            .map(|solution| solution.run())
+            .collect::<Vec<_>>()
+    });
+
+    let mut answers = Vec::new();
+    for answer in results.into_iter().flat_map(|result| result.answers) {
+        let dict = PyDict::new(py);
+        dict.set_item("day", answer.day)?;
+        dict.set_item("part", answer.part)?;
+        match answer.result {
+            Ok(text) => dict.set_item("answer", text)?,
+            Err(error) => dict.set_item("error", error)?,
+        }
+        dict.set_item("duration", answer.duration)?;
+        answers.push(dict);
+    }
+    Ok(answers)
+}
+
+#[pymodule]
+fn advent2023(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(run_all, module)?)?;
+    Ok(())
+}
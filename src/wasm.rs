@@ -0,0 +1,13 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    crate::solve(day, part, input)
+}
+
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    let mut days = crate::solutions().into_keys().collect::<Vec<_>>();
+    days.sort();
+    days
+}
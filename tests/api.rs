@@ -0,0 +1,133 @@
+#![cfg(all(
+    feature = "day1",
+    feature = "day2",
+    feature = "day3",
+    feature = "day4",
+    feature = "day5",
+    feature = "day6",
+    feature = "day7",
+    feature = "day8",
+))]
+
+use advent2023::{day1, day2, day3, day4, day5, day6, day7, day8};
+
+#[test]
+fn day2_games_should_be_usable_from_another_crate() {
+    let games = [
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+    ]
+    .map(|game| game.parse::<day2::Game>().unwrap());
+
+    assert_eq!(day2::sum_of_possible_game_ids(&games, 12, 13, 14), Some(1));
+    assert_eq!(
+        games[0].minimum_bag(),
+        day2::Draw {
+            red: 4,
+            green: 2,
+            blue: 6,
+        },
+    );
+    assert_eq!(games[0].minimum_power(), Some(48));
+}
+
+#[test]
+fn day3_schematic_should_be_usable_from_another_crate() {
+    let schematic =
+        day3::EngineSchematic::from_lines(["467..114..", "...*......", "..35..633."]).unwrap();
+
+    assert_eq!(day3::sum_of_gear_ratios(&schematic.gears()), Some(467 * 35));
+    assert_eq!(
+        schematic.symbols_with_adjacent_numbers(),
+        vec![(day3::Position(3, 1), '*', vec![467, 35])],
+    );
+}
+
+#[test]
+fn day4_scratchcards_should_be_usable_from_another_crate() {
+    let card = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+        .parse::<day4::Scratchcard>()
+        .unwrap();
+
+    assert_eq!(card.points(), 8);
+    assert_eq!(day4::copy_counts(std::slice::from_ref(&card)), vec![1]);
+    assert_eq!(day4::compute_nb_scratchcards(&[card]), 1);
+}
+
+#[test]
+fn day5_range_mapping_should_be_usable_from_another_crate() {
+    let map = "200 50 10".parse::<day5::Map>().unwrap();
+
+    assert_eq!(map.map_range(40..70), vec![40..50, 200..210, 60..70]);
+}
+
+#[test]
+fn day6_races_should_be_usable_from_another_crate() {
+    let races = [day6::Race::new(7, 9), day6::Race::new(15, 40)];
+
+    assert_eq!(day6::ways_to_win_product(&races), Some(32));
+    assert_eq!(day6::margin_of_error(&races), 32);
+    assert_eq!((races[1].time(), races[1].record()), (15, 40));
+}
+
+#[test]
+fn day7_hand_ranking_should_be_usable_from_another_crate() {
+    let hands = ["32T3K 765", "KTJJT 220"].map(|hand| hand.parse::<day7::Hand>().unwrap());
+
+    assert_eq!(
+        day7::total_winnings(&hands, &day7::StandardRules),
+        Some(765 + 220 * 2)
+    );
+    assert_eq!(
+        day7::total_winnings(&hands, &day7::JokerRules),
+        Some(765 + 220 * 2)
+    );
+    assert_eq!(hands[1].to_string(), "KTJJT (Two Pairs, bid 220)");
+    assert_eq!(hands[1].cards()[0], day7::Card::King);
+}
+
+#[test]
+fn day8_traversal_should_be_usable_from_another_crate() {
+    let (instructions, nodes) =
+        day8::parse_network("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+
+    assert_eq!(day8::traverse_wasteland(&instructions, &nodes), Ok(6));
+}
+
+#[test]
+fn answers_should_be_computable_from_raw_input() {
+    assert_eq!(day1::calibration_sum("1abc2\npqr3stu8vwx\n"), Ok(50));
+    assert_eq!(day1::fixed_calibration_sum("two1nine\n"), Ok(29));
+    assert_eq!(
+        day2::possible_games_sum("Game 1: 3 blue, 4 red\nGame 2: 20 red"),
+        Ok(1)
+    );
+    assert_eq!(
+        day2::minimum_powers_sum("Game 1: 3 blue, 4 red; 2 green"),
+        Ok(24)
+    );
+    assert_eq!(day3::part_numbers_sum("467..114..\n...*......"), Ok(467));
+    assert_eq!(day3::gear_ratios_sum("467..\n...*.\n..35."), Ok(467 * 35));
+    assert_eq!(
+        day4::total_points("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"),
+        Ok(8)
+    );
+    assert_eq!(
+        day4::total_scratchcards("Card 1: 41 48 | 41 9\nCard 2: 1 2 | 3 4"),
+        Ok(3)
+    );
+    let almanac = "seeds: 79 14 55 13\n\nseed-to-location map:\n50 98 2\n52 50 48\n";
+    assert_eq!(day5::lowest_location(almanac), Ok(13));
+    assert_eq!(day5::lowest_location_with_ranges(almanac), Ok(57));
+    let races = "Time:      7  15   30\nDistance:  9  40  200\n";
+    assert_eq!(day6::product_of_ways_to_win(races), Ok(288));
+    assert_eq!(day6::ways_to_win_long_race(races), Ok(71503));
+    assert_eq!(day7::winnings("32T3K 765\nKTJJT 220"), Ok(765 + 220 * 2));
+    assert_eq!(
+        day7::winnings_with_jokers("32T3K 765\nKTJJT 220"),
+        Ok(765 + 220 * 2)
+    );
+    let network = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+    assert_eq!(day8::steps(network), Ok(6));
+    assert_eq!(day8::ghost_steps(network), Ok(6));
+}
@@ -0,0 +1,38 @@
+use advent2023::{answer_value, solutions};
+
+/// The days whose input has not been committed yet, and whose answers are therefore not checked.
+const MISSING_INPUTS: [u8; 6] = [9, 10, 16, 17, 20, 24];
+
+#[test]
+#[cfg_attr(not(feature = "golden"), ignore = "enable with --features golden")]
+fn every_registered_day_should_return_its_known_answers() {
+    let solutions = solutions();
+    let mut days = solutions.keys().copied().collect::<Vec<_>>();
+    days.sort();
+    let (missing, days): (Vec<u8>, Vec<u8>) = days
+        .into_iter()
+        .partition(|day| solutions[day].input().is_empty());
+    let expected_missing = MISSING_INPUTS
+        .into_iter()
+        .filter(|day| solutions.contains_key(day))
+        .collect::<Vec<_>>();
+    assert_eq!(missing, expected_missing, "Days without input");
+
+    for day in days {
+        let solution = &solutions[&day];
+        let known_answers = solution.known_answers();
+        assert_eq!(
+            known_answers.len(),
+            solution.parts().len(),
+            "No known answers for day {day}"
+        );
+
+        for (&part, known_answer) in solution.parts().iter().zip(known_answers) {
+            assert_eq!(
+                answer_value(&solution.solve_part(part, solution.input()).unwrap()),
+                *known_answer,
+                "Day {day} part {part}"
+            );
+        }
+    }
+}
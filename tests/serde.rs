@@ -0,0 +1,106 @@
+#![cfg(all(
+    feature = "serde",
+    feature = "day2",
+    feature = "day4",
+    feature = "day5",
+    feature = "day7",
+    feature = "day8",
+))]
+
+use std::time::Duration;
+
+use advent2023::{day2, day4, day5, day7, day8, Answer, DayResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn game_should_round_trip() {
+    let game: day2::Game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+        .parse()
+        .unwrap();
+
+    assert_eq!(round_trip(&game), game);
+}
+
+#[test]
+fn scratchcard_should_round_trip() {
+    let card: day4::Scratchcard = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+        .parse()
+        .unwrap();
+
+    assert_eq!(round_trip(&card), card);
+}
+
+#[test]
+fn map_should_serialize_entries_in_puzzle_order() {
+    let map: day5::Map = "50 98 2\n52 50 48".parse().unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&map).unwrap(),
+        "[[52,50,48],[50,98,2]]"
+    );
+    assert_eq!(round_trip(&map), map);
+}
+
+#[test]
+fn map_should_reject_overflowing_entries() {
+    assert!(serde_json::from_str::<day5::Map>("[[0,18446744073709551610,10]]").is_err());
+}
+
+#[test]
+fn hand_should_round_trip() {
+    let hand: day7::Hand = "KTJJT 220".parse().unwrap();
+
+    assert_eq!(round_trip(&hand), hand);
+    assert_eq!(round_trip(&hand.to_jokers()), hand.to_jokers());
+}
+
+#[test]
+fn hand_type_should_be_recomputed_from_cards() {
+    let hand: day7::Hand = serde_json::from_str(
+        r#"{"cards":["King","Ten","Jack","Jack","Ten"],"bid":220,"hand_type":"FiveOfAKind"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(hand, "KTJJT 220".parse().unwrap());
+}
+
+#[test]
+fn node_should_serialize_ids_as_strings() {
+    let node: day8::Node = "AAA = (BBB, CCC)".parse().unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&node).unwrap(),
+        r#"{"id":"AAA","left":"BBB","right":"CCC"}"#
+    );
+    assert_eq!(round_trip(&node), node);
+    assert!(serde_json::from_str::<day8::Node>(r#"{"id":"A","left":"B","right":"C"}"#).is_err());
+}
+
+#[test]
+fn day_result_should_round_trip() {
+    let result = DayResult {
+        day: 2,
+        answers: vec![
+            Answer {
+                day: 2,
+                part: 1,
+                result: Ok("Sum of IDs of possible games: 8".to_string()),
+                duration: Duration::from_micros(1500),
+            },
+            Answer {
+                day: 2,
+                part: 2,
+                result: Err("Sum of minimum powers overflows".to_string()),
+                duration: Duration::from_millis(3),
+            },
+        ],
+        duration: Duration::from_micros(4500),
+    };
+
+    assert_eq!(round_trip(&result), result);
+}